@@ -1,9 +1,9 @@
 use std::{
     fmt::Display,
-    ops::{Add, Mul},
+    ops::{Add, Div, Mul, Neg, Sub},
 };
 
-use crate::traits::{Abs, IsZero, One, Sqrt, Zero};
+use crate::traits::{Abs, ApproxZero, Conjugate, IsField, IsPositive, IsZero, One, Sqrt, Zero};
 
 use super::Complex;
 
@@ -37,6 +37,10 @@ impl<T: One + Zero> One for Complex<T> {
     }
 }
 
+impl<T: IsField> IsField for Complex<T> {
+    const IS_FIELD: bool = T::IS_FIELD;
+}
+
 impl<T: IsZero> IsZero for &Complex<T> {
     fn is_zero(&self) -> bool {
         self.real.is_zero() && self.imaginary.is_zero()
@@ -57,6 +61,72 @@ where
     }
 }
 
+impl<T> Conjugate for Complex<T>
+where
+    T: Clone,
+    for<'a> &'a T: Neg<Output = T>,
+{
+    fn conjugate(&self) -> Self {
+        Self {
+            real: self.real.clone(),
+            imaginary: -&self.imaginary,
+        }
+    }
+}
+
+///
+/// A complex number is approximately zero if its modulus is at most the tolerance.
+///
+impl<T> ApproxZero for Complex<T>
+where
+    T: PartialOrd + Add<T, Output = T>,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    type Tolerance = T;
+
+    fn is_approx_zero(&self, tolerance: &T) -> bool {
+        &self.real * &self.real + &self.imaginary * &self.imaginary <= tolerance * tolerance
+    }
+}
+
+///
+/// A complex number is positive if it is a positive real number.
+///
+impl<T: IsZero + IsPositive> IsPositive for Complex<T> {
+    fn is_positive(&self) -> bool {
+        self.imaginary.is_zero() && self.real.is_positive()
+    }
+}
+
+///
+/// Returns the principal square root, the one with a non-negative real part.
+///
+impl<T> Sqrt for Complex<T>
+where
+    T: Clone
+        + Zero
+        + One
+        + Sqrt
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Div<T, Output = T>,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    fn sqrt(&self) -> Self {
+        let two = T::one() + T::one();
+        let modulus = (&self.real * &self.real + &self.imaginary * &self.imaginary).sqrt();
+        let real = ((modulus.clone() + self.real.clone()) / two.clone()).sqrt();
+        let imaginary = ((modulus - self.real.clone()) / two).sqrt();
+        if self.imaginary < T::zero() {
+            Self::new(real, -imaginary)
+        } else {
+            Self::new(real, imaginary)
+        }
+    }
+}
+
 impl<T> Complex<T> {
     pub const fn im(&self) -> &T {
         &self.imaginary
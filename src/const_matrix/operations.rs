@@ -1,4 +1,6 @@
 mod add;
+mod adjugate;
+mod linear_operator;
 mod mul_mat;
 mod mul_vec;
 mod scalar_mul;
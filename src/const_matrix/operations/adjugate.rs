@@ -0,0 +1,76 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::{
+    const_matrix::SquareMat,
+    traits::{IsZero, One},
+    Matrix,
+};
+
+impl<K, const SIZE: usize> SquareMat<K, SIZE>
+where
+    for<'a> K: Clone + Default + One + AddAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Neg<Output = K>
+        + IsZero,
+{
+    ///
+    /// Returns the `(line, column)` minor of the matrix, or `None` if `line`
+    /// or `column` is out of bounds.
+    ///
+    pub fn minor(&self, line: usize, column: usize) -> Option<K> {
+        Matrix::from(&self.content[..]).minor(line, column).ok()
+    }
+
+    ///
+    /// Returns the cofactor matrix, whose `(i, j)` element is the `(i, j)`
+    /// minor, times `(-1)^(i + j)`.
+    ///
+    #[must_use]
+    pub fn cofactor_matrix(&self) -> Self {
+        let cofactors = Matrix::from(&self.content[..])
+            .cofactor_matrix()
+            .unwrap_or_else(|_| unreachable!());
+        Self {
+            content: std::array::from_fn(|line| {
+                std::array::from_fn(|column| cofactors[line * SIZE + column].clone())
+            }),
+        }
+    }
+
+    ///
+    /// Returns the adjugate: the transpose of the cofactor matrix.
+    ///
+    /// ```
+    /// use matrix::const_matrix::ConstMatrix;
+    ///
+    /// let u = ConstMatrix::from([[2, 1], [5, 3]]);
+    /// assert_eq!(u.adjugate(), ConstMatrix::from([[3, -1], [-5, 2]]));
+    /// ```
+    ///
+    #[must_use]
+    pub fn adjugate(&self) -> Self {
+        self.cofactor_matrix().transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::const_matrix::ConstMatrix;
+
+    #[test]
+    fn example() {
+        let u = ConstMatrix::from([[-3, 2, -5], [-1, 0, -2], [3, -4, 1]]);
+        assert_eq!(u.minor(1, 1), Some(12));
+        assert_eq!(u.minor(0, 3), None);
+        assert_eq!(
+            u.cofactor_matrix(),
+            ConstMatrix::from([[-8, -5, 4], [18, 12, -6], [-4, -1, 2]])
+        );
+        assert_eq!(
+            u.adjugate(),
+            ConstMatrix::from([[-8, 18, -4], [-5, 12, -1], [4, -6, 2]])
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use std::ops::{AddAssign, Mul};
+
+use crate::{
+    const_matrix::ConstMatrix, linear_operator::LinearOperator, matrix::Dimensions, Vector,
+};
+
+impl<K, const ROW_NUMBER: usize, const COL_NUMBER: usize> LinearOperator<K>
+    for ConstMatrix<K, ROW_NUMBER, COL_NUMBER>
+where
+    K: Clone + Default + AddAssign,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            width: COL_NUMBER,
+            height: ROW_NUMBER,
+        }
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        let result: Vec<K> = self
+            .content
+            .iter()
+            .map(|line| {
+                let mut sum = K::default();
+                for (matrix_elt, vector_elt) in line.iter().zip(vec.iter()) {
+                    sum += matrix_elt * vector_elt;
+                }
+                sum
+            })
+            .collect();
+        Vector::from(result)
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        let mut result = Vector::fill(&K::default(), COL_NUMBER);
+        for (line, vector_elt) in self.content.iter().zip(vec.iter()) {
+            for (result, matrix_elt) in result.iter_mut().zip(line) {
+                *result += matrix_elt * vector_elt;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{linear_operator::LinearOperator, matrix::Dimensions, ConstMatrix, Vector};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn example() {
+        let mat = ConstMatrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            mat.dimensions(),
+            Dimensions {
+                width: 3,
+                height: 2
+            }
+        );
+        assert_eq!(mat.apply(&Vector::from([1, 0, -1])), [-2, -2]);
+        assert_eq!(mat.apply_transpose(&Vector::from([1, -1])), [-3, -3, -3]);
+    }
+}
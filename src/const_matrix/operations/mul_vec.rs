@@ -47,7 +47,7 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::{const_matrix::ConstMatrix, const_vector::ConstVector};
+    use crate::{const_matrix::ConstMatrix, const_vector::ConstVector, Gf};
 
     #[test]
     fn example_vec() {
@@ -124,4 +124,11 @@ mod test {
             assert_eq!(&u * &v, ConstVector::from([4., -4.]));
         }
     }
+
+    #[test]
+    fn vec_with_finite_field() {
+        let u = ConstMatrix::from([[1, 2], [3, 4]].map(|line| line.map(Gf::<5>::new)));
+        let v = ConstVector::from([4, 2].map(Gf::<5>::new));
+        assert_eq!(&u * &v, ConstVector::from([3, 0].map(Gf::new)));
+    }
 }
@@ -2,8 +2,11 @@ use std::ops::Index;
 
 use crate::static_asserts::{AssertNonZero, AssertNonZeroSizeType};
 
+mod gram_schmidt;
 mod operations;
 
+pub use gram_schmidt::gram_schmidt;
+
 #[repr(C)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct ConstVector<K, const SIZE: usize> {
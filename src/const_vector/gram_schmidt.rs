@@ -0,0 +1,84 @@
+use std::ops::{AddAssign, DivAssign, Mul, SubAssign};
+
+use crate::{
+    traits::{ApproxZero, Conjugate, Sqrt},
+    vector, ConstVector, Vector,
+};
+
+///
+/// Returns the `N` vectors orthonormalized with the modified Gram-Schmidt
+/// process, or `None` if they are linearly dependent within `tolerance`.
+///
+/// Each vector keeps the direction of its part orthogonal to the previous
+/// ones, which is what is needed to build a frame from a forward and an up
+/// direction.
+///
+/// # Example:
+/// ```
+/// use matrix::const_vector::{gram_schmidt, Vec3};
+///
+/// let forward = Vec3::new(0., 0., 2.);
+/// let up = Vec3::new(0., 1., 1.);
+/// let [forward, up] = gram_schmidt([forward, up], &1e-6).unwrap();
+/// assert_eq!(forward, Vec3::new(0., 0., 1.));
+/// assert_eq!(up, Vec3::new(0., 1., 0.));
+/// ```
+///
+/// See [`modified_gram_schmidt`](crate::vector::modified_gram_schmidt).
+///
+pub fn gram_schmidt<K, const SIZE: usize, const N: usize>(
+    vectors: [ConstVector<K, SIZE>; N],
+    tolerance: &K::Tolerance,
+) -> Option<[ConstVector<K, SIZE>; N]>
+where
+    for<'a> K: Clone
+        + Default
+        + Conjugate
+        + Sqrt
+        + ApproxZero
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    let vectors = vectors.map(|elt| Vector::from(elt.content));
+    // The vectors all have the same size
+    let basis =
+        vector::modified_gram_schmidt(&vectors, tolerance).unwrap_or_else(|_| unreachable!());
+    if basis.len() != N {
+        return None;
+    }
+    Some(std::array::from_fn(|index| ConstVector {
+        content: std::array::from_fn(|coordinate| basis[index][coordinate].clone()),
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::gram_schmidt;
+    use crate::const_vector::Vec3;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn frame() {
+        let frame = gram_schmidt(
+            [
+                Vec3::new(0., 0., 4.),
+                Vec3::new(0., 5., 3.),
+                Vec3::new(2., 1., 1.),
+            ],
+            &1e-6,
+        );
+        assert_eq!(
+            frame,
+            Some([
+                Vec3::new(0., 0., 1.),
+                Vec3::new(0., 1., 0.),
+                Vec3::new(1., 0., 0.),
+            ])
+        );
+
+        let frame = gram_schmidt([Vec3::new(1., 2., 3.), Vec3::new(2., 4., 6.)], &1e-6);
+        assert_eq!(frame, None);
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(clippy::module_name_repetitions)]
 
-use crate::matrix::Dimensions;
+use crate::{matrix::Dimensions, Vector};
 
 ///
 /// Describes the reason for which a [`Vector`](crate::Vector) operation can fail.
@@ -141,4 +141,291 @@ pub enum SubmatrixError {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WithoutLineColumnError {
     TooSmallMatrix,
+    /// Contains the `(line, column)` that was to be removed
+    IndexOutOfBounds(usize, usize),
+}
+
+///
+/// Describes the reason the LU decomposition of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`lu`](crate::Matrix#method.lu).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuDecompositionError {
+    NotSquareMatrix,
+}
+
+///
+/// Describes the reason solving a system through an
+/// [`LuDecomposition`](crate::matrix::LuDecomposition) can fail.
+///
+/// See [`solve`](crate::matrix::LuDecomposition#method.solve).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuSolveError {
+    /// Contains the `(matrix_size, vector_size)`
+    SizeMismatch(usize, usize),
+    SingularMatrix,
+}
+
+///
+/// Describes the reason solving a linear system can fail.
+///
+/// `Particular` is the type of the solution: a [`Vector`] for
+/// [`solve`](crate::Matrix#method.solve), a [`Matrix`](crate::Matrix) for
+/// [`solve_many`](crate::Matrix#method.solve_many).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError<K: Clone, Particular = Vector<K>> {
+    /// Contains the `(matrix_height, right_hand_side_height)`
+    SizeMismatch(usize, usize),
+    /// The system is inconsistent
+    NoSolution,
+    /// Every `particular + linear_combination(null_space, _)` is a solution
+    InfinitelyManySolutions {
+        /// The solution obtained by setting all free variables to zero
+        particular: Particular,
+        /// A basis of the null space of the matrix
+        null_space: Vec<Vector<K>>,
+    },
+}
+
+///
+/// Describes the reason the Cholesky decomposition of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`cholesky`](crate::Matrix#method.cholesky).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CholeskyError {
+    NotSquareMatrix,
+    NotPositiveDefinite,
+}
+
+///
+/// Describes the reason the `LDLᵀ` decomposition of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`ldlt`](crate::Matrix#method.ldlt).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LdltError {
+    NotSquareMatrix,
+    /// One of the leading principal minors of the matrix is zero
+    SingularLeadingMinor,
+}
+
+///
+/// Describes the reason the eigen decomposition of a symmetric [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`symmetric_eigen`](crate::Matrix#method.symmetric_eigen).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymmetricEigenError {
+    NotSquareMatrix,
+    NotSymmetricMatrix,
+}
+
+///
+/// Describes the reason the computation of the eigenvalues of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`eigenvalues`](crate::Matrix#method.eigenvalues).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EigenvaluesError {
+    NotSquareMatrix,
+    /// The QR iterations didn't converge
+    NoConvergence,
+}
+
+///
+/// Describes the reason the least squares solution of a system can fail.
+///
+/// See [`least_squares`](crate::Matrix#method.least_squares).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeastSquaresError {
+    /// Contains the `(number_of_matrix_line, vector_size)`
+    SizeMismatch(usize, usize),
+}
+
+///
+/// Describes the reason the computation of the characteristic polynomial of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`characteristic_polynomial`](crate::Matrix#method.characteristic_polynomial).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacteristicPolynomialError {
+    NotSquareMatrix,
+}
+
+///
+/// Describes the reason the computation of the roots of a [`Polynomial`](crate::polynomial::Polynomial) can fail.
+///
+/// See [`roots`](crate::polynomial::Polynomial#method.roots).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootsError {
+    /// Every number is a root of the zero polynomial
+    ZeroPolynomial,
+    /// The QR iterations didn't converge
+    NoConvergence,
+}
+
+///
+/// Describes the reason the integer power of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`pow`](crate::Matrix#method.pow).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowError {
+    NotSquareMatrix,
+}
+
+///
+/// Describes the reason the exponential of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`exp`](crate::Matrix#method.exp).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpError {
+    NotSquareMatrix,
+    /// An element or the norm of the matrix is infinite or NaN
+    NonFiniteMatrix,
+}
+
+///
+/// Describes the reason the square root of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`sqrt`](crate::Matrix#method.sqrt).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqrtError {
+    NotSquareMatrix,
+    SingularMatrix,
+    /// The iterations didn't converge, which happens when the matrix has
+    /// negative real eigenvalues
+    NoConvergence,
+}
+
+///
+/// Describes the reason the logarithm of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`log`](crate::Matrix#method.log).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogError {
+    NotSquareMatrix,
+    SingularMatrix,
+    /// The square roots didn't converge, which happens when the matrix has
+    /// negative real eigenvalues
+    NoConvergence,
+}
+
+///
+/// Describes the reason the computation of a minor of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`minor`](crate::Matrix#method.minor).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinorError {
+    NotSquareMatrix,
+    /// Contains the `(line, column)` that was asked for
+    IndexOutOfBounds(usize, usize),
+}
+
+///
+/// Describes the reason the computation of the cofactor matrix or of the
+/// adjugate of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`cofactor_matrix`](crate::Matrix#method.cofactor_matrix) and [`adjugate`](crate::Matrix#method.adjugate).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdjugateError {
+    NotSquareMatrix,
+}
+
+///
+/// Describes the reason the condition number estimate of a [`Matrix`](crate::Matrix) can fail.
+///
+/// See [`reciprocal_condition_number`](crate::Matrix#method.reciprocal_condition_number).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionNumberError {
+    NotSquareMatrix,
+}
+
+///
+/// Describes the reason for which a Gram-Schmidt orthonormalization can fail.
+///
+/// See [`gram_schmidt`](crate::vector::gram_schmidt) and [`modified_gram_schmidt`](crate::vector::modified_gram_schmidt).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GramSchmidtError {
+    /// Contains the `size` of `(first_vector, first_mismatch)`
+    VectorSizeMismatch(usize, usize),
+}
+
+///
+/// Describes the reason the creation of a [`SparseMatrix`](crate::matrix::SparseMatrix) from
+/// triplets can fail.
+///
+/// See [`from_triplets`](crate::matrix::SparseMatrix#method.from_triplets).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseMatrixCreationError {
+    /// Contains the `(line, column)` of the first triplet outside of the matrix
+    IndexOutOfBounds(usize, usize),
+}
+
+///
+/// Describes the reason an iterative solver can't start.
+///
+/// See the [`solvers`](crate::solvers) module.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverError {
+    NotSquareOperator,
+    /// Contains the `(operator_size, right_hand_side_size)`
+    SizeMismatch(usize, usize),
+    /// Contains the line of the zero diagonal element
+    ZeroOnDiagonal(usize),
+}
+
+///
+/// Describes the reason the power iteration can fail.
+///
+/// See [`power_iteration`](crate::linear_operator::power_iteration).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerIterationError {
+    NotSquareOperator,
+    /// The iterations didn't converge, which happens when the dominant
+    /// eigenvalues are not unique, like complex conjugates
+    NoConvergence,
+}
+
+///
+/// Describes the reason the creation of a structured matrix, like an
+/// [`UpperTriangular`](crate::matrix::UpperTriangular) or a
+/// [`Tridiagonal`](crate::matrix::Tridiagonal) one, can fail.
+///
+/// See [`from_packed`](crate::matrix::UpperTriangular#method.from_packed) and [`new`](crate::matrix::Tridiagonal#method.new).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuredMatrixCreationError {
+    /// Contains the `(expected_size, actual_size)` of the mismatched storage
+    SizeMismatch(usize, usize),
+}
+
+///
+/// Describes the reason solving a linear system with a structured matrix can fail.
+///
+/// See [`solve`](crate::matrix::UpperTriangular#method.solve) and [`solve`](crate::matrix::Tridiagonal#method.solve).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuredSolveError {
+    /// Contains the `(matrix_size, vector_size)`
+    SizeMismatch(usize, usize),
+    SingularMatrix,
+    /// The elimination without pivoting met a zero pivot, on the contained line
+    ZeroPivot(usize),
 }
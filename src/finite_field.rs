@@ -0,0 +1,13 @@
+///
+/// An element of the finite field `GF(P)`, the integers modulo the prime `P`.
+///
+/// Using a `P` that is not a prime is a compile time error.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Gf<const P: u64> {
+    /// Always in `0..P`
+    value: u64,
+}
+
+mod operations;
+mod utils;
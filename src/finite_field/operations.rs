@@ -0,0 +1,176 @@
+use std::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::Gf;
+
+impl<const P: u64> Gf<P> {
+    // The intermediate values are computed on 128 bits, so that they don't
+    // overflow for any `P`
+    #[inline(always)]
+    #[allow(clippy::cast_possible_truncation)]
+    const fn default_add(self, rhs: Self) -> Self {
+        Self {
+            value: ((self.value as u128 + rhs.value as u128) % P as u128) as u64,
+        }
+    }
+
+    #[inline(always)]
+    const fn default_sub(self, rhs: Self) -> Self {
+        self.default_add(rhs.default_neg())
+    }
+
+    #[inline(always)]
+    #[allow(clippy::cast_possible_truncation)]
+    const fn default_mul(self, rhs: Self) -> Self {
+        Self {
+            value: ((self.value as u128 * rhs.value as u128) % P as u128) as u64,
+        }
+    }
+
+    ///
+    /// # Panics
+    /// If `rhs` is zero.
+    ///
+    #[inline(always)]
+    fn default_div(self, rhs: Self) -> Self {
+        let Some(inverse) = rhs.inverse() else {
+            panic!("attempt to divide by zero in GF({P})");
+        };
+        self.default_mul(inverse)
+    }
+
+    #[inline(always)]
+    const fn default_neg(self) -> Self {
+        if self.value == 0 {
+            self
+        } else {
+            Self {
+                value: P - self.value,
+            }
+        }
+    }
+}
+
+macro_rules! impl_operation {
+    ($trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident, $internal: ident) => {
+        impl<const P: u64> $assign_trait<&Self> for Gf<P> {
+            fn $assign_method(&mut self, rhs: &Self) {
+                *self = self.$internal(*rhs);
+            }
+        }
+
+        impl<const P: u64> $assign_trait<Self> for Gf<P> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.$internal(rhs);
+            }
+        }
+
+        impl<const P: u64> $trait<Self> for &Gf<P> {
+            type Output = Gf<P>;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$internal(*rhs)
+            }
+        }
+
+        impl<const P: u64> $trait<Gf<P>> for &Gf<P> {
+            type Output = Gf<P>;
+            fn $method(self, rhs: Gf<P>) -> Self::Output {
+                self.$internal(rhs)
+            }
+        }
+
+        impl<const P: u64> $trait<&Self> for Gf<P> {
+            type Output = Self;
+            fn $method(self, rhs: &Self) -> Self::Output {
+                self.$internal(*rhs)
+            }
+        }
+
+        impl<const P: u64> $trait<Self> for Gf<P> {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$internal(rhs)
+            }
+        }
+    };
+}
+
+impl_operation!(Add, add, AddAssign, add_assign, default_add);
+impl_operation!(Sub, sub, SubAssign, sub_assign, default_sub);
+impl_operation!(Mul, mul, MulAssign, mul_assign, default_mul);
+impl_operation!(Div, div, DivAssign, div_assign, default_div);
+
+impl<const P: u64> Neg for Gf<P> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.default_neg()
+    }
+}
+
+impl<const P: u64> Neg for &Gf<P> {
+    type Output = Gf<P>;
+    fn neg(self) -> Self::Output {
+        self.default_neg()
+    }
+}
+
+impl<const P: u64> Sum for Gf<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0), |acc, new| acc + new)
+    }
+}
+
+impl<const P: u64> Product for Gf<P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), |acc, new| acc * new)
+    }
+}
+
+#[allow(clippy::op_ref)]
+#[cfg(test)]
+mod test {
+    use crate::Gf;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn arithmetic() {
+        let (lhs, rhs) = (Gf::<7>::new(5), Gf::<7>::new(4));
+        assert_eq!(lhs + rhs, Gf::new(2));
+        assert_eq!(&lhs - &rhs, Gf::new(1));
+        assert_eq!(&rhs - lhs, Gf::new(6));
+        assert_eq!(lhs * &rhs, Gf::new(6));
+        assert_eq!(lhs / rhs, Gf::new(3));
+        assert_eq!(-lhs, Gf::new(2));
+        assert_eq!(-Gf::<7>::new(0), Gf::new(0));
+
+        let mut nb = Gf::<2>::new(1);
+        nb += Gf::new(1);
+        assert_eq!(nb, Gf::new(0));
+        nb -= &Gf::new(1);
+        nb *= Gf::new(1);
+        nb /= Gf::new(1);
+        assert_eq!(nb, Gf::new(1));
+
+        // No overflow close to `u64::MAX`
+        let big = Gf::<18_446_744_073_709_551_557>::new(18_446_744_073_709_551_556);
+        assert_eq!(big + big, Gf::new(18_446_744_073_709_551_555));
+        assert_eq!(big * big, Gf::new(1));
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let sum: Gf<5> = (1..=4).map(Gf::new).sum();
+        assert_eq!(sum, Gf::new(0));
+        // Wilson's theorem: (p - 1)! = -1 mod p
+        let product: Gf<11> = (1..11).map(Gf::new).product();
+        assert_eq!(product, Gf::new(10));
+    }
+
+    #[test]
+    #[should_panic = "attempt to divide by zero in GF(5)"]
+    fn division_by_zero() {
+        let _ = Gf::<5>::new(1) / Gf::new(0);
+    }
+}
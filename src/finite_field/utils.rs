@@ -0,0 +1,181 @@
+use std::fmt::Display;
+
+use crate::{
+    static_asserts::AssertPrime,
+    traits::{Conjugate, Divisor, IsField, IsOne, IsZero, One, Zero},
+};
+
+use super::Gf;
+
+impl<const P: u64> Gf<P> {
+    ///
+    /// Returns `value` modulo `P`.
+    ///
+    #[must_use]
+    #[allow(clippy::no_effect, path_statements)]
+    pub const fn new(value: u64) -> Self {
+        AssertPrime::<P>::OK;
+        Self { value: value % P }
+    }
+
+    ///
+    /// Returns the representative of the element in `0..P`.
+    ///
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+
+    ///
+    /// Returns `self` raised to the power `exponent`, by squaring.
+    ///
+    /// # Complexity
+    /// Time: O(log(exponent))
+    ///
+    #[must_use]
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    ///
+    /// Returns the multiplicative inverse of the element, or `None` if it is
+    /// zero.
+    ///
+    /// Since `P` is prime, this is `self ^ (P - 2)` by Fermat's little theorem.
+    ///
+    #[must_use]
+    pub fn inverse(self) -> Option<Self> {
+        if self.value == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+impl<const P: u64> From<u64> for Gf<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for Gf<P> {
+    fn from(value: i64) -> Self {
+        let reduced = Self::new(value.unsigned_abs());
+        if value < 0 {
+            -reduced
+        } else {
+            reduced
+        }
+    }
+}
+
+impl<const P: u64> Default for Gf<P> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const P: u64> Zero for Gf<P> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const P: u64> One for Gf<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const P: u64> IsZero for Gf<P> {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const P: u64> IsZero for &Gf<P> {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const P: u64> IsOne for Gf<P> {
+    #[inline(always)]
+    fn is_one(&self) -> bool {
+        self.value == 1
+    }
+}
+
+impl<const P: u64> IsOne for &Gf<P> {
+    #[inline(always)]
+    fn is_one(&self) -> bool {
+        self.value == 1
+    }
+}
+
+impl<const P: u64> Divisor for Gf<P> {
+    #[inline(always)]
+    fn can_be_divisor(&self) -> bool {
+        self.value != 0
+    }
+}
+
+impl<const P: u64> IsField for Gf<P> {
+    const IS_FIELD: bool = true;
+}
+
+impl<const P: u64> Conjugate for Gf<P> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<const P: u64> Display for Gf<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        traits::{IsOne, IsZero},
+        Gf,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn construction() {
+        assert_eq!(Gf::<7>::new(10).value(), 3);
+        assert_eq!(Gf::<7>::from(-1_i64).value(), 6);
+        assert_eq!(Gf::<7>::from(-14_i64).value(), 0);
+        assert!(Gf::<7>::new(14).is_zero());
+        assert!(Gf::<7>::new(8).is_one());
+        assert_eq!(Gf::<5>::new(3).to_string(), "3");
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(Gf::<7>::new(0).inverse(), None);
+        for value in 1..7 {
+            let nb = Gf::<7>::new(value);
+            assert!((nb * nb.inverse().unwrap()).is_one());
+        }
+        let nb = Gf::<18_446_744_073_709_551_557>::new(123_456_789);
+        assert!((nb * nb.inverse().unwrap()).is_one());
+        assert_eq!(Gf::<13>::new(2).pow(12), Gf::new(1));
+    }
+}
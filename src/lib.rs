@@ -19,8 +19,13 @@ pub mod complex;
 pub mod const_matrix;
 pub mod const_vector;
 pub mod error;
+pub mod finite_field;
+pub mod linear_operator;
 pub mod macros;
 pub mod matrix;
+pub mod polynomial;
+pub mod rational;
+pub mod solvers;
 pub mod traits;
 pub mod utils;
 pub mod vector;
@@ -30,7 +35,10 @@ mod static_asserts;
 pub use crate::complex::Complex;
 pub use crate::const_matrix::ConstMatrix;
 pub use crate::const_vector::ConstVector;
+pub use crate::finite_field::Gf;
 pub use crate::matrix::Matrix;
+pub use crate::polynomial::Polynomial;
+pub use crate::rational::Rational;
 pub use crate::vector::Vector;
 
 #[cfg(test)]
@@ -47,3 +55,16 @@ macro_rules! assert_eq_float {
 
 #[cfg(test)]
 pub(crate) use assert_eq_float;
+
+///
+/// Asserts that two float matrices have the same size, and that their
+/// elements are equal within the tolerance of [`assert_eq_float`].
+///
+#[cfg(test)]
+#[track_caller]
+pub(crate) fn assert_approx(lhs: &Matrix<f64>, rhs: &Matrix<f64>) {
+    assert_eq!(lhs.size(), rhs.size());
+    for (lhs, rhs) in lhs.iter().zip(rhs.iter()) {
+        assert_eq_float!(lhs, *rhs);
+    }
+}
@@ -0,0 +1,49 @@
+//!
+//! Linear operators: anything that can multiply a [`Vector`].
+//!
+//! Algorithms that only need the products of a matrix, or of its transpose,
+//! by vectors are written once against the [`LinearOperator`] trait, and work
+//! on [`Matrix`](crate::Matrix), [`ConstMatrix`](crate::ConstMatrix),
+//! [`SparseMatrix`](crate::matrix::SparseMatrix) and matrix-free operators
+//! built from closures with [`FnOperator`] alike:
+//! - the iterative [`solvers`](crate::solvers),
+//! - [`power_iteration`], for the dominant eigenvalue,
+//! - [`estimate_norm_1`], for the 1-norm.
+//!
+
+use crate::{matrix::Dimensions, Vector};
+
+mod function;
+mod norm_estimate;
+mod power_iteration;
+
+pub use function::FnOperator;
+pub use norm_estimate::estimate_norm_1;
+pub use power_iteration::power_iteration;
+
+///
+/// A linear map from vectors of size `dimensions().width` to vectors of size
+/// `dimensions().height`.
+///
+pub trait LinearOperator<K: Clone> {
+    ///
+    /// Returns the dimensions of the matrix of the operator.
+    ///
+    fn dimensions(&self) -> Dimensions;
+
+    ///
+    /// Returns the product of the operator by `vec`.
+    ///
+    /// `vec` must have `dimensions().width` elements: the result is
+    /// non-sensical otherwise.
+    ///
+    fn apply(&self, vec: &Vector<K>) -> Vector<K>;
+
+    ///
+    /// Returns the product of the transpose of the operator by `vec`.
+    ///
+    /// `vec` must have `dimensions().height` elements: the result is
+    /// non-sensical otherwise.
+    ///
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K>;
+}
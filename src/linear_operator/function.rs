@@ -0,0 +1,118 @@
+use crate::{matrix::Dimensions, Vector};
+
+use super::LinearOperator;
+
+///
+/// A matrix-free [`LinearOperator`], defined by two closures computing its
+/// products by a vector and the products of its transpose.
+///
+/// # Example
+/// ```
+/// use matrix::{
+///     linear_operator::{FnOperator, LinearOperator},
+///     matrix::Dimensions,
+///     Vector,
+/// };
+///
+/// // Sums up the elements of a vector of size 3
+/// let sum = FnOperator::new(
+///     Dimensions { width: 3, height: 1 },
+///     |vec: &Vector<i32>| Vector::from([vec.iter().sum()]),
+///     |vec: &Vector<i32>| Vector::fill(&vec[0], 3),
+/// );
+/// assert_eq!(sum.apply(&Vector::from([1, 2, 3])), [6]);
+/// assert_eq!(sum.apply_transpose(&Vector::from([2])), [2, 2, 2]);
+/// ```
+///
+#[derive(Debug, Clone, Copy)]
+pub struct FnOperator<F, G> {
+    dimensions: Dimensions,
+    apply: F,
+    apply_transpose: G,
+}
+
+impl<F, G> FnOperator<F, G> {
+    ///
+    /// Creates an operator with the given dimensions, where `apply` computes
+    /// `A * x` and `apply_transpose` computes `Aᵀ * x`.
+    ///
+    pub const fn new(dimensions: Dimensions, apply: F, apply_transpose: G) -> Self {
+        Self {
+            dimensions,
+            apply,
+            apply_transpose,
+        }
+    }
+}
+
+impl<K, F, G> LinearOperator<K> for FnOperator<F, G>
+where
+    K: Clone,
+    F: Fn(&Vector<K>) -> Vector<K>,
+    G: Fn(&Vector<K>) -> Vector<K>,
+{
+    fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        (self.apply)(vec)
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        (self.apply_transpose)(vec)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        linear_operator::LinearOperator, matrix::Dimensions, solvers::conjugate_gradient, Vector,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::FnOperator;
+
+    ///
+    /// Returns the product of the `[-1, 2, -1]` tridiagonal matrix by `vec`.
+    ///
+    fn laplacian(vec: &Vector<f64>) -> Vector<f64> {
+        let size = vec.len();
+        let result: Vec<f64> = (0..size)
+            .map(|index| {
+                let mut value = 2. * vec[index];
+                if index > 0 {
+                    value -= vec[index - 1];
+                }
+                if index + 1 < size {
+                    value -= vec[index + 1];
+                }
+                value
+            })
+            .collect();
+        Vector::from(result)
+    }
+
+    #[test]
+    fn matrix_free() {
+        let size = 50;
+        let operator = FnOperator::new(
+            Dimensions {
+                width: size,
+                height: size,
+            },
+            laplacian,
+            laplacian,
+        );
+        let ones = Vector::fill(&1., size);
+        assert_eq!(operator.apply(&ones), operator.apply_transpose(&ones));
+        assert_eq!(operator.apply(&ones)[0], 1.);
+
+        let report = conjugate_gradient(&operator, &ones, &1e-10, 100).unwrap();
+        assert!(report.converged());
+        for value in &laplacian(report.solution()) {
+            assert!((value - 1.).abs() < 1e-6);
+        }
+    }
+}
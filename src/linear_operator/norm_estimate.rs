@@ -0,0 +1,128 @@
+use std::ops::{AddAssign, Div, Mul, Neg};
+
+use crate::{
+    traits::{Abs, One},
+    Vector,
+};
+
+use super::LinearOperator;
+
+/// Past this number of iterations, the estimate of the 1-norm is returned as is.
+const MAX_ESTIMATE_ITERATIONS: usize = 5;
+
+///
+/// Estimates the 1-norm `‖A‖₁` of an operator, with Hager's method.
+///
+/// It climbs the convex function `x -> ‖A * x‖₁` on the unit ball of the
+/// 1-norm, whose maximum is reached on a vector of the canonical basis. The
+/// estimate is a lower bound of the norm, and is exact most of the time.
+///
+/// # Example
+/// ```
+/// use matrix::{linear_operator::estimate_norm_1, Matrix};
+///
+/// let mat = Matrix::from([[1., -2.], [3., 4.]]);
+/// assert_eq!(estimate_norm_1(&mat), 6.);
+/// ```
+///
+/// # Complexity
+/// At most 5 products by `A` and by `Aᵀ`, and
+/// Time: O(n + m)
+/// Space: O(n + m)
+///
+pub fn estimate_norm_1<K>(operator: &impl LinearOperator<K>) -> K
+where
+    for<'a> K: Clone + Default + One + Abs + PartialOrd + Neg<Output = K> + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K>,
+{
+    let width = operator.dimensions().width;
+    if width == 0 {
+        return K::default();
+    }
+    let one = K::one();
+    let mut count = K::default();
+    for _ in 0..width {
+        count += &one;
+    }
+    let mut x = Vector::fill(&(&one / &count), width);
+    let mut estimate = K::default();
+    for iteration in 0..MAX_ESTIMATE_ITERATIONS {
+        let y = operator.apply(&x);
+        let mut y_norm = K::default();
+        for value in &y {
+            y_norm += &value.abs();
+        }
+        if iteration > 0 && y_norm <= estimate {
+            break;
+        }
+        estimate = y_norm;
+
+        let signs: Vec<K> = y
+            .iter()
+            .map(|value| {
+                if *value >= K::default() {
+                    one.clone()
+                } else {
+                    -one.clone()
+                }
+            })
+            .collect();
+        let z = operator.apply_transpose(&Vector::from(signs));
+        let mut z_dot_x = K::default();
+        for (z, x) in z.iter().zip(x.iter()) {
+            z_dot_x += &(z * x);
+        }
+        let (index, z_max) =
+            z.iter()
+                .map(Abs::abs)
+                .enumerate()
+                .fold((0, K::default()), |best, (index, value)| {
+                    if value > best.1 {
+                        (index, value)
+                    } else {
+                        best
+                    }
+                });
+        // `x` is a local maximum
+        if z_max <= z_dot_x {
+            break;
+        }
+        x = Vector::fill(&K::default(), width);
+        if let Some(elt) = x.get_mut(index) {
+            *elt = one.clone();
+        }
+    }
+    estimate
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{linear_operator::estimate_norm_1, matrix::SparseMatrix, ConstMatrix, Matrix};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn exact_estimates() {
+        let mat = Matrix::from([[1., -5., 3.], [4., 2., -6.]]);
+        assert_eq!(estimate_norm_1(&mat), 9.);
+        assert_eq!(estimate_norm_1(&SparseMatrix::from(&mat)), 9.);
+
+        let mat = ConstMatrix::from([[2., 0., 1.], [-1., 3., 0.], [0., 1., -4.]]);
+        assert_eq!(estimate_norm_1(&mat), 5.);
+
+        let mat = Matrix::from([[0., 0.], [0., 0.]]);
+        assert_eq!(estimate_norm_1(&mat), 0.);
+    }
+
+    #[test]
+    fn lower_bound() {
+        let mat = Matrix::from([
+            [1., -1., 2., 0.5],
+            [-3., 2., 0., 1.],
+            [0.5, 4., -1., -2.],
+            [2., 0., 1., 3.],
+        ]);
+        let estimate = estimate_norm_1(&mat);
+        assert!(estimate <= mat.norm_1());
+        assert!(estimate * 3. >= mat.norm_1());
+    }
+}
@@ -0,0 +1,223 @@
+use std::ops::{AddAssign, Div, Mul, SubAssign};
+
+use crate::{
+    error::PowerIterationError,
+    traits::{IsZero, One, Sqrt},
+    utils::{dot, norm},
+    Vector,
+};
+
+use super::LinearOperator;
+
+/// Seed of the start vectors, so that the results are reproducible.
+const SEED: u64 = 0x853c_49e6_748f_ea9b;
+
+///
+/// Returns the dominant eigenvalue of a square operator, the one with the
+/// biggest absolute value, along with a unit eigenvector for it.
+///
+/// The iterations start from a deterministic pseudo-random vector, which
+/// has no reason to be orthogonal to the dominant eigenvector, and restart
+/// from another one if they fall in the kernel of the operator. They stop
+/// once `‖A * x - λ * x‖` is at most `tolerance * ‖A * x‖`, and are meant
+/// for real numbers.
+///
+/// # Example
+/// ```
+/// use matrix::{linear_operator::power_iteration, Matrix};
+///
+/// let mat = Matrix::from([[2., 1.], [1., 2.]]);
+/// let (eigenvalue, eigenvector) = power_iteration(&mat, &1e-12, 100).unwrap();
+/// assert!((eigenvalue - 3_f64).abs() < 1e-12);
+/// assert!((eigenvector[0] - eigenvector[1]).abs() < 1e-10);
+/// ```
+///
+/// # Errors
+/// If the operator is not square, returns a [`NotSquareOperator`](PowerIterationError::NotSquareOperator)
+/// If the iterations don't converge in `max_iterations`, returns a [`NoConvergence`](PowerIterationError::NoConvergence)
+///
+/// # Complexity
+/// Each iteration takes one product by `A`, and
+/// Time: O(n)
+/// Space: O(n)
+///
+pub fn power_iteration<K>(
+    operator: &impl LinearOperator<K>,
+    tolerance: &K,
+    max_iterations: usize,
+) -> Result<(K, Vector<K>), PowerIterationError>
+where
+    for<'a> K: Clone + Default + One + Sqrt + PartialOrd + AddAssign<&'a K> + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    let dimensions = operator.dimensions();
+    if dimensions.width != dimensions.height {
+        return Err(PowerIterationError::NotSquareOperator);
+    }
+    let size = dimensions.width;
+    if size == 0 {
+        return Ok((K::default(), Vector::from(Vec::new())));
+    }
+
+    let mut seed = SEED;
+    let mut vector = pseudo_random_unit_vector(size, &mut seed);
+    for _ in 0..max_iterations {
+        let product = operator.apply(&vector);
+        let product_norm = norm(&product);
+        // `x` is in the kernel: unless the operator is zero, another start
+        // vector gets out of it
+        if (&product_norm).is_zero() {
+            if is_zero_operator(operator, size) {
+                return Ok((K::default(), vector));
+            }
+            vector = pseudo_random_unit_vector(size, &mut seed);
+            continue;
+        }
+        // Rayleigh quotient, since `x` has a unit norm
+        let eigenvalue = dot(&vector, &product);
+        let mut residual = product.clone();
+        for (residual, elt) in residual.iter_mut().zip(vector.iter()) {
+            *residual -= &(&eigenvalue * elt);
+        }
+        if norm(&residual) <= tolerance * &product_norm {
+            return Ok((eigenvalue, vector));
+        }
+        let next: Vec<K> = product.iter().map(|elt| elt / &product_norm).collect();
+        vector = Vector::from(next);
+    }
+    Err(PowerIterationError::NoConvergence)
+}
+
+///
+/// Returns a unit vector of size `size`, whose elements are proportional to
+/// integers between -8 and 8 drawn from `seed`, which is updated.
+///
+/// The elements are never zero, and built from additions only, so that no
+/// conversion into `K` is needed.
+///
+fn pseudo_random_unit_vector<K>(size: usize, seed: &mut u64) -> Vector<K>
+where
+    for<'a> K: Clone + Default + One + Sqrt + AddAssign<&'a K> + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K>,
+{
+    let one = K::one();
+    let mut content = Vec::with_capacity(size);
+    for _ in 0..size {
+        // Linear congruential generator, whose high bits are the best ones
+        *seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let magnitude = (*seed >> 33) % 8 + 1;
+        let negative = (*seed >> 63) == 1;
+        let mut elt = K::default();
+        for _ in 0..magnitude {
+            if negative {
+                elt -= &one;
+            } else {
+                elt += &one;
+            }
+        }
+        content.push(elt);
+    }
+    let vector_norm = norm(&content);
+    Vector::from(
+        content
+            .iter()
+            .map(|elt| elt / &vector_norm)
+            .collect::<Vec<K>>(),
+    )
+}
+
+///
+/// Returns whether the operator sends every vector of the canonical basis,
+/// and thus every vector, to zero.
+///
+fn is_zero_operator<K>(operator: &impl LinearOperator<K>, size: usize) -> bool
+where
+    for<'a> K: Clone + Default + One + Sqrt + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + IsZero,
+{
+    (0..size).all(|index| {
+        let mut basis = vec![K::default(); size];
+        basis[index] = K::one();
+        (&norm(&operator.apply(&Vector::from(basis)))).is_zero()
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::{pseudo_random_unit_vector, SEED};
+    use crate::{
+        error::PowerIterationError, linear_operator::power_iteration, matrix::SparseMatrix,
+        utils::dot, ConstMatrix, Matrix,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn example() {
+        let mat = Matrix::from([[4_f64, 1., 0.], [1., 3., 1.], [0., 1., 2.]]);
+        let (eigenvalue, eigenvector) = power_iteration(&mat, &1e-10, 1000).unwrap();
+        let product = mat.mul_vec(&eigenvector).unwrap();
+        for (product, elt) in product.iter().zip(eigenvector.iter()) {
+            assert!((product - eigenvalue * elt).abs() < 1e-8);
+        }
+        // The eigenvalues are 3 and 3 ± sqrt(3)
+        assert!((eigenvalue - (3. + 3_f64.sqrt())).abs() < 1e-8);
+
+        let (sparse_eigenvalue, _) =
+            power_iteration(&SparseMatrix::from(&mat), &1e-10, 1000).unwrap();
+        assert!((sparse_eigenvalue - eigenvalue).abs() < 1e-12);
+
+        // Negative dominant eigenvalue
+        let mat = ConstMatrix::from([[-5_f64, 0.], [0., 2.]]);
+        let (eigenvalue, _) = power_iteration(&mat, &1e-10, 1000).unwrap();
+        assert!((eigenvalue + 5.).abs() < 1e-8);
+    }
+
+    #[test]
+    fn symmetric_start() {
+        // The all-equal vector is an eigenvector for 1, not for the dominant 3
+        let mat = Matrix::from([[2_f64, -1.], [-1., 2.]]);
+        let (eigenvalue, _) = power_iteration(&mat, &1e-10, 1000).unwrap();
+        assert!((eigenvalue - 3.).abs() < 1e-8);
+
+        // The all-equal vector is in the kernel, while the dominant eigenvalue is 2
+        let mat = Matrix::from([[1_f64, -1.], [-1., 1.]]);
+        let (eigenvalue, _) = power_iteration(&mat, &1e-10, 1000).unwrap();
+        assert!((eigenvalue - 2.).abs() < 1e-8);
+    }
+
+    #[test]
+    fn kernel() {
+        // `v * v^T`, with `v` orthogonal to the first start vector, sends it to zero
+        let start = pseudo_random_unit_vector::<f64>(2, &mut SEED.clone());
+        let (x, y) = (start[1], -start[0]);
+        let mat = Matrix::from([[x * x, x * y], [y * x, y * y]]);
+        assert_eq!(mat.mul_vec(&start).unwrap(), [0., 0.]);
+        let (eigenvalue, eigenvector) = power_iteration(&mat, &1e-10, 1000).unwrap();
+        assert!((eigenvalue - 1.).abs() < 1e-8);
+        // The eigenvector is along `v`, hence orthogonal to the start vector
+        assert!(dot(&eigenvector, &start).abs() < 1e-8);
+
+        let mat = Matrix::from([[0_f64, 0.], [0., 0.]]);
+        let (eigenvalue, _) = power_iteration(&mat, &1e-10, 1000).unwrap();
+        assert_eq!(eigenvalue, 0.);
+    }
+
+    #[test]
+    fn errors() {
+        let mat = Matrix::from([[1., 2., 3.]]);
+        assert_eq!(
+            power_iteration(&mat, &1e-10, 10),
+            Err(PowerIterationError::NotSquareOperator)
+        );
+
+        // A rotation has no real eigenvalue
+        let mat = Matrix::from([[0., -1.], [1., 0.]]);
+        assert_eq!(
+            power_iteration(&mat, &1e-10, 100),
+            Err(PowerIterationError::NoConvergence)
+        );
+    }
+}
@@ -1,16 +1,46 @@
+mod access;
+mod adjugate;
 mod basic_operations;
+mod characteristic_polynomial;
+mod cholesky;
 mod determinant;
+mod eigenvalues;
+mod functions;
 mod inverse;
+mod kronecker;
+mod linear_operator;
+mod lu;
 mod matrix_mul;
 mod multiplicative_trace;
+mod normal_form;
+mod norms;
+mod power;
+mod pseudo_inverse;
+mod qr;
 mod rank;
 mod reduced_row_echelon;
+mod solve;
+mod sparse;
+mod structured;
+mod subspaces;
+mod svd;
+mod symmetric_eigen;
 mod trace;
 mod transpose;
 mod utils;
 mod vector_mul;
+mod view;
 
+pub use access::MatrixAccess;
+pub use lu::LuDecomposition;
+pub use normal_form::{HermiteNormalForm, SmithNormalForm};
+pub use qr::QrDecomposition;
+pub use sparse::SparseMatrix;
+pub use structured::{Banded, LowerTriangular, Tridiagonal, UpperTriangular};
+pub use svd::SvdDecomposition;
+pub use symmetric_eigen::SymmetricEigen;
 pub use utils::TermByTermMul;
+pub use view::{MatrixView, MatrixViewMut, WithoutLineColumn};
 
 ///
 /// A structure that store the size of a Matrix
@@ -28,7 +58,7 @@ pub struct Dimensions {
 /// Implementation of a Matrix
 ///
 #[must_use]
-pub struct Matrix<K: Clone> {
+pub struct Matrix<K> {
     content: Vec<K>,
     dimensions: Dimensions,
 }
@@ -0,0 +1,64 @@
+//!
+//! Read-only access to the elements of a matrix, whatever their layout.
+//!
+//! [`Matrix`], [`MatrixView`](super::MatrixView),
+//! [`MatrixViewMut`](super::MatrixViewMut) and
+//! [`WithoutLineColumn`](super::WithoutLineColumn) all implement
+//! [`MatrixAccess`], so that the algorithms that only read the elements are
+//! written once and take any of them.
+//!
+
+use crate::Matrix;
+
+use super::Dimensions;
+
+///
+/// Something whose elements can be read by `(line, column)`.
+///
+pub trait MatrixAccess<K> {
+    ///
+    /// Returns the dimensions of the matrix.
+    ///
+    fn dimensions(&self) -> Dimensions;
+
+    ///
+    /// Returns the element at `(line, column)`, or `None` if it is out of the
+    /// matrix.
+    ///
+    fn get(&self, line: usize, column: usize) -> Option<&K>;
+
+    ///
+    /// Returns a [`Matrix`] holding a copy of the elements.
+    ///
+    /// # Complexity
+    /// Time: O(mn) for a `m` * `n` matrix.
+    /// Space: O(mn)
+    ///
+    fn to_matrix(&self) -> Matrix<K>
+    where
+        K: Clone,
+    {
+        let dimensions = self.dimensions();
+        let content = (0..dimensions.height)
+            .flat_map(|line| (0..dimensions.width).map(move |column| (line, column)))
+            .filter_map(|(line, column)| self.get(line, column).cloned())
+            .collect();
+        Matrix {
+            content,
+            dimensions,
+        }
+    }
+}
+
+impl<K> MatrixAccess<K> for Matrix<K> {
+    fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn get(&self, line: usize, column: usize) -> Option<&K> {
+        if line >= self.dimensions.height || column >= self.dimensions.width {
+            return None;
+        }
+        self.content.get(line * self.dimensions.width + column)
+    }
+}
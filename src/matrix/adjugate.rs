@@ -0,0 +1,293 @@
+//!
+//! Minors, cofactor matrix and adjugate of a square [`Matrix`].
+//!
+//! # Implementations details
+//!
+//! None of them divides: the minors of up to `4×4` use the closed-form
+//! determinants, and the bigger ones the constant term of the
+//! [characteristic polynomial](Matrix#method.characteristic_polynomial).
+//! This makes them exact over integers and polynomials, where
+//! `A * adj(A) = det(A) * I` gives an inverse up to a single division.
+//!
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::{
+    error::{AdjugateError, MinorError},
+    traits::{IsZero, One},
+    Matrix,
+};
+
+use super::{
+    determinant::{closed_form_determinant, determinant_2, determinant_3, determinant_4},
+    MatrixAccess,
+};
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + Default + One + AddAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Neg<Output = K>
+        + IsZero,
+{
+    ///
+    /// Returns the determinant of a square matrix, without any division.
+    ///
+    fn division_free_determinant(&self) -> K {
+        let size = self.dimensions.height;
+        match size {
+            0 => K::one(),
+            1 => self[0].clone(),
+            2 => determinant_2(&[&self[0], &self[1], &self[2], &self[3]]),
+            3 => determinant_3(&[
+                &self[0], &self[1], &self[2], &self[3], &self[4], &self[5], &self[6], &self[7],
+                &self[8],
+            ]),
+            4 => determinant_4(&[
+                &self[0], &self[1], &self[2], &self[3], &self[4], &self[5], &self[6], &self[7],
+                &self[8], &self[9], &self[10], &self[11], &self[12], &self[13], &self[14],
+                &self[15],
+            ]),
+            _ => {
+                // det(x * I - A) = (-1)^n * det(A) at x = 0
+                // The polynomial is monic of degree `size`, so it has a constant coefficient
+                let constant = self
+                    .characteristic_polynomial()
+                    .unwrap_or_else(|_| unreachable!())
+                    .coefficients()
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| unreachable!());
+                if size.is_multiple_of(2) {
+                    constant
+                } else {
+                    -&constant
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns the `(line, column)` minor of a square matrix: the determinant
+    /// of the matrix without its line `line` and its column `column`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+    /// // det([[4, 6], [7, 10]])
+    /// assert_eq!(mat.minor(0, 1), Ok(-2));
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](MinorError::NotSquareMatrix)
+    /// If `line` or `column` is not in the matrix, returns an [`IndexOutOfBounds`](MinorError::IndexOutOfBounds)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^2) up to `n = 5`, O(n^4) after
+    /// Space: O(n^2)
+    ///
+    pub fn minor(&self, line: usize, column: usize) -> Result<K, MinorError> {
+        if !self.is_square() {
+            return Err(MinorError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        if line >= size || column >= size {
+            return Err(MinorError::IndexOutOfBounds(line, column));
+        }
+        Ok(self.minor_internal(line, column))
+    }
+
+    ///
+    /// Returns the minor, assuming that the matrix is square and that
+    /// `line` and `column` are valid.
+    ///
+    fn minor_internal(&self, line: usize, column: usize) -> K {
+        // The closed forms read the minor in place, the bigger ones need a copy
+        self.as_view()
+            .without_line_column(column, line)
+            .map_or_else(
+                |_| K::one(),
+                |minor| {
+                    closed_form_determinant(&minor)
+                        .unwrap_or_else(|| minor.to_matrix().division_free_determinant())
+                },
+            )
+    }
+
+    ///
+    /// Returns the cofactor matrix of a square matrix, whose `(i, j)`
+    /// element is the `(i, j)` minor, times `(-1)^(i + j)`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2], [3, 4]]);
+    /// assert_eq!(mat.cofactor_matrix().unwrap(), [[4, -3], [-2, 1]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](AdjugateError::NotSquareMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^4) up to `n = 5`, O(n^6) after
+    /// Space: O(n^2)
+    ///
+    pub fn cofactor_matrix(&self) -> Result<Self, AdjugateError> {
+        if !self.is_square() {
+            return Err(AdjugateError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        let mut content = Vec::with_capacity(size * size);
+        for line in 0..size {
+            for column in 0..size {
+                let minor = self.minor_internal(line, column);
+                if (line + column).is_multiple_of(2) {
+                    content.push(minor);
+                } else {
+                    content.push(-&minor);
+                }
+            }
+        }
+        Ok(Self {
+            content,
+            dimensions: self.dimensions,
+        })
+    }
+
+    ///
+    /// Returns the adjugate of a square matrix: the transpose of its cofactor
+    /// matrix.
+    ///
+    /// It satisfies `A * adj(A) = adj(A) * A = det(A) * I`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2, 1], [5, 3]]);
+    /// // The determinant is 1, so this is the inverse
+    /// assert_eq!(mat.adjugate().unwrap(), [[3, -1], [-5, 2]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](AdjugateError::NotSquareMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^4) up to `n = 5`, O(n^6) after
+    /// Space: O(n^2)
+    ///
+    pub fn adjugate(&self) -> Result<Self, AdjugateError> {
+        Ok(self.cofactor_matrix()?.transpose())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        error::{AdjugateError, MinorError},
+        polynomial::Polynomial,
+        rational::rat,
+        Matrix,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn minor() {
+        let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+        assert_eq!(mat.minor(0, 0), Ok(2));
+        assert_eq!(mat.minor(1, 1), Ok(-11));
+        assert_eq!(mat.minor(2, 0), Ok(-3));
+        assert_eq!(Matrix::from([[5]]).minor(0, 0), Ok(1));
+        assert_eq!(mat.minor(3, 0), Err(MinorError::IndexOutOfBounds(3, 0)));
+        assert_eq!(
+            Matrix::from([[1, 2]]).minor(0, 0),
+            Err(MinorError::NotSquareMatrix)
+        );
+    }
+
+    #[test]
+    fn adjugate() {
+        let mat = Matrix::from([[-3, 2, -5], [-1, 0, -2], [3, -4, 1]]);
+        assert_eq!(
+            mat.adjugate(),
+            Ok(Matrix::from([[-8, 18, -4], [-5, 12, -1], [4, -6, 2]]))
+        );
+        assert_eq!(
+            mat.cofactor_matrix(),
+            Ok(Matrix::from([[-8, -5, 4], [18, 12, -6], [-4, -1, 2]]))
+        );
+        assert_eq!(Matrix::from([[7]]).adjugate(), Ok(Matrix::from([[1]])));
+        assert_eq!(
+            Matrix::from([[1, 2, 3]]).adjugate(),
+            Err(AdjugateError::NotSquareMatrix)
+        );
+    }
+
+    #[test]
+    fn adjugate_identity() {
+        // A * adj(A) = det(A) * I, with minors bigger than 4x4
+        let mat = Matrix::from([
+            [2, -1, 0, 3, 1, 4],
+            [1, 3, -2, 0, 2, -1],
+            [0, 1, 4, -3, 1, 2],
+            [5, 0, 1, 2, -1, 0],
+            [-2, 2, 0, 1, 3, 1],
+            [1, 0, 3, -1, 2, 2],
+        ]);
+        let determinant = mat.fraction_free_determinant().unwrap();
+        assert_ne!(determinant, 0);
+        let product = mat.mul_mat(&mat.adjugate().unwrap()).unwrap();
+        for line in 0..6 {
+            for column in 0..6 {
+                let expected = if line == column { determinant } else { 0 };
+                assert_eq!(product[line * 6 + column], expected);
+            }
+        }
+
+        let mat = Matrix::from([[rat!(1_i64, 2), rat!(2, 3)], [rat!(-1), rat!(4)]]);
+        assert_eq!(
+            mat.adjugate(),
+            Ok(Matrix::from([
+                [rat!(4), rat!(-2, 3)],
+                [rat!(1), rat!(1, 2)]
+            ]))
+        );
+    }
+
+    #[test]
+    fn polynomial_entries() {
+        // adj(x * I - A) * (x * I - A) = p(x) * I, with p the characteristic polynomial
+        let mat = Matrix::from([[1, 2, 0], [0, 3, 1], [4, 0, 2]]);
+        let x = |constant: i64| Polynomial::from([-constant, 1]);
+        let constant = |constant: i64| Polynomial::from([-constant]);
+        let shifted = Matrix::from([
+            [x(1), constant(2), constant(0)],
+            [constant(0), x(3), constant(1)],
+            [constant(4), constant(0), x(2)],
+        ]);
+        let adjugate = shifted.adjugate().unwrap();
+        let characteristic = mat.characteristic_polynomial().unwrap();
+        for line in 0..3 {
+            for column in 0..3 {
+                let mut entry = Polynomial::default();
+                for index in 0..3 {
+                    entry += &adjugate[line * 3 + index] * &shifted[index * 3 + column];
+                }
+                if line == column {
+                    assert_eq!(entry, characteristic);
+                } else {
+                    assert!(entry.is_zero());
+                }
+            }
+        }
+    }
+}
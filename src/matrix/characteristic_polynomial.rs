@@ -0,0 +1,194 @@
+//!
+//! Characteristic polynomial of a square [`Matrix`], using the Berkowitz
+//! algorithm.
+//!
+//! # Implementations details
+//!
+//! Write `A = [[a, R], [C, M]]`, with `a` a scalar and `M` the trailing
+//! submatrix. The characteristic polynomial of `A` is the product of a
+//! lower triangular Toeplitz matrix, whose first column is
+//! `1, -a, -R * C, -R * M * C, -R * M^2 * C, ...`, by the one of `M`.
+//! Starting from the last diagonal entry and growing the submatrix one line
+//! and column at a time, no division is ever needed: this works over any
+//! commutative ring, integers included.
+//!
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::{
+    error::CharacteristicPolynomialError,
+    polynomial::Polynomial,
+    traits::{IsZero, One},
+    Matrix,
+};
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + Default + One + AddAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Neg<Output = K>
+        + IsZero,
+{
+    ///
+    /// Returns the characteristic polynomial `det(x * I - A)` of a square
+    /// matrix.
+    ///
+    /// It is monic, of degree the size of the matrix. Its constant term is
+    /// `(-1)^n * det(A)`, and its `x^(n - 1)` coefficient is `-trace(A)`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{polynomial::Polynomial, Matrix};
+    ///
+    /// let mat = Matrix::from([[2, 1], [1, 2]]);
+    /// // x^2 - 4x + 3
+    /// assert_eq!(
+    ///     mat.characteristic_polynomial(),
+    ///     Ok(Polynomial::from([3, -4, 1]))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](CharacteristicPolynomialError::NotSquareMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^4)
+    /// Space: O(n)
+    ///
+    pub fn characteristic_polynomial(
+        &self,
+    ) -> Result<Polynomial<K>, CharacteristicPolynomialError> {
+        if !self.is_square() {
+            return Err(CharacteristicPolynomialError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+
+        // Coefficients of the characteristic polynomial of the trailing
+        // submatrix, from the leading one down
+        let mut coefficients = vec![K::one()];
+        for corner in (0..size).rev() {
+            let trailing = size - corner - 1;
+
+            // toeplitz[k + 2] = -R * M^k * C, computed as R * (M^k * C)
+            let mut toeplitz = Vec::with_capacity(trailing + 2);
+            toeplitz.push(K::one());
+            toeplitz.push(-&self[corner * size + corner]);
+            let mut power_column: Vec<K> = (corner + 1..size)
+                .map(|line| self[line * size + corner].clone())
+                .collect();
+            for _ in 0..trailing {
+                let mut product = K::default();
+                for (offset, elt) in power_column.iter().enumerate() {
+                    product += &(&self[corner * size + corner + 1 + offset] * elt);
+                }
+                toeplitz.push(-&product);
+                power_column = (corner + 1..size)
+                    .map(|line| {
+                        let mut sum = K::default();
+                        for (offset, elt) in power_column.iter().enumerate() {
+                            sum += &(&self[line * size + corner + 1 + offset] * elt);
+                        }
+                        sum
+                    })
+                    .collect();
+            }
+
+            coefficients = (0..trailing + 2)
+                .map(|index| {
+                    let mut sum = K::default();
+                    for (inner, coefficient) in coefficients.iter().enumerate().take(index + 1) {
+                        sum += &(&toeplitz[index - inner] * coefficient);
+                    }
+                    sum
+                })
+                .collect();
+        }
+
+        coefficients.reverse();
+        Ok(Polynomial::new(coefficients))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        complex::cpl, error::CharacteristicPolynomialError, polynomial::Polynomial, rational::rat,
+        Gf, Matrix,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn example() {
+        let mat = Matrix::from([[1, 2], [3, 4]]);
+        assert_eq!(
+            mat.characteristic_polynomial(),
+            Ok(Polynomial::from([-2, -5, 1]))
+        );
+
+        let mat = Matrix::from([[2, -1, 0], [-1, 2, -1], [0, -1, 2]]);
+        // x^3 - 6x^2 + 10x - 4
+        assert_eq!(
+            mat.characteristic_polynomial(),
+            Ok(Polynomial::from([-4, 10, -6, 1]))
+        );
+
+        let mat = Matrix::from([[7]]);
+        assert_eq!(
+            mat.characteristic_polynomial(),
+            Ok(Polynomial::from([-7, 1]))
+        );
+    }
+
+    #[test]
+    fn against_determinant() {
+        let mat = Matrix::from([[3, 1, -2, 5], [0, 4, 7, -1], [2, -3, 1, 6], [8, 0, -5, 2]]);
+        let poly = mat.characteristic_polynomial().unwrap();
+        assert_eq!(poly.degree(), Some(4));
+        assert_eq!(Ok(poly.coefficients()[0]), mat.determinant());
+        assert_eq!(poly.coefficients()[3], -10);
+        // Cayley-Hamilton: p(A) = 0
+        let mut value = Matrix::fill_default(4, 4).unwrap();
+        for coefficient in poly.coefficients().iter().rev() {
+            value = value.mul_mat(&mat).unwrap();
+            for index in 0..4 {
+                value[index * 4 + index] += coefficient;
+            }
+        }
+        assert_eq!(value, [[0; 4]; 4]);
+    }
+
+    #[test]
+    fn other_scalars() {
+        // Rotation by a quarter turn: x^2 + 1
+        let mat = Matrix::from([[cpl!(0, 0), cpl!(-1, 0)], [cpl!(1, 0), cpl!(0, 0)]]);
+        assert_eq!(
+            mat.characteristic_polynomial(),
+            Ok(Polynomial::from([cpl!(1, 0), cpl!(0, 0), cpl!(1, 0)]))
+        );
+
+        let mat = Matrix::from([[rat!(1_i64, 2), rat!(1)], [rat!(0), rat!(1, 3)]]);
+        assert_eq!(
+            mat.characteristic_polynomial(),
+            Ok(Polynomial::from([rat!(1, 6), rat!(-5, 6), rat!(1)]))
+        );
+
+        let mat = Matrix::from([[1, 1], [1, 0]].map(|line| line.map(Gf::<2>::new)));
+        assert_eq!(
+            mat.characteristic_polynomial(),
+            Ok(Polynomial::from([Gf::new(1), Gf::new(1), Gf::new(1)]))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            mat.characteristic_polynomial(),
+            Err(CharacteristicPolynomialError::NotSquareMatrix)
+        );
+    }
+}
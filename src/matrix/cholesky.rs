@@ -0,0 +1,225 @@
+//!
+//! Cholesky (`A = L * Lᴴ`) and `LDLᵀ` (`A = L * D * Lᴴ`) decompositions of a
+//! symmetric, or Hermitian, [`Matrix`].
+//!
+//! # Implementations details
+//!
+//! Only the lower triangle of the matrix is read: the upper triangle is
+//! assumed to be its conjugate transpose.
+//!
+//! For real matrices, `Lᴴ` is simply `Lᵀ`.
+//!
+
+use std::ops::{Div, Mul, SubAssign};
+
+use crate::{
+    error::{CholeskyError, LdltError},
+    traits::{Conjugate, IsPositive, IsZero, One, Sqrt},
+    Matrix, Vector,
+};
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + Default + Conjugate + IsPositive + Sqrt + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K>,
+{
+    ///
+    /// Returns the lower triangular matrix `L` of the Cholesky decomposition
+    /// of a positive-definite matrix, such that `self = L * Lᴴ`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[4., 2.], [2., 10.]]);
+    /// assert_eq!(mat.cholesky().unwrap(), [[2., 0.], [1., 3.]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](CholeskyError::NotSquareMatrix)
+    /// If the matrix is not positive-definite, returns a [`NotPositiveDefinite`](CholeskyError::NotPositiveDefinite)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn cholesky(&self) -> Result<Self, CholeskyError> {
+        if !self.is_square() {
+            return Err(CholeskyError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        let mut lower = Self {
+            content: vec![K::default(); size * size],
+            dimensions: self.dimensions,
+        };
+        for column in 0..size {
+            let mut diagonal = self[column * size + column].clone();
+            for index in 0..column {
+                let elt = &lower[column * size + index];
+                diagonal -= &(elt * &elt.conjugate());
+            }
+            if !diagonal.is_positive() {
+                return Err(CholeskyError::NotPositiveDefinite);
+            }
+            let diagonal = diagonal.sqrt();
+
+            for line in column + 1..size {
+                let mut elt = self[line * size + column].clone();
+                for index in 0..column {
+                    elt -=
+                        &(&lower[line * size + index] * &lower[column * size + index].conjugate());
+                }
+                lower[line * size + column] = &elt / &diagonal;
+            }
+            lower[column * size + column] = diagonal;
+        }
+        Ok(lower)
+    }
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + Default + One + Conjugate + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the `LDLᵀ` decomposition of a matrix, as the unit lower triangular
+    /// matrix `L` and the diagonal of `D`, such that `self = L * D * Lᴴ`.
+    ///
+    /// Unlike [`cholesky`](Matrix#method.cholesky), this doesn't need any
+    /// square root, and works on indefinite matrices.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[4., 2.], [2., -2.]]);
+    /// let (lower, diagonal) = mat.ldlt().unwrap();
+    /// assert_eq!(lower, [[1., 0.], [0.5, 1.]]);
+    /// assert_eq!(diagonal, [4., -3.]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](LdltError::NotSquareMatrix)
+    /// If one of the leading principal minors of the matrix is zero, returns a [`SingularLeadingMinor`](LdltError::SingularLeadingMinor)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn ldlt(&self) -> Result<(Self, Vector<K>), LdltError> {
+        if !self.is_square() {
+            return Err(LdltError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        let mut lower = Self {
+            content: vec![K::default(); size * size],
+            dimensions: self.dimensions,
+        };
+        let mut diagonal: Vec<K> = Vec::with_capacity(size);
+        for column in 0..size {
+            let mut pivot = self[column * size + column].clone();
+            for (index, factor) in diagonal.iter().enumerate() {
+                let elt = &lower[column * size + index];
+                pivot -= &(&(elt * &elt.conjugate()) * factor);
+            }
+            if (&pivot).is_zero() {
+                return Err(LdltError::SingularLeadingMinor);
+            }
+
+            for line in column + 1..size {
+                let mut elt = self[line * size + column].clone();
+                for (index, factor) in diagonal.iter().enumerate() {
+                    elt -= &(&(&lower[line * size + index]
+                        * &lower[column * size + index].conjugate())
+                        * factor);
+                }
+                lower[line * size + column] = &elt / &pivot;
+            }
+            lower[column * size + column] = K::one();
+            diagonal.push(pivot);
+        }
+        Ok((lower, Vector::from(diagonal)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        assert_eq_float,
+        complex::cpl,
+        error::{CholeskyError, LdltError},
+        Matrix, Vector,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cholesky() {
+        {
+            let mat = Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+            let res = mat.cholesky().unwrap();
+            assert_eq!(res, [[2., 0., 0.], [6., 1., 0.], [-8., 5., 3.]]);
+            assert_eq!(res.mul_mat(&res.transpose()).unwrap(), mat);
+        }
+        {
+            let mat = Matrix::from([[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+            let res = mat.cholesky().unwrap();
+            let product = res.mul_mat(&res.transpose()).unwrap();
+            for (found, expected) in product.iter().zip(mat.iter()) {
+                assert_eq_float!(found, *expected);
+            }
+        }
+    }
+
+    #[test]
+    fn ldlt() {
+        let mat = Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let (lower, diagonal) = mat.ldlt().unwrap();
+        assert_eq!(lower, [[1., 0., 0.], [3., 1., 0.], [-4., 5., 1.]]);
+        assert_eq!(diagonal, [4., 1., 9.]);
+
+        // Indefinite, but every leading minor is non-zero
+        let mat = Matrix::from([[1., 2.], [2., 1.]]);
+        let (lower, diagonal) = mat.ldlt().unwrap();
+        assert_eq!(lower, [[1., 0.], [2., 1.]]);
+        assert_eq!(diagonal, [1., -3.]);
+        assert_eq!(mat.cholesky(), Err(CholeskyError::NotPositiveDefinite));
+    }
+
+    #[test]
+    fn hermitian() {
+        let mat = Matrix::from([[cpl!(4., 0.), cpl!(2., -2.)], [cpl!(2., 2.), cpl!(6., 0.)]]);
+        let res = mat.cholesky().unwrap();
+        assert_eq!(
+            res,
+            [[cpl!(2., 0.), cpl!(0., 0.)], [cpl!(1., 1.), cpl!(2., 0.)]]
+        );
+
+        let (lower, diagonal) = mat.ldlt().unwrap();
+        assert_eq!(
+            lower,
+            [[cpl!(1., 0.), cpl!(0., 0.)], [cpl!(0.5, 0.5), cpl!(1., 0.)]]
+        );
+        assert_eq!(diagonal, Vector::from([cpl!(4., 0.), cpl!(4., 0.)]));
+    }
+
+    #[test]
+    fn errors() {
+        {
+            let mat = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+            assert_eq!(mat.cholesky(), Err(CholeskyError::NotSquareMatrix));
+            assert_eq!(mat.ldlt(), Err(LdltError::NotSquareMatrix));
+        }
+        {
+            let mat = Matrix::from([[1., 2.], [2., 4.]]);
+            assert_eq!(mat.cholesky(), Err(CholeskyError::NotPositiveDefinite));
+        }
+        {
+            let mat = Matrix::from([[0., 1.], [1., 0.]]);
+            assert_eq!(mat.ldlt(), Err(LdltError::SingularLeadingMinor));
+        }
+    }
+}
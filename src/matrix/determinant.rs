@@ -6,6 +6,9 @@
 //! Determinant for a matrix of up to `4×4` does not allocate.
 //! For bigger dimensions, the process passes by a reduced row echelon, taking
 //! far more time and space.
+//! If `K` is not a field (integers for example), the divisions of the row
+//! echelon would be truncated: the fraction-free Bareiss algorithm is used
+//! instead.
 //!
 
 use std::{
@@ -15,15 +18,17 @@ use std::{
 
 use crate::{
     error::DeterminantError,
-    traits::{IsZero, One},
+    traits::{IsField, IsZero, One},
     Matrix,
 };
 
+use super::{Dimensions, MatrixAccess};
+
 // Matrix:
 // 0 1
 // 2 3
 #[inline(always)]
-fn determinant_2<K>(content: &[&K; 4]) -> K
+pub(super) fn determinant_2<K>(content: &[&K; 4]) -> K
 where
     K: Clone + Default,
     for<'a> &'a K: Mul<&'a K, Output = K> + Sub<&'a K, Output = K>,
@@ -36,7 +41,7 @@ where
 // 3 4 5
 // 6 7 8
 #[inline(always)]
-fn determinant_3<K>(content: &[&K; 9]) -> K
+pub(super) fn determinant_3<K>(content: &[&K; 9]) -> K
 where
     K: Clone + Default,
     for<'a> &'a K: Mul<&'a K, Output = K> + Sub<&'a K, Output = K> + Add<&'a K, Output = K>,
@@ -52,7 +57,7 @@ where
 //  8  9 10 11
 // 12 13 14 15
 #[inline(always)]
-fn determinant_4<K>(content: &[&K; 16]) -> K
+pub(super) fn determinant_4<K>(content: &[&K; 16]) -> K
 where
     K: Clone + Default,
     for<'a> &'a K: Mul<&'a K, Output = K> + Sub<&'a K, Output = K> + Add<&'a K, Output = K>,
@@ -107,9 +112,35 @@ where
             ]))
 }
 
+///
+/// Returns the determinant of a square matrix of up to `4×4` with the closed
+/// forms, reading its elements in place, or `None` for the other sizes.
+///
+pub(super) fn closed_form_determinant<K>(mat: &impl MatrixAccess<K>) -> Option<K>
+where
+    K: Clone + Default,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Sub<&'a K, Output = K> + Add<&'a K, Output = K>,
+{
+    let Dimensions { width, height } = mat.dimensions();
+    if width != height {
+        return None;
+    }
+    let at = |index: usize| {
+        mat.get(index / width, index % width)
+            .unwrap_or_else(|| unreachable!())
+    };
+    match width {
+        1 => Some(at(0).clone()),
+        2 => Some(determinant_2(&std::array::from_fn(at))),
+        3 => Some(determinant_3(&std::array::from_fn(at))),
+        4 => Some(determinant_4(&std::array::from_fn(at))),
+        _ => None,
+    }
+}
+
 impl<'a, K> Matrix<K>
 where
-    K: Clone + Default + 'a + Neg<Output = K> + One,
+    K: Clone + Default + 'a + Neg<Output = K> + One + IsField,
     for<'b> &'b K: Sub<&'b K, Output = K> + Mul<&'b K, Output = K> + Add<&'b K, Output = K>,
     for<'b> K: Display
         + Clone
@@ -120,11 +151,15 @@ where
         + DivAssign<&'b K>,
     for<'b> &'b K: PartialEq + Mul<&'b K, Output = K> + Div<&'b K, Output = K> + IsZero,
 {
-    // ! I know it looks terrible, but it's the only way to do it without cloning the matrix
-    // ! Moreover, it's the way that takes the less time to compute
     ///
     /// Returns the determinant of a matrix.
     ///
+    /// The element type must implement [`IsField`]: a custom element type
+    /// needs an implementation to keep calling this method. When it is not a
+    /// field, like the integers, the determinant is computed by
+    /// [`fraction_free_determinant`](Matrix::fraction_free_determinant),
+    /// which stays exact.
+    ///
     /// # Example:
     /// ```
     /// use matrix::Matrix;
@@ -137,18 +172,11 @@ where
     /// If the matrix is not a square, returns a [`NotSquareMatrix`](DeterminantError::NotSquareMatrix)
     ///
     pub fn determinant(&self) -> Result<K, DeterminantError> {
+        if let Some(determinant) = closed_form_determinant(self) {
+            return Ok(determinant);
+        }
         match self.size() {
-            (1, 1) => Ok(self.content[0].clone()),
-            (2, 2) => Ok(determinant_2(&[&self[0], &self[1], &self[2], &self[3]])),
-            (3, 3) => Ok(determinant_3(&[
-                &self[0], &self[1], &self[2], &self[3], &self[4], &self[5], &self[6], &self[7],
-                &self[8],
-            ])),
-            (4, 4) => Ok(determinant_4(&[
-                &self[0], &self[1], &self[2], &self[3], &self[4], &self[5], &self[6], &self[7],
-                &self[8], &self[9], &self[10], &self[11], &self[12], &self[13], &self[14],
-                &self[15],
-            ])),
+            (x, y) if x == y && !K::IS_FIELD => self.fraction_free_determinant(),
             (x, y) if x == y => {
                 let (row_echelon, swaps, factor) = self.row_echelon_internal();
                 let mut trace = row_echelon.multiplicative_trace_internal();
@@ -164,10 +192,84 @@ where
     }
 }
 
+impl<K> Matrix<K>
+where
+    K: Clone + Default + Neg<Output = K> + One,
+    for<'a> &'a K:
+        Sub<&'a K, Output = K> + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the determinant of a matrix, using the Bareiss algorithm.
+    ///
+    /// Every division it does is exact, so the result is exact for integers,
+    /// and every intermediate value is a minor of the matrix, which limits
+    /// their growth.
+    ///
+    /// # Example:
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2, 1, 0], [1, 2, 1], [0, 1, 2]]);
+    /// assert_eq!(mat.fraction_free_determinant().unwrap(), 4)
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](DeterminantError::NotSquareMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn fraction_free_determinant(&self) -> Result<K, DeterminantError> {
+        if !self.is_square() {
+            return Err(DeterminantError::NotSquareMatrix);
+        }
+        let size = self.dimensions.width;
+        if size == 0 {
+            return Ok(K::one());
+        }
+        let mut matrix = self.clone();
+        let mut previous_pivot = K::one();
+        let mut negate = false;
+        for pivot_index in 0..size - 1 {
+            if (&matrix[pivot_index * size + pivot_index]).is_zero() {
+                let Some(line) = (pivot_index + 1..size)
+                    .find(|line| !(&matrix[line * size + pivot_index]).is_zero())
+                else {
+                    return Ok(K::default());
+                };
+                matrix.swap_line(pivot_index, line);
+                negate = !negate;
+            }
+            let pivot = matrix[pivot_index * size + pivot_index].clone();
+            for line in pivot_index + 1..size {
+                let factor = matrix[line * size + pivot_index].clone();
+                for column in pivot_index + 1..size {
+                    let product = &(&matrix[line * size + column] * &pivot)
+                        - &(&factor * &matrix[pivot_index * size + column]);
+                    // Sylvester's identity ensures that this division is exact
+                    matrix[line * size + column] = &product / &previous_pivot;
+                }
+            }
+            previous_pivot = pivot;
+        }
+        let determinant = matrix[size * size - 1].clone();
+        if negate {
+            Ok(-determinant)
+        } else {
+            Ok(determinant)
+        }
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test {
     use crate::assert_eq_float;
     use crate::complex::cpl;
+    use crate::error::DeterminantError;
+    use crate::rational::rat;
     use crate::Matrix;
 
     #[test]
@@ -221,6 +323,85 @@ mod test {
         println!("det({u}) = {res:?}");
     }
 
+    #[test]
+    fn integers() {
+        {
+            // L * U, with a unit lower triangular L: the determinant is the
+            // product of the diagonal of U
+            let lower = Matrix::from([
+                [1_i64, 0, 0, 0, 0, 0],
+                [3, 1, 0, 0, 0, 0],
+                [-2, 4, 1, 0, 0, 0],
+                [5, 1, -3, 1, 0, 0],
+                [0, 2, 7, -1, 1, 0],
+                [1, -6, 2, 3, 4, 1],
+            ]);
+            let upper = Matrix::from([
+                [2_i64, 1, -3, 4, 0, 2],
+                [0, 3, 5, -1, 2, 1],
+                [0, 0, -1, 2, 6, -4],
+                [0, 0, 0, 5, 1, 3],
+                [0, 0, 0, 0, 1, -2],
+                [0, 0, 0, 0, 0, 2],
+            ]);
+            let u = lower.mul_mat(&upper).unwrap();
+            assert_eq!(u.determinant(), Ok(-60));
+            assert_eq!(u.fraction_free_determinant(), Ok(-60));
+        }
+        {
+            // Needs line swaps
+            let u = Matrix::from([
+                [0_i32, 1, 0, 0, 0],
+                [0, 0, 0, 2, 0],
+                [3, 0, 0, 0, 0],
+                [0, 0, 0, 0, 1],
+                [0, 0, 5, 0, 0],
+            ]);
+            assert_eq!(u.determinant(), Ok(30));
+        }
+        {
+            let u = Matrix::from([
+                [1_i32, 2, 3, 4, 5],
+                [2, 4, 6, 8, 10],
+                [0, 1, 0, 1, 0],
+                [7, 0, 2, 1, 1],
+                [3, 3, 3, 3, 4],
+            ]);
+            assert_eq!(u.determinant(), Ok(0));
+        }
+    }
+
+    #[test]
+    fn fraction_free() {
+        let u = Matrix::from([
+            [8., 5., -2., 4., 4.],
+            [2.5, 20., 4., 8., 5.],
+            [1., 4., 28., -4., 17.],
+            [1., 4., 2., 0.5, 41.],
+            [21., 8., 5., 10., 24.],
+        ]);
+        assert_eq_float!(u.fraction_free_determinant().unwrap(), -627_635.25);
+        let u = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(
+            u.fraction_free_determinant(),
+            Err(DeterminantError::NotSquareMatrix)
+        );
+    }
+
+    #[test]
+    fn with_rational() {
+        let u = Matrix::from([
+            [rat!(1_i64, 2), rat!(1, 3), rat!(0), rat!(0), rat!(1)],
+            [rat!(1), rat!(1, 4), rat!(2), rat!(0), rat!(0)],
+            [rat!(0), rat!(1), rat!(1, 5), rat!(3), rat!(0)],
+            [rat!(0), rat!(0), rat!(1), rat!(1, 6), rat!(4)],
+            [rat!(5), rat!(0), rat!(0), rat!(1), rat!(1, 7)],
+        ]);
+        let expected = u.fraction_free_determinant();
+        assert_eq!(u.determinant(), expected);
+        assert_eq!(u.lu().map(|lu| lu.determinant()).ok(), expected.ok());
+    }
+
     #[test]
     fn with_complex() {
         let u = Matrix::from([
@@ -0,0 +1,423 @@
+//!
+//! Eigenvalues of a general real square [`Matrix`], using a reduction to the
+//! Hessenberg form, followed by the Francis double shift QR algorithm.
+//!
+//! # Implementations details
+//!
+//! The matrix is first reduced to an upper Hessenberg matrix (zero under its
+//! first subdiagonal) with Householder similarity transforms, that keep its
+//! eigenvalues.
+//! Implicit double shift QR steps then bring it to a quasi-triangular form,
+//! where each `1` * `1` diagonal block is a real eigenvalue, and each `2` * `2`
+//! diagonal block holds a pair of complex conjugate eigenvalues. Since the
+//! shifts come in conjugate pairs, all the computations stay in real
+//! arithmetic.
+//!
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{
+    error::EigenvaluesError,
+    traits::{Abs, Epsilon, IsZero, One, Sqrt},
+    utils::norm,
+    Complex, Matrix, Vector,
+};
+
+/// Number of QR steps allowed to isolate a single eigenvalue.
+const MAX_ITERATIONS: usize = 30;
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Sqrt
+        + Abs
+        + Epsilon
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>
+        + IsZero,
+{
+    ///
+    /// Returns the eigenvalues of a square matrix, sorted by decreasing real
+    /// part, then by decreasing imaginary part.
+    ///
+    /// Complex eigenvalues of a real matrix come in conjugate pairs, that are
+    /// next to each other in the result.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Complex, Matrix};
+    ///
+    /// let mat = Matrix::from([[0., -1.], [1., 0.]]);
+    /// assert_eq!(
+    ///     mat.eigenvalues().unwrap(),
+    ///     [Complex::new(0., 1.), Complex::new(0., -1.)]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](EigenvaluesError::NotSquareMatrix)
+    /// If the QR iterations don't converge, returns a [`NoConvergence`](EigenvaluesError::NoConvergence)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn eigenvalues(&self) -> Result<Vector<Complex<K>>, EigenvaluesError> {
+        if !self.is_square() {
+            return Err(EigenvaluesError::NotSquareMatrix);
+        }
+        let mut hessenberg = self.clone();
+        hessenberg.reduce_to_hessenberg();
+        let mut eigenvalues = hessenberg.francis_qr()?;
+        eigenvalues.sort_by(|lhs, rhs| {
+            rhs.re()
+                .partial_cmp(lhs.re())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| {
+                    rhs.im()
+                        .partial_cmp(lhs.im())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+        });
+        Ok(Vector::from(eigenvalues))
+    }
+
+    ///
+    /// Replaces a square matrix by a similar upper Hessenberg matrix, using
+    /// Householder reflections on both sides.
+    ///
+    fn reduce_to_hessenberg(&mut self) {
+        let size = self.dimensions.width;
+        for column in 0..size.saturating_sub(2) {
+            let mut reflector: Vec<K> = (column + 1..size)
+                .map(|line| self[line * size + column].clone())
+                .collect();
+            let column_norm = norm(&reflector);
+            let alpha = if reflector[0] < K::default() {
+                column_norm
+            } else {
+                -column_norm
+            };
+            reflector[0] -= &alpha;
+            let reflector_norm = norm(&reflector);
+            if (&reflector_norm).is_zero() {
+                continue;
+            }
+            for elt in &mut reflector {
+                *elt /= &reflector_norm;
+            }
+
+            self.reflect(&reflector, column + 1, column + 1..size);
+            self[(column + 1) * size + column] = alpha;
+            for line in column + 2..size {
+                self[line * size + column] = K::default();
+            }
+            for line in 0..size {
+                let mut dot = K::default();
+                for (offset, elt) in reflector.iter().enumerate() {
+                    dot += &(elt * &self[line * size + column + 1 + offset]);
+                }
+                let twice_dot = &dot + &dot;
+                for (offset, elt) in reflector.iter().enumerate() {
+                    self[line * size + column + 1 + offset] -= &(elt * &twice_dot);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns the eigenvalues of an upper Hessenberg matrix, destroying it
+    /// in the process.
+    ///
+    #[allow(clippy::many_single_char_names, clippy::too_many_lines)]
+    fn francis_qr(&mut self) -> Result<Vec<Complex<K>>, EigenvaluesError> {
+        let size = self.dimensions.width;
+        let at = |line: usize, column: usize| line * size + column;
+        let one = K::one();
+        let two = &one + &one;
+        let four = &two + &two;
+        let three_quarters = &(&two + &one) / &four;
+        let seven_sixteenths = &(&(&four + &two) + &one) / &(&four * &four);
+        let epsilon = K::epsilon();
+
+        let mut matrix_norm = K::default();
+        for line in 0..size {
+            for column in line.saturating_sub(1)..size {
+                matrix_norm += &self[at(line, column)].abs();
+            }
+        }
+
+        let mut eigenvalues = Vec::with_capacity(size);
+        // The accumulated exceptional shifts
+        let mut shift = K::default();
+        let mut iterations = 0;
+        // The eigenvalues of the lines `end..` are already found
+        let mut end = size;
+        while end > 0 {
+            let last = end - 1;
+
+            // Looks for a negligible subdiagonal element, that splits the matrix
+            let mut first = last;
+            while first > 0 {
+                let mut scale =
+                    &self[at(first - 1, first - 1)].abs() + &self[at(first, first)].abs();
+                if (&scale).is_zero() {
+                    scale = matrix_norm.clone();
+                }
+                if self[at(first, first - 1)].abs() <= &epsilon * &scale {
+                    self[at(first, first - 1)] = K::default();
+                    break;
+                }
+                first -= 1;
+            }
+
+            let x = self[at(last, last)].clone();
+            if first == last {
+                eigenvalues.push(Complex::new(&x + &shift, K::default()));
+                end -= 1;
+                iterations = 0;
+                continue;
+            }
+            let y = self[at(last - 1, last - 1)].clone();
+            let w = &self[at(last, last - 1)] * &self[at(last - 1, last)];
+            if first + 1 == last {
+                // A `2` * `2` block: its eigenvalues are the roots of a quadratic
+                let p = &(&y - &x) / &two;
+                let q = &(&p * &p) + &w;
+                let z = q.abs().sqrt();
+                let x = &x + &shift;
+                if q < K::default() {
+                    let real = &x + &p;
+                    eigenvalues.push(Complex::new(real.clone(), z.clone()));
+                    eigenvalues.push(Complex::new(real, -z));
+                } else {
+                    let z = if p < K::default() { &p - &z } else { &p + &z };
+                    let larger = &x + &z;
+                    let smaller = if (&z).is_zero() {
+                        larger.clone()
+                    } else {
+                        &x - &(&w / &z)
+                    };
+                    eigenvalues.push(Complex::new(larger, K::default()));
+                    eigenvalues.push(Complex::new(smaller, K::default()));
+                }
+                end -= 2;
+                iterations = 0;
+                continue;
+            }
+
+            if iterations == MAX_ITERATIONS {
+                return Err(EigenvaluesError::NoConvergence);
+            }
+            let (mut x, mut y, mut w) = (x, y, w);
+            if iterations == 10 || iterations == 20 {
+                // Exceptional shift, to get out of a cycle
+                shift += &x;
+                for index in 0..=last {
+                    self[at(index, index)] -= &x;
+                }
+                let s = &self[at(last, last - 1)].abs() + &self[at(last - 1, last - 2)].abs();
+                x = &three_quarters * &s;
+                y = x.clone();
+                w = -(&(&seven_sixteenths * &s) * &s);
+            }
+            iterations += 1;
+
+            // Looks for two consecutive small subdiagonal elements, to start
+            // the QR step as low as possible
+            let mut start = last - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = &self[at(start, start)];
+                let r_shift = &x - z;
+                let s_shift = &y - z;
+                p = &(&(&(&r_shift * &s_shift) - &w) / &self[at(start + 1, start)])
+                    + &self[at(start, start + 1)];
+                q = &(&(&self[at(start + 1, start + 1)] - z) - &r_shift) - &s_shift;
+                r = self[at(start + 2, start + 1)].clone();
+                let scale = &(&p.abs() + &q.abs()) + &r.abs();
+                p /= &scale;
+                q /= &scale;
+                r /= &scale;
+                if start == first {
+                    break;
+                }
+                let u = &self[at(start, start - 1)].abs() * &(&q.abs() + &r.abs());
+                let v = &p.abs()
+                    * &(&(&self[at(start - 1, start - 1)].abs() + &z.abs())
+                        + &self[at(start + 1, start + 1)].abs());
+                if u <= &epsilon * &v {
+                    break;
+                }
+                start -= 1;
+            }
+            for index in start + 2..=last {
+                self[at(index, index - 2)] = K::default();
+                if index != start + 2 {
+                    self[at(index, index - 3)] = K::default();
+                }
+            }
+
+            // Double shift QR step on the lines and columns `first..=last`
+            let mut scale = K::default();
+            for k in start..last {
+                if k != start {
+                    p = self[at(k, k - 1)].clone();
+                    q = self[at(k + 1, k - 1)].clone();
+                    r = if k + 1 == last {
+                        K::default()
+                    } else {
+                        self[at(k + 2, k - 1)].clone()
+                    };
+                    scale = &(&p.abs() + &q.abs()) + &r.abs();
+                    if !(&scale).is_zero() {
+                        p /= &scale;
+                        q /= &scale;
+                        r /= &scale;
+                    }
+                }
+                let s = (&(&(&p * &p) + &(&q * &q)) + &(&r * &r)).sqrt();
+                let s = if p < K::default() { -s } else { s };
+                if (&s).is_zero() {
+                    continue;
+                }
+                if k != start {
+                    self[at(k, k - 1)] = -(&s * &scale);
+                } else if first != start {
+                    self[at(k, k - 1)] = -self[at(k, k - 1)].clone();
+                }
+                p += &s;
+                let x = &p / &s;
+                let y = &q / &s;
+                let z = &r / &s;
+                q /= &p;
+                r /= &p;
+                for column in k..=last {
+                    let mut dot = &self[at(k, column)] + &(&q * &self[at(k + 1, column)]);
+                    if k + 1 != last {
+                        dot += &(&r * &self[at(k + 2, column)]);
+                        self[at(k + 2, column)] -= &(&dot * &z);
+                    }
+                    self[at(k + 1, column)] -= &(&dot * &y);
+                    self[at(k, column)] -= &(&dot * &x);
+                }
+                for line in first..=last.min(k + 3) {
+                    let mut dot = &(&x * &self[at(line, k)]) + &(&y * &self[at(line, k + 1)]);
+                    if k + 1 != last {
+                        dot += &(&z * &self[at(line, k + 2)]);
+                        self[at(line, k + 2)] -= &(&dot * &r);
+                    }
+                    self[at(line, k + 1)] -= &(&dot * &q);
+                    self[at(line, k)] -= &dot;
+                }
+            }
+        }
+        Ok(eigenvalues)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{assert_eq_float, complex::cpl, error::EigenvaluesError, Complex, Matrix};
+    use pretty_assertions::assert_eq;
+
+    fn assert_eigenvalues(mat: &Matrix<f64>, expected: &[Complex<f64>]) {
+        let res = mat.eigenvalues().unwrap();
+        assert_eq!(res.len(), expected.len());
+        for (found, expected) in res.iter().zip(expected) {
+            assert_eq_float!(found.re(), *expected.re());
+            assert_eq_float!(found.im(), *expected.im());
+        }
+    }
+
+    #[test]
+    fn real_eigenvalues() {
+        assert_eigenvalues(
+            &Matrix::from([[2., 0.], [0., 3.]]),
+            &[cpl!(3., 0.), cpl!(2., 0.)],
+        );
+        assert_eigenvalues(
+            &Matrix::from([[6., -11., 6.], [1., 0., 0.], [0., 1., 0.]]),
+            &[cpl!(3., 0.), cpl!(2., 0.), cpl!(1., 0.)],
+        );
+        assert_eigenvalues(
+            &Matrix::from([[2., 0., 0.], [0., 3., 4.], [0., 4., 9.]]),
+            &[cpl!(11., 0.), cpl!(2., 0.), cpl!(1., 0.)],
+        );
+        {
+            let mat = Matrix::from([
+                [4., 1., -2., 2.],
+                [1., 2., 0., 1.],
+                [-2., 0., 3., -2.],
+                [2., 1., -2., -1.],
+            ]);
+            let symmetric = mat.symmetric_eigen().unwrap();
+            let expected: Vec<_> = symmetric
+                .eigenvalues()
+                .iter()
+                .rev()
+                .map(|value| cpl!(*value, 0.))
+                .collect();
+            assert_eigenvalues(&mat, &expected);
+        }
+    }
+
+    #[test]
+    fn complex_eigenvalues() {
+        assert_eigenvalues(
+            &Matrix::from([[0., -1.], [1., 0.]]),
+            &[cpl!(0., 1.), cpl!(0., -1.)],
+        );
+        assert_eigenvalues(
+            &Matrix::from([[1., -2., 0.], [2., 1., 0.], [0., 0., 3.]]),
+            &[cpl!(3., 0.), cpl!(1., 2.), cpl!(1., -2.)],
+        );
+        // Companion matrix of (x^2 + 1) * (x - 2) * (x + 1)
+        assert_eigenvalues(
+            &Matrix::from([
+                [1., 1., 1., 2.],
+                [1., 0., 0., 0.],
+                [0., 1., 0., 0.],
+                [0., 0., 1., 0.],
+            ]),
+            &[cpl!(2., 0.), cpl!(0., 1.), cpl!(0., -1.), cpl!(-1., 0.)],
+        );
+    }
+
+    #[test]
+    fn trace_and_determinant() {
+        let mat = Matrix::from([
+            [1., 2., 3., 4., 5.],
+            [-2., 0., 1., 7., 1.],
+            [3., 3., -1., 0., 2.],
+            [0., 5., 1., 2., -3.],
+            [1., -1., 4., 2., 0.],
+        ]);
+        let res = mat.eigenvalues().unwrap();
+        let sum = res.iter().fold(cpl!(0., 0.), |acc, value| acc + *value);
+        let product = res.iter().fold(cpl!(1., 0.), |acc, value| acc * *value);
+        assert_eq_float!(sum.re(), mat.trace().unwrap());
+        assert_eq_float!(sum.im(), 0.);
+        assert_eq_float!(product.re(), mat.determinant().unwrap());
+        assert_eq_float!(product.im(), 0.);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Matrix::from([[1., 2., 3.], [4., 5., 6.]]).eigenvalues(),
+            Err(EigenvaluesError::NotSquareMatrix)
+        );
+    }
+}
@@ -0,0 +1,393 @@
+//!
+//! Exponential, square root and logarithm of a real square [`Matrix`].
+//!
+//! # Implementations details
+//!
+//! - The exponential uses scaling and squaring: the matrix is divided by `2^s`
+//!   until its norm is at most `1/2`, its exponential is approximated by the
+//!   `[6/6]` Padé approximant, which is then squared `s` times.
+//! - The square root uses the Denman-Beavers iteration, which converges
+//!   quadratically to the principal square root.
+//! - The logarithm uses inverse scaling and squaring: square roots are taken
+//!   until the matrix is close to the identity, where the series of
+//!   `log(I + X)` converges quickly, and the result is multiplied back by
+//!   `2^k`.
+//!
+
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    error::{ExpError, LogError, SqrtError},
+    traits::{Abs, Epsilon, IsFinite, IsZero, One, Sqrt},
+    Matrix,
+};
+
+/// Degree of the Padé approximant of the exponential.
+const PADE_DEGREE: u8 = 6;
+
+/// Past this number of iterations, the square root is considered not to converge.
+const MAX_ITERATIONS: usize = 100;
+
+/// Past this number of halvings, the norm of the matrix is considered infinite.
+const MAX_SQUARINGS: usize = 2048;
+
+/// Past this number of square roots, the logarithm is considered not to converge.
+const MAX_SQUARE_ROOTS: usize = 64;
+
+/// Past this number of terms, the series of the logarithm is stopped.
+const MAX_SERIES_TERMS: u8 = 64;
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Sum
+        + Abs
+        + Sqrt
+        + Epsilon
+        + IsFinite
+        + PartialOrd
+        + From<u8>
+        + Neg<Output = K>
+        + AddAssign
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>
+        + MulAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>
+        + IsZero,
+{
+    ///
+    /// Returns the exponential `I + A + A^2 / 2! + A^3 / 3! + ...` of a
+    /// square matrix.
+    ///
+    /// The solution of the differential equation `x' = A * x` is
+    /// `x(t) = exp(t * A) * x(0)`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[0., 1.], [0., 0.]]);
+    /// assert_eq!(mat.exp().unwrap(), [[1., 1.], [0., 1.]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](ExpError::NotSquareMatrix)
+    /// If an element or the norm of the matrix is infinite or NaN, returns a [`NonFiniteMatrix`](ExpError::NonFiniteMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix, with `s` the number of halvings needed to
+    /// bring its norm under `1/2`:
+    /// Time: O(n^3 * (s + 1))
+    /// Space: O(n^2)
+    ///
+    pub fn exp(&self) -> Result<Self, ExpError> {
+        if !self.is_square() {
+            return Err(ExpError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        if size == 0 {
+            return Ok(self.clone());
+        }
+
+        let (one, two) = (K::one(), K::from(2));
+        let half = &one / &two;
+        // A NaN compares to nothing, which the norm would silently skip, and
+        // an infinite norm, even of finite elements, would be halved forever
+        if !self.iter().all(IsFinite::is_finite) {
+            return Err(ExpError::NonFiniteMatrix);
+        }
+        let mut norm = self.norm_1();
+        if !norm.is_finite() {
+            return Err(ExpError::NonFiniteMatrix);
+        }
+        let mut squarings = 0;
+        let mut scaled = self.clone();
+        while norm > half {
+            if squarings == MAX_SQUARINGS {
+                return Err(ExpError::NonFiniteMatrix);
+            }
+            norm /= &two;
+            scaled *= &half;
+            squarings += 1;
+        }
+
+        // N = sum(c_k * A^k), D = sum((-1)^k * c_k * A^k), with
+        // c_k = c_(k - 1) * (q - k + 1) / ((2q - k + 1) * k)
+        let identity = Self::identity(&one, size).unwrap_or_else(|| unreachable!());
+        let mut coefficient = one;
+        let mut power = identity.clone();
+        let mut numerator = identity.clone();
+        let mut denominator = identity;
+        for index in 1..=PADE_DEGREE {
+            coefficient *= &K::from(PADE_DEGREE - index + 1);
+            coefficient /= &(&K::from(2 * PADE_DEGREE - index + 1) * &K::from(index));
+            power = power.mul_mat_internal(&scaled);
+            let term = power.clone() * &coefficient;
+            numerator += &term;
+            if index % 2 == 0 {
+                denominator += &term;
+            } else {
+                denominator -= &term;
+            }
+        }
+
+        // The denominator is well conditioned, since the norm is small
+        let mut result = denominator
+            .inverse_lu()
+            .unwrap_or_else(|_| unreachable!())
+            .mul_mat_internal(&numerator);
+        for _ in 0..squarings {
+            result = result.mul_mat_internal(&result);
+        }
+        Ok(result)
+    }
+
+    ///
+    /// Returns the principal square root of a square matrix: the only square
+    /// root whose eigenvalues all have a positive real part.
+    ///
+    /// It exists when the matrix has no eigenvalue on the closed negative
+    /// real axis.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[4., 0.], [0., 9.]]);
+    /// assert_eq!(mat.sqrt().unwrap(), [[2., 0.], [0., 3.]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](SqrtError::NotSquareMatrix)
+    /// If one of the iterates is singular, returns a [`SingularMatrix`](SqrtError::SingularMatrix)
+    /// If the iterations don't converge, returns a [`NoConvergence`](SqrtError::NoConvergence)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix, each iteration takes
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn sqrt(&self) -> Result<Self, SqrtError> {
+        if !self.is_square() {
+            return Err(SqrtError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        if size == 0 {
+            return Ok(self.clone());
+        }
+
+        let half = &K::one() / &K::from(2);
+        let threshold = K::epsilon().sqrt();
+        // Y converges to sqrt(A), and Z to its inverse
+        let mut root = self.clone();
+        let mut inverse_root = Self::identity(&K::one(), size).unwrap_or_else(|| unreachable!());
+        let mut converging = false;
+        for _ in 0..MAX_ITERATIONS {
+            let next_root = (root.clone() + &inverse_root.inverse_lu()?) * &half;
+            let next_inverse_root = (inverse_root + &root.inverse_lu()?) * &half;
+            let difference = (next_root.clone() - &root).norm_1();
+            let norm = next_root.norm_1();
+            (root, inverse_root) = (next_root, next_inverse_root);
+            // The convergence is quadratic: once the difference is around
+            // `sqrt(epsilon)`, the next iterate is as precise as it gets
+            if converging {
+                return Ok(root);
+            }
+            converging = difference <= &threshold * &norm;
+        }
+        Err(SqrtError::NoConvergence)
+    }
+
+    ///
+    /// Returns the principal logarithm of a square matrix: the only
+    /// logarithm whose eigenvalues all have an imaginary part in `]-π, π[`.
+    ///
+    /// Like the square root, it exists when the matrix has no eigenvalue on
+    /// the closed negative real axis.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 1.], [0., 1.]]);
+    /// assert_eq!(mat.log().unwrap(), [[0., 1.], [0., 0.]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](LogError::NotSquareMatrix)
+    /// If the matrix is singular, returns a [`SingularMatrix`](LogError::SingularMatrix)
+    /// If its square roots don't converge, returns a [`NoConvergence`](LogError::NoConvergence)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix, each square root and each term of the series takes
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn log(&self) -> Result<Self, LogError> {
+        if !self.is_square() {
+            return Err(LogError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        if size == 0 {
+            return Ok(self.clone());
+        }
+
+        let identity = Self::identity(&K::one(), size).unwrap_or_else(|| unreachable!());
+        let quarter = &K::one() / &K::from(4);
+        let mut root = self.clone();
+        let mut square_roots = 0;
+        while (root.clone() - &identity).norm_1() > quarter {
+            if square_roots == MAX_SQUARE_ROOTS {
+                return Err(LogError::NoConvergence);
+            }
+            root = root.sqrt().map_err(|error| match error {
+                SqrtError::SingularMatrix => LogError::SingularMatrix,
+                SqrtError::NoConvergence | SqrtError::NotSquareMatrix => LogError::NoConvergence,
+            })?;
+            square_roots += 1;
+        }
+
+        // log(I + X) = X - X^2 / 2 + X^3 / 3 - ...
+        let offset = root - &identity;
+        let mut power = offset.clone();
+        let mut result = offset.clone();
+        for index in 2..=MAX_SERIES_TERMS {
+            power = power.mul_mat_internal(&offset);
+            let mut term = power.clone();
+            term *= &(&K::one() / &K::from(index));
+            if term.norm_1() <= &K::epsilon() * &result.norm_1() {
+                break;
+            }
+            if index % 2 == 0 {
+                result -= &term;
+            } else {
+                result += &term;
+            }
+        }
+
+        let two = K::from(2);
+        for _ in 0..square_roots {
+            result *= &two;
+        }
+        Ok(result)
+    }
+
+    ///
+    /// Returns the inverse of the matrix, with partial pivoting.
+    ///
+    fn inverse_lu(&self) -> Result<Self, SqrtError> {
+        self.lu()
+            .map_err(|_| SqrtError::NotSquareMatrix)?
+            .inverse()
+            .map_err(|_| SqrtError::SingularMatrix)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        assert_approx, assert_eq_float,
+        error::{ExpError, LogError, SqrtError},
+        Matrix,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn exp() {
+        let mat = Matrix::from([[1., 0.], [0., 2.]]);
+        assert_approx(
+            &mat.exp().unwrap(),
+            &Matrix::from([[1_f64.exp(), 0.], [0., 2_f64.exp()]]),
+        );
+
+        // x'' = -x: the exponential is a rotation
+        let mat = Matrix::from([[0., 1.], [-1., 0.]]);
+        let angle = 3_f64;
+        let res = (mat * &angle).exp().unwrap();
+        assert_approx(
+            &res,
+            &Matrix::from([[angle.cos(), angle.sin()], [-angle.sin(), angle.cos()]]),
+        );
+
+        // Not diagonalizable
+        let mat = Matrix::from([[2., 1.], [0., 2.]]);
+        let exp_2 = 2_f64.exp();
+        assert_approx(
+            &mat.exp().unwrap(),
+            &Matrix::from([[exp_2, exp_2], [0., exp_2]]),
+        );
+
+        // A large norm needs many squarings
+        let mat = Matrix::from([[-10., 0.], [0., 5.]]);
+        let res = mat.exp().unwrap();
+        assert_eq_float!(res[0], (-10_f64).exp());
+        assert!((res[3] / 5_f64.exp() - 1.).abs() < 1e-12);
+
+        assert_eq!(
+            Matrix::from([[f64::INFINITY, 0.], [0., 1.]]).exp(),
+            Err(ExpError::NonFiniteMatrix)
+        );
+        assert_eq!(
+            Matrix::from([[f64::NAN, 0.], [0., 1.]]).exp(),
+            Err(ExpError::NonFiniteMatrix)
+        );
+        // Every element is finite, but the norm overflows
+        assert_eq!(
+            Matrix::from([[1e308_f64, 0.], [1e308, 0.]]).exp(),
+            Err(ExpError::NonFiniteMatrix)
+        );
+    }
+
+    #[test]
+    fn sqrt() {
+        let mat = Matrix::from([[33., 24.], [48., 57.]]);
+        let res = mat.sqrt().unwrap();
+        assert_approx(&res, &Matrix::from([[5., 2.], [4., 7.]]));
+
+        let mat = Matrix::from([[4., 1., 0.], [1., 5., 2.], [0., 2., 6.]]);
+        let res = mat.sqrt().unwrap();
+        assert_approx(&res.mul_mat(&res).unwrap(), &mat);
+
+        // A rotation by a quarter turn has a rotation by an eighth of a turn as root
+        let mat = Matrix::from([[0., -1.], [1., 0.]]);
+        let res = mat.sqrt().unwrap();
+        let value = std::f64::consts::FRAC_1_SQRT_2;
+        assert_approx(&res, &Matrix::from([[value, -value], [value, value]]));
+    }
+
+    #[test]
+    fn log() {
+        let mat = Matrix::from([[1., 0.], [0., 2.]]);
+        let res = mat.log().unwrap();
+        assert_approx(&res, &Matrix::from([[0., 0.], [0., 2_f64.ln()]]));
+
+        let mat = Matrix::from([[3., 1., 0.], [1., 4., 1.], [0., 1., 5.]]);
+        assert_approx(&mat.log().unwrap().exp().unwrap(), &mat);
+
+        let mat = Matrix::from([[0.5, -1.], [2., 1.5]]);
+        assert_approx(&mat.exp().unwrap().log().unwrap(), &mat);
+    }
+
+    #[test]
+    fn errors() {
+        let mat = Matrix::from([[1., 2., 3.]]);
+        assert_eq!(mat.exp(), Err(ExpError::NotSquareMatrix));
+        assert_eq!(mat.sqrt(), Err(SqrtError::NotSquareMatrix));
+        assert_eq!(mat.log(), Err(LogError::NotSquareMatrix));
+
+        let mat = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(mat.log(), Err(LogError::SingularMatrix));
+        let mat = Matrix::from([[-1., 0.], [0., 1.]]);
+        assert!(mat.sqrt().is_err());
+    }
+}
@@ -6,7 +6,7 @@ use std::ops::{Div, DivAssign, Mul, MulAssign, SubAssign};
 
 use crate::{
     error::InverseError,
-    traits::{IsZero, One},
+    traits::{ApproxZero, IsZero, One},
     Matrix,
 };
 
@@ -33,6 +33,10 @@ where
     /// If the matrix is not a square, returns a [`NotSquareMatrix`](InverseError::NotSquareMatrix)
     ///
     pub fn inverse(&self) -> Result<Self, InverseError> {
+        self.inverse_with(|x| x.is_zero())
+    }
+
+    fn inverse_with(&self, is_zero: impl Fn(&K) -> bool) -> Result<Self, InverseError> {
         if !self.is_square() {
             return Err(InverseError::NotSquareMatrix);
         }
@@ -42,7 +46,7 @@ where
             &Self::identity(&mul_identity, self.dimensions.height).unwrap(),
         )
         .unwrap();
-        return_matrix = return_matrix.reduced_row_echelon();
+        return_matrix = return_matrix.reduced_row_echelon_with(is_zero);
         for i in 0..self.dimensions.height {
             if return_matrix.get(i, i).unwrap() != &mul_identity {
                 return Err(InverseError::SingularMatrix);
@@ -57,9 +61,38 @@ where
     }
 }
 
+impl<K> Matrix<K>
+where
+    for<'a> K:
+        Clone + One + Default + MulAssign<&'a K> + SubAssign<&'a K> + DivAssign<&'a K> + ApproxZero,
+    for<'a> &'a K: PartialEq + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the inverse of a matrix, where every element whose absolute
+    /// value is at most `tolerance` is considered to be zero during the
+    /// elimination.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{error::InverseError, Matrix};
+    ///
+    /// let u = Matrix::from([[1., 2.], [1., 2. + 1e-12]]);
+    /// assert!(u.inverse().is_ok());
+    /// assert_eq!(u.inverse_with_tolerance(&1e-9), Err(InverseError::SingularMatrix));
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](InverseError::NotSquareMatrix)
+    /// If the matrix is singular, up to `tolerance`, returns a [`SingularMatrix`](InverseError::SingularMatrix)
+    ///
+    pub fn inverse_with_tolerance(&self, tolerance: &K::Tolerance) -> Result<Self, InverseError> {
+        self.inverse_with(|x| x.is_approx_zero(tolerance))
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{complex::cpl, error::InverseError, Matrix};
+    use crate::{complex::cpl, error::InverseError, rational::rat, Gf, Matrix};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -100,6 +133,35 @@ mod test {
         }
     }
 
+    #[test]
+    fn with_rational() {
+        let u = Matrix::from([[rat!(2_i64), rat!(1)], [rat!(1), rat!(3)]]);
+        assert_eq!(
+            u.inverse(),
+            Ok(Matrix::from([
+                [rat!(3, 5), rat!(-1, 5)],
+                [rat!(-1, 5), rat!(2, 5)]
+            ]))
+        );
+        let u = Matrix::from([[rat!(1_i64), rat!(2)], [rat!(1, 2), rat!(1)]]);
+        assert_eq!(u.inverse(), Err(InverseError::SingularMatrix));
+    }
+
+    #[test]
+    fn with_finite_field() {
+        let u = Matrix::from([[2, 1], [1, 3]].map(|line| line.map(Gf::<7>::new)));
+        assert_eq!(
+            u.inverse(),
+            Ok(Matrix::from(
+                [[2, 4], [4, 6]].map(|line| line.map(Gf::<7>::new))
+            ))
+        );
+        let u = Matrix::from([[1, 1], [0, 1]].map(|line| line.map(Gf::<2>::new)));
+        assert_eq!(u.inverse(), Ok(u));
+        let u = Matrix::from([[1, 2], [2, 4]].map(|line| line.map(Gf::<5>::new)));
+        assert_eq!(u.inverse(), Err(InverseError::SingularMatrix));
+    }
+
     #[test]
     fn errors() {
         {
@@ -110,6 +172,15 @@ mod test {
             let mat = Matrix::from([[1, 2], [2, 4]]);
             assert_eq!(mat.inverse(), Err(InverseError::SingularMatrix));
         }
+        {
+            let mat = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9. + 1e-13]]);
+            assert!(mat.inverse().is_ok());
+            assert_eq!(
+                mat.inverse_with_tolerance(&1e-9),
+                Err(InverseError::SingularMatrix)
+            );
+            assert_eq!(mat.inverse_with_tolerance(&0.), mat.inverse());
+        }
     }
 
     #[test]
@@ -0,0 +1,216 @@
+//!
+//! Kronecker product, direct sum and block diagonal construction of
+//! [`Matrix`].
+//!
+
+use std::ops::Mul;
+
+use crate::Matrix;
+
+use super::Dimensions;
+
+impl<K> Matrix<K>
+where
+    K: Clone,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    ///
+    /// Returns the Kronecker product `A ⊗ B` of two matrices: the block
+    /// matrix whose block `(i, j)` is `A[i][j] * B`.
+    ///
+    /// It is the matrix of the tensor product of the two linear maps, which
+    /// is what combines quantum gates acting on different qubits.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let lhs = Matrix::from([[1, 2], [3, 4]]);
+    /// let rhs = Matrix::from([[0, 1], [1, 0]]);
+    /// assert_eq!(
+    ///     lhs.kronecker(&rhs),
+    ///     [[0, 1, 0, 2], [1, 0, 2, 0], [0, 3, 0, 4], [3, 0, 4, 0]]
+    /// );
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix and a `p` * `q` matrix:
+    /// Time: O(mnpq)
+    /// Space: O(mnpq)
+    ///
+    pub fn kronecker(&self, other: &Self) -> Self {
+        let Dimensions { width, height } = self.dimensions;
+        let (other_width, other_height) = (other.dimensions.width, other.dimensions.height);
+        let mut content = Vec::with_capacity(self.content.len() * other.content.len());
+        for line in 0..height {
+            for other_line in 0..other_height {
+                for column in 0..width {
+                    let factor = &self[line * width + column];
+                    for other_column in 0..other_width {
+                        content.push(factor * &other[other_line * other_width + other_column]);
+                    }
+                }
+            }
+        }
+        Self {
+            content,
+            dimensions: Dimensions {
+                width: width * other_width,
+                height: height * other_height,
+            },
+        }
+    }
+}
+
+impl<K> Matrix<K>
+where
+    K: Clone + Default,
+{
+    ///
+    /// Returns the direct sum `A ⊕ B` of two matrices: the block diagonal
+    /// matrix `[[A, 0], [0, B]]`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let lhs = Matrix::from([[1, 2]]);
+    /// let rhs = Matrix::from([[3], [4]]);
+    /// assert_eq!(lhs.direct_sum(&rhs), [[1, 2, 0], [0, 0, 3], [0, 0, 4]]);
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix and a `p` * `q` matrix:
+    /// Time: O((m + p) * (n + q))
+    /// Space: O((m + p) * (n + q))
+    ///
+    pub fn direct_sum(&self, other: &Self) -> Self {
+        Self::block_diag_internal(&[self, other])
+    }
+
+    ///
+    /// Returns the block diagonal matrix whose diagonal blocks are `blocks`,
+    /// or `None` if `blocks` is empty.
+    ///
+    /// The blocks don't need to be square.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let blocks = [
+    ///     Matrix::from([[1]]),
+    ///     Matrix::from([[2, 3], [4, 5]]),
+    ///     Matrix::from([[6]]),
+    /// ];
+    /// assert_eq!(
+    ///     Matrix::block_diag(&blocks).unwrap(),
+    ///     [[1, 0, 0, 0], [0, 2, 3, 0], [0, 4, 5, 0], [0, 0, 0, 6]]
+    /// );
+    /// ```
+    ///
+    /// # Complexity
+    /// With `m` and `n` the sums of the heights and widths of the blocks:
+    /// Time: O(mn)
+    /// Space: O(mn)
+    ///
+    #[must_use]
+    pub fn block_diag(blocks: &[Self]) -> Option<Self> {
+        if blocks.is_empty() {
+            return None;
+        }
+        Some(Self::block_diag_internal(
+            &blocks.iter().collect::<Vec<_>>(),
+        ))
+    }
+
+    fn block_diag_internal(blocks: &[&Self]) -> Self {
+        let width: usize = blocks.iter().map(|block| block.dimensions.width).sum();
+        let height: usize = blocks.iter().map(|block| block.dimensions.height).sum();
+        let mut content = vec![K::default(); width * height];
+        let (mut first_line, mut first_column) = (0, 0);
+        for block in blocks {
+            let block_width = block.dimensions.width;
+            for (offset, line) in block.content.chunks(block_width.max(1)).enumerate() {
+                let start = (first_line + offset) * width + first_column;
+                content[start..start + block_width].clone_from_slice(line);
+            }
+            first_line += block.dimensions.height;
+            first_column += block_width;
+        }
+        Self {
+            content,
+            dimensions: Dimensions { width, height },
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{complex::cpl, Matrix};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn kronecker() {
+        let lhs = Matrix::from([[1, 2, 3]]);
+        let rhs = Matrix::from([[1], [-1]]);
+        assert_eq!(lhs.kronecker(&rhs), [[1, 2, 3], [-1, -2, -3]]);
+        assert_eq!(rhs.kronecker(&lhs), [[1, 2, 3], [-1, -2, -3]]);
+
+        // (A ⊗ B) * (C ⊗ D) = (A * C) ⊗ (B * D)
+        let a = Matrix::from([[1, 2], [0, 1]]);
+        let b = Matrix::from([[2, 0, 1], [1, 1, 0]]);
+        let c = Matrix::from([[3, -1], [1, 2]]);
+        let d = Matrix::from([[1, 0], [0, 1], [-1, 1]]);
+        assert_eq!(
+            a.kronecker(&b).mul_mat(&c.kronecker(&d)),
+            a.mul_mat(&c)
+                .and_then(|ac| Ok(ac.kronecker(&b.mul_mat(&d)?)))
+        );
+    }
+
+    #[test]
+    fn quantum_gates() {
+        // CNOT * (H ⊗ I) maps |00> to the Bell state (|00> + |11>) / sqrt(2)
+        let value = std::f64::consts::FRAC_1_SQRT_2;
+        let hadamard = Matrix::from([
+            [cpl!(value, 0.), cpl!(value, 0.)],
+            [cpl!(value, 0.), cpl!(-value, 0.)],
+        ]);
+        let identity = Matrix::identity(&cpl!(1., 0.), 2).unwrap();
+        let not = Matrix::from([[cpl!(0., 0.), cpl!(1., 0.)], [cpl!(1., 0.), cpl!(0., 0.)]]);
+        let cnot = identity.direct_sum(&not);
+        let circuit = cnot.mul_mat(&hadamard.kronecker(&identity)).unwrap();
+        let zero = cpl!(0., 0.);
+        let half = cpl!(value, 0.);
+        assert_eq!(
+            circuit
+                .get_column(0)
+                .map(|column| column.copied().collect::<Vec<_>>()),
+            Some(vec![half, zero, zero, half])
+        );
+    }
+
+    #[test]
+    fn block_diag() {
+        let blocks = [Matrix::from([[1, 2], [3, 4]]), Matrix::from([[5, 6, 7]])];
+        assert_eq!(
+            Matrix::block_diag(&blocks),
+            Some(Matrix::from([
+                [1, 2, 0, 0, 0],
+                [3, 4, 0, 0, 0],
+                [0, 0, 5, 6, 7]
+            ]))
+        );
+        assert_eq!(
+            Matrix::block_diag(&blocks[..1]),
+            Some(Matrix::from([[1, 2], [3, 4]]))
+        );
+        assert_eq!(Matrix::<i32>::block_diag(&[]), None);
+        assert_eq!(
+            blocks[0].direct_sum(&blocks[1]),
+            Matrix::block_diag(&blocks).unwrap()
+        );
+    }
+}
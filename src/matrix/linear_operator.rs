@@ -0,0 +1,65 @@
+use std::{
+    iter::Sum,
+    ops::{AddAssign, Mul},
+};
+
+use crate::{linear_operator::LinearOperator, solvers::LineEntries, Matrix, Vector};
+
+use super::Dimensions;
+
+impl<K> LinearOperator<K> for Matrix<K>
+where
+    K: Clone + Sum + Default + AddAssign,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        self.mul_vec_internal(vec.iter())
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        let width = self.dimensions.width;
+        let mut result = Vector::fill(&K::default(), width);
+        if width == 0 {
+            return result;
+        }
+        for (line, vector_elt) in self.content.chunks(width).zip(vec.iter()) {
+            for (result, matrix_elt) in result.iter_mut().zip(line) {
+                *result += matrix_elt * vector_elt;
+            }
+        }
+        result
+    }
+}
+
+impl<K: Clone> LineEntries<K> for Matrix<K> {
+    fn line_entries<'a>(&'a self, line: usize) -> impl Iterator<Item = (usize, &'a K)>
+    where
+        K: 'a,
+    {
+        let width = self.dimensions.width;
+        self.content[line * width..(line + 1) * width]
+            .iter()
+            .enumerate()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{linear_operator::LinearOperator, matrix::SparseMatrix, Matrix, Vector};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn apply_transpose() {
+        let mat = Matrix::from([[1, 0, 2], [0, 3, -1]]);
+        let vec = Vector::from([2, -1]);
+        let expected = mat.transpose().mul_vec(&vec).unwrap();
+        assert_eq!(mat.apply_transpose(&vec), expected);
+        assert_eq!(SparseMatrix::from(&mat).apply_transpose(&vec), expected);
+        assert_eq!(mat.apply(&Vector::from([1, 1, 1])), [3, 2]);
+    }
+}
@@ -0,0 +1,565 @@
+//!
+//! LU decomposition of a [`Matrix`], with partial pivoting.
+//!
+//! # Implementations details
+//!
+//! At each step, the line holding the biggest pivot (in absolute value) is
+//! swapped to the top of the remaining lines, which keeps the multipliers of
+//! `L` between `-1` and `1` for floating point numbers.
+//!
+//! Once computed, an [`LuDecomposition`] can be reused to solve as many
+//! systems as needed, or to get the determinant and the inverse of the matrix,
+//! without factoring it again.
+//!
+
+use std::ops::{AddAssign, Div, Mul, MulAssign, Neg, SubAssign};
+
+use crate::{
+    error::{InverseError, LuDecompositionError, LuSolveError},
+    linear_operator::{estimate_norm_1, LinearOperator},
+    traits::{Abs, IsZero, One},
+    Matrix, Vector,
+};
+
+use super::Dimensions;
+
+///
+/// The result of the LU decomposition of a square [`Matrix`] `A`, such that
+/// `P * A = L * U`.
+///
+/// See [`lu`](Matrix#method.lu).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct LuDecomposition<K: Clone> {
+    lower: Matrix<K>,
+    upper: Matrix<K>,
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + Default + One + Abs + PartialOrd + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the LU decomposition of a matrix, using partial pivoting.
+    ///
+    /// A singular matrix can still be decomposed: it simply yields a `U` with
+    /// a zero on its diagonal.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 2.], [2., 2.]]);
+    /// let lu = mat.lu().unwrap();
+    /// assert_eq!(lu.permutation(), &[1, 0]);
+    /// assert_eq!(lu.lower(), &Matrix::from([[1., 0.], [0.5, 1.]]));
+    /// assert_eq!(lu.upper(), &Matrix::from([[2., 2.], [0., 1.]]));
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](LuDecompositionError::NotSquareMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn lu(&self) -> Result<LuDecomposition<K>, LuDecompositionError> {
+        if !self.is_square() {
+            return Err(LuDecompositionError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        let mut upper = self.clone();
+        let mut lower = Self {
+            content: vec![K::default(); size * size],
+            dimensions: self.dimensions,
+        };
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut swaps = 0;
+
+        for column in 0..size {
+            let pivot_line = (column + 1..size).fold(column, |best, line| {
+                if upper[line * size + column].abs() > upper[best * size + column].abs() {
+                    line
+                } else {
+                    best
+                }
+            });
+            if pivot_line != column {
+                upper.swap_line(column, pivot_line);
+                // Only the multipliers already computed follow the swap
+                for index in 0..column {
+                    lower.swap(column * size + index, pivot_line * size + index);
+                }
+                permutation.swap(column, pivot_line);
+                swaps += 1;
+            }
+            lower[column * size + column] = K::one();
+
+            let pivot = upper[column * size + column].clone();
+            if (&pivot).is_zero() {
+                // The whole column is zero: there is nothing to eliminate
+                continue;
+            }
+            for line in column + 1..size {
+                let factor = &upper[line * size + column] / &pivot;
+                if (&factor).is_zero() {
+                    continue;
+                }
+                for index in column + 1..size {
+                    let tmp = &factor * &upper[column * size + index];
+                    upper[line * size + index] -= &tmp;
+                }
+                upper[line * size + column] = K::default();
+                lower[line * size + column] = factor;
+            }
+        }
+
+        Ok(LuDecomposition {
+            lower,
+            upper,
+            permutation,
+            swaps,
+        })
+    }
+}
+
+impl<K: Clone> LuDecomposition<K> {
+    ///
+    /// Returns the lower triangular matrix `L`. Its diagonal is only made of ones.
+    ///
+    pub const fn lower(&self) -> &Matrix<K> {
+        &self.lower
+    }
+
+    ///
+    /// Returns the upper triangular matrix `U`.
+    ///
+    pub const fn upper(&self) -> &Matrix<K> {
+        &self.upper
+    }
+
+    ///
+    /// Returns the permutation applied on the lines of the decomposed matrix:
+    /// the line `i` of `P * A` is the line `permutation()[i]` of `A`.
+    ///
+    #[must_use]
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    ///
+    /// Returns the sign of the permutation: `1` if an even number of lines
+    /// were swapped, `-1` otherwise.
+    ///
+    #[must_use]
+    pub const fn permutation_sign(&self) -> i8 {
+        if self.swaps.is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+
+    ///
+    /// Returns the size of the decomposed matrix.
+    ///
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.upper.dimensions.height
+    }
+}
+
+impl<K> LuDecomposition<K>
+where
+    K: Clone + Default + One,
+{
+    ///
+    /// Returns the permutation matrix `P`.
+    ///
+    pub fn permutation_matrix(&self) -> Matrix<K> {
+        let size = self.size();
+        let mut content = vec![K::default(); size * size];
+        for (line, column) in self.permutation.iter().enumerate() {
+            content[line * size + column] = K::one();
+        }
+        Matrix {
+            content,
+            dimensions: self.upper.dimensions,
+        }
+    }
+}
+
+impl<K> LuDecomposition<K>
+where
+    for<'a> K: Clone + Default + One + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns `true` if the decomposed matrix is singular, meaning that one of
+    /// the diagonal elements of `U` is zero.
+    ///
+    #[must_use]
+    pub fn is_singular(&self) -> bool {
+        let size = self.size();
+        (0..size).any(|i| (&self.upper[i * size + i]).is_zero())
+    }
+
+    ///
+    /// Returns the solution `x` of `A * x = vec`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, Vector};
+    ///
+    /// let lu = Matrix::from([[2., 0.], [0., 4.]]).lu().unwrap();
+    /// assert_eq!(lu.solve(&Vector::from([2., 2.])).unwrap(), [1., 0.5]);
+    /// assert_eq!(lu.solve(&Vector::from([4., 1.])).unwrap(), [2., 0.25]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the size of `vec` doesn't match the size of the matrix, returns a [`SizeMismatch`](LuSolveError::SizeMismatch)
+    /// If the decomposed matrix is singular, returns a [`SingularMatrix`](LuSolveError::SingularMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^2)
+    /// Space: O(n)
+    ///
+    pub fn solve(&self, vec: &Vector<K>) -> Result<Vector<K>, LuSolveError> {
+        if vec.len() != self.size() {
+            return Err(LuSolveError::SizeMismatch(self.size(), vec.len()));
+        }
+        if self.is_singular() {
+            return Err(LuSolveError::SingularMatrix);
+        }
+        Ok(Vector::from(self.solve_internal(vec)))
+    }
+
+    ///
+    /// Returns the inverse of the decomposed matrix.
+    ///
+    /// # Errors
+    /// If the decomposed matrix is singular, returns a [`SingularMatrix`](InverseError::SingularMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn inverse(&self) -> Result<Matrix<K>, InverseError> {
+        if self.is_singular() {
+            return Err(InverseError::SingularMatrix);
+        }
+        let size = self.size();
+        let mut content = vec![K::default(); size * size];
+        let mut unit = vec![K::default(); size];
+        for column in 0..size {
+            unit[column] = K::one();
+            for (line, value) in self.solve_internal(&unit).into_iter().enumerate() {
+                content[line * size + column] = value;
+            }
+            unit[column] = K::default();
+        }
+        Ok(Matrix {
+            content,
+            dimensions: Dimensions {
+                width: size,
+                height: size,
+            },
+        })
+    }
+
+    ///
+    /// Solves `L * U * x = P * vec`, assuming that `vec` has the right size
+    /// and that `U` is invertible.
+    ///
+    pub(crate) fn solve_internal(&self, vec: &[K]) -> Vec<K> {
+        let size = self.size();
+        let mut result: Vec<K> = self.permutation.iter().map(|&i| vec[i].clone()).collect();
+        // Forward substitution: `L` has a unit diagonal
+        for line in 0..size {
+            for column in 0..line {
+                let tmp = &self.lower[line * size + column] * &result[column];
+                result[line] -= &tmp;
+            }
+        }
+        // Backward substitution
+        for line in (0..size).rev() {
+            for column in line + 1..size {
+                let tmp = &self.upper[line * size + column] * &result[column];
+                result[line] -= &tmp;
+            }
+            result[line] = &result[line] / &self.upper[line * size + line];
+        }
+        result
+    }
+
+    ///
+    /// Solves `Aᵀ * x = vec`, that is `Uᵀ * Lᵀ * P * x = vec`, assuming that
+    /// `vec` has the right size and that `U` is invertible.
+    ///
+    fn solve_transpose_internal(&self, vec: &[K]) -> Vec<K> {
+        let size = self.size();
+        let mut result = vec.to_vec();
+        // Forward substitution with `Uᵀ`
+        for line in 0..size {
+            for column in 0..line {
+                let tmp = &self.upper[column * size + line] * &result[column];
+                result[line] -= &tmp;
+            }
+            result[line] = &result[line] / &self.upper[line * size + line];
+        }
+        // Backward substitution with `Lᵀ`, which has a unit diagonal
+        for line in (0..size).rev() {
+            for column in line + 1..size {
+                let tmp = &self.lower[column * size + line] * &result[column];
+                result[line] -= &tmp;
+            }
+        }
+        let mut unpermuted = vec![K::default(); size];
+        for (line, value) in self.permutation.iter().zip(result) {
+            unpermuted[*line] = value;
+        }
+        unpermuted
+    }
+}
+
+///
+/// The decomposition acts as the inverse `A⁻¹` of the decomposed matrix,
+/// which must not be singular.
+///
+impl<K> LinearOperator<K> for LuDecomposition<K>
+where
+    for<'a> K: Clone + Default + One + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    fn dimensions(&self) -> Dimensions {
+        self.upper.dimensions
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        Vector::from(self.solve_internal(vec))
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        Vector::from(self.solve_transpose_internal(vec))
+    }
+}
+
+impl<K> LuDecomposition<K>
+where
+    for<'a> K: Clone + One + MulAssign<&'a K> + Neg<Output = K>,
+{
+    ///
+    /// Returns the determinant of the decomposed matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let lu = Matrix::from([[0., 2.], [3., 4.]]).lu().unwrap();
+    /// assert_eq!(lu.determinant(), -6.);
+    /// ```
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n)
+    /// Space: O(1)
+    ///
+    #[must_use]
+    pub fn determinant(&self) -> K {
+        let size = self.size();
+        let mut determinant = K::one();
+        for i in 0..size {
+            determinant *= &self.upper[i * size + i];
+        }
+        if self.swaps.is_multiple_of(2) {
+            determinant
+        } else {
+            -determinant
+        }
+    }
+}
+
+impl<K> LuDecomposition<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Abs
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns an estimate of the reciprocal of the condition number
+    /// `‖A‖₁ * ‖A⁻¹‖₁` of the decomposed matrix, given its 1-norm `norm_1`,
+    /// like LAPACK's `gecon`.
+    ///
+    /// A result close to the machine epsilon means that solving systems with
+    /// this matrix, or inverting it, loses all precision. It is zero for a
+    /// singular matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 2.], [3., 4.]]);
+    /// let lu = mat.lu().unwrap();
+    /// // ‖A‖₁ = 6, ‖A⁻¹‖₁ = 3.5
+    /// let rcond = lu.reciprocal_condition_number(&mat.norm_1());
+    /// assert!((rcond - 1_f64 / 21.).abs() < 1e-15);
+    /// ```
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^2)
+    /// Space: O(n)
+    ///
+    #[must_use]
+    pub fn reciprocal_condition_number(&self, norm_1: &K) -> K {
+        if self.size() == 0 {
+            return K::one();
+        }
+        if self.is_singular() || norm_1.is_zero() {
+            return K::default();
+        }
+        // `‖A⁻¹‖₁`, with the decomposition acting as `A⁻¹`
+        let inverse_norm = estimate_norm_1(self);
+        &K::one() / &(norm_1 * &inverse_norm)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        assert_eq_float,
+        error::{InverseError, LuDecompositionError, LuSolveError},
+        Matrix, Vector,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn decomposition() {
+        let mat = Matrix::from([[2., 1., 1.], [4., -6., 0.], [-2., 7., 2.]]);
+        let lu = mat.lu().unwrap();
+        assert_eq!(lu.permutation(), &[1, 0, 2]);
+        assert_eq!(lu.permutation_sign(), -1);
+        assert_eq!(
+            lu.lower(),
+            &Matrix::from([[1., 0., 0.], [0.5, 1., 0.], [-0.5, 1., 1.]])
+        );
+        assert_eq!(
+            lu.upper(),
+            &Matrix::from([[4., -6., 0.], [0., 4., 1.], [0., 0., 1.]])
+        );
+        assert_eq!(
+            lu.permutation_matrix().mul_mat(&mat).unwrap(),
+            lu.lower().mul_mat(lu.upper()).unwrap()
+        );
+        assert!(!lu.is_singular());
+        assert_eq!(lu.determinant(), -16.);
+    }
+
+    #[test]
+    fn pivoting() {
+        // Without pivoting, the tiny pivot would absorb the `1` of the second line
+        let mat = Matrix::from([[1e-20, 1.], [1., 1.]]);
+        let lu = mat.lu().unwrap();
+        assert_eq!(lu.permutation(), &[1, 0]);
+        assert_eq!(lu.permutation_sign(), -1);
+        let res = lu.solve(&Vector::from([1., 2.])).unwrap();
+        assert_eq_float!(res[0], 1.);
+        assert_eq_float!(res[1], 1.);
+    }
+
+    #[test]
+    fn solve() {
+        let lu = Matrix::from([[2., 1., -1.], [-3., -1., 2.], [-2., 1., 2.]])
+            .lu()
+            .unwrap();
+        let res = lu.solve(&Vector::from([8., -11., -3.])).unwrap();
+        for (found, expected) in res.iter().zip([2., 3., -1.]) {
+            assert_eq_float!(found, expected);
+        }
+        let res = lu.solve(&Vector::from([1., -1., 1.])).unwrap();
+        for (found, expected) in res.iter().zip([0., 1., 0.]) {
+            assert_eq_float!(found, expected);
+        }
+    }
+
+    #[test]
+    fn determinant() {
+        let mat = Matrix::from([
+            [8., 5., -2., 4., 4.],
+            [2.5, 20., 4., 8., 5.],
+            [1., 4., 28., -4., 17.],
+            [1., 4., 2., 0.5, 41.],
+            [21., 8., 5., 10., 24.],
+        ]);
+        assert_eq_float!(mat.lu().unwrap().determinant(), -627_635.25);
+        let mat = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+        assert_eq_float!(mat.lu().unwrap().determinant(), -174.);
+    }
+
+    #[test]
+    fn inverse() {
+        let mat = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+        let res = mat.lu().unwrap().inverse().unwrap();
+        assert!(res.approx_eq(
+            &[
+                [0.649_425_287, 0.097_701_149, -0.655_172_414],
+                [-0.781_609_195, -0.126_436_782, 0.965_517_241],
+                [0.143_678_161, 0.074_712_644, -0.206_896_552]
+            ],
+            &0.00001
+        ));
+    }
+
+    #[test]
+    fn reciprocal_condition_number() {
+        let mat = Matrix::from([[1., 2.], [3., 4.]]);
+        let lu = mat.lu().unwrap();
+        assert_eq_float!(lu.reciprocal_condition_number(&mat.norm_1()), 1. / 21.);
+        // Solving with the transpose
+        let res = lu.solve_transpose_internal(&[1., 2.]);
+        assert_eq_float!(res[0], 1.);
+        assert_eq_float!(res[1], 0.);
+
+        let mat = Matrix::from([[0., 1., 0.], [0., 0., 1.], [1e-10, 0., 0.]]);
+        let lu = mat.lu().unwrap();
+        assert!(lu.reciprocal_condition_number(&mat.norm_1()) < 1e-9);
+    }
+
+    #[test]
+    fn errors() {
+        {
+            let mat = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+            assert_eq!(mat.lu(), Err(LuDecompositionError::NotSquareMatrix));
+        }
+        {
+            let lu = Matrix::from([[1., 2.], [2., 4.]]).lu().unwrap();
+            assert_eq!(
+                lu.solve(&Vector::from([1., 2.])),
+                Err(LuSolveError::SingularMatrix)
+            );
+            assert_eq!(lu.inverse(), Err(InverseError::SingularMatrix));
+            assert_eq!(lu.determinant(), 0.);
+        }
+        {
+            let lu = Matrix::from([[1., 2.], [3., 4.]]).lu().unwrap();
+            assert_eq!(
+                lu.solve(&Vector::from([1., 2., 3.])),
+                Err(LuSolveError::SizeMismatch(2, 3))
+            );
+        }
+    }
+}
@@ -75,12 +75,10 @@ where
     }
 
     #[inline(always)]
-    fn mul_mat_internal(&self, rhs: &Self) -> Self {
-        let mut return_matrix =
-            Self::from(self.mul_vec_internal(rhs.get_column(0).unwrap(), rhs.dimensions.height));
+    pub(super) fn mul_mat_internal(&self, rhs: &Self) -> Self {
+        let mut return_matrix = Self::from(self.mul_vec_internal(rhs.get_column(0).unwrap()));
         for index in 1..rhs.dimensions.width {
-            let return_vec =
-                self.mul_vec_internal(rhs.get_column(index).unwrap(), rhs.dimensions.height);
+            let return_vec = self.mul_vec_internal(rhs.get_column(index).unwrap());
             return_matrix.append_column(&return_vec);
         }
         return_matrix
@@ -88,10 +86,11 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test {
     use pretty_assertions::assert_eq;
 
-    use crate::{complex::cpl, Matrix};
+    use crate::{complex::cpl, Gf, Matrix};
 
     #[test]
     fn example_mat() {
@@ -121,6 +120,16 @@ mod test {
         }
     }
 
+    #[test]
+    fn rectangular() {
+        let u = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let v = Matrix::from([[1., 0., -1.], [0., 1., 1.]]);
+        let res = u.mul_mat(&v).unwrap();
+        assert_eq!(res, [[1., 2., 1.], [3., 4., 1.], [5., 6., 1.]]);
+        let res = v.mul_mat(&u).unwrap();
+        assert_eq!(res, [[-4., -4.], [8., 10.]]);
+    }
+
     #[test]
     fn mat_with_complex() {
         let u = Matrix::from([[cpl!(1, 5), cpl!(3, 2)], [cpl!(9, 0), cpl!(12, -4)]]);
@@ -134,4 +143,30 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn mat_with_finite_field() {
+        // Every codeword of the Hamming(7, 4) code has a zero syndrome
+        let generator = Matrix::from(
+            [
+                [1, 0, 0, 0, 1, 1, 0],
+                [0, 1, 0, 0, 1, 0, 1],
+                [0, 0, 1, 0, 0, 1, 1],
+                [0, 0, 0, 1, 1, 1, 1],
+            ]
+            .map(|line| line.map(Gf::<2>::new)),
+        );
+        let parity_check = Matrix::from(
+            [
+                [1, 1, 0, 1, 1, 0, 0],
+                [1, 0, 1, 1, 0, 1, 0],
+                [0, 1, 1, 1, 0, 0, 1],
+            ]
+            .map(|line| line.map(Gf::<2>::new)),
+        );
+        assert_eq!(
+            generator.mul_mat(&parity_check.transpose()),
+            Ok(Matrix::from([[Gf::new(0); 3]; 4]))
+        );
+    }
 }
@@ -0,0 +1,497 @@
+//!
+//! Hermite and Smith normal forms of an integer [`Matrix`].
+//!
+//! # Implementations details
+//!
+//! Unlike the other elimination routines of the crate, no division is ever
+//! performed: two lines (or columns) are combined with the coefficients of
+//! Bézout's identity, so that one of the two entries becomes their greatest
+//! common divisor and the other becomes zero. Such a combination has a
+//! determinant of 1, so the transforms stay unimodular.
+//!
+//! The coefficients may grow quickly on big matrices: intermediate values that
+//! don't fit in an `i64` overflow.
+//!
+
+use crate::Matrix;
+
+use super::Dimensions;
+
+///
+/// The Hermite normal form `H` of a [`Matrix`] `A`, and the unimodular matrix
+/// `U` such that `U * A = H`.
+///
+/// See [`hermite_normal_form`](Matrix#method.hermite_normal_form).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct HermiteNormalForm {
+    hermite: Matrix<i64>,
+    transform: Matrix<i64>,
+}
+
+///
+/// The Smith normal form `S` of a [`Matrix`] `A`, and the unimodular matrices
+/// `U` and `V` such that `U * A * V = S`.
+///
+/// See [`smith_normal_form`](Matrix#method.smith_normal_form).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct SmithNormalForm {
+    smith: Matrix<i64>,
+    left_transform: Matrix<i64>,
+    right_transform: Matrix<i64>,
+}
+
+///
+/// Returns `(g, x, y)`, with `g = gcd(a, b)` and `x * a + y * b = g`.
+///
+const fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_remainder, mut remainder) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while remainder != 0 {
+        let quotient = old_remainder / remainder;
+        (old_remainder, remainder) = (remainder, old_remainder - quotient * remainder);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_remainder < 0 {
+        (-old_remainder, -old_x, -old_y)
+    } else {
+        (old_remainder, old_x, old_y)
+    }
+}
+
+///
+/// Returns the coefficients `[[x, y], [-b / g, a / g]]` of the unimodular
+/// combination that sends `(a, b)` to `(g, 0)`, with `g = gcd(a, b)`.
+///
+const fn bezout_combination(a: i64, b: i64) -> [i64; 4] {
+    let (divisor, x, y) = extended_gcd(a, b);
+    [x, y, -b / divisor, a / divisor]
+}
+
+impl Matrix<i64> {
+    ///
+    /// Returns the `size` * `size` identity matrix, which may be empty.
+    ///
+    fn unimodular_identity(size: usize) -> Self {
+        let mut content = vec![0; size * size];
+        for index in 0..size {
+            content[index * size + index] = 1;
+        }
+        Self {
+            content,
+            dimensions: Dimensions {
+                width: size,
+                height: size,
+            },
+        }
+    }
+
+    ///
+    /// Replaces the lines `first` and `second` by `c[0] * first + c[1] * second`
+    /// and `c[2] * first + c[3] * second`.
+    ///
+    fn combine_lines(&mut self, first: usize, second: usize, coefficients: [i64; 4]) {
+        let width = self.dimensions.width;
+        for column in 0..width {
+            let lhs = self[first * width + column];
+            let rhs = self[second * width + column];
+            self[first * width + column] = coefficients[0] * lhs + coefficients[1] * rhs;
+            self[second * width + column] = coefficients[2] * lhs + coefficients[3] * rhs;
+        }
+    }
+
+    ///
+    /// Replaces the columns `first` and `second` by `c[0] * first + c[1] * second`
+    /// and `c[2] * first + c[3] * second`.
+    ///
+    fn combine_columns(&mut self, first: usize, second: usize, coefficients: [i64; 4]) {
+        let width = self.dimensions.width;
+        for line in 0..self.dimensions.height {
+            let lhs = self[line * width + first];
+            let rhs = self[line * width + second];
+            self[line * width + first] = coefficients[0] * lhs + coefficients[1] * rhs;
+            self[line * width + second] = coefficients[2] * lhs + coefficients[3] * rhs;
+        }
+    }
+
+    fn negate_line(&mut self, line: usize) {
+        let width = self.dimensions.width;
+        for elt in &mut self.content[line * width..(line + 1) * width] {
+            *elt = -*elt;
+        }
+    }
+
+    fn swap_column(&mut self, first: usize, second: usize) {
+        let width = self.dimensions.width;
+        for line in 0..self.dimensions.height {
+            self.content
+                .swap(line * width + first, line * width + second);
+        }
+    }
+
+    ///
+    /// Returns the Hermite normal form of an integer matrix, along with the
+    /// unimodular transform that produces it.
+    ///
+    /// The Hermite normal form is the unique row echelon form obtained by
+    /// integer line operations such that:
+    /// - every pivot is positive
+    /// - the entries above a pivot are in `0..pivot`
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2, 3], [4, 5]]);
+    /// let hnf = mat.hermite_normal_form();
+    /// assert_eq!(hnf.hermite(), &Matrix::from([[2, 0], [0, 1]]));
+    /// assert_eq!(hnf.transform().mul_mat(&mat).unwrap(), *hnf.hermite());
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix, not counting the cost of the growing integers:
+    /// Time: O(m^2 * n)
+    /// Space: O(m^2 + mn)
+    ///
+    pub fn hermite_normal_form(&self) -> HermiteNormalForm {
+        let (height, width) = self.size();
+        let mut hermite = self.clone();
+        let mut transform = Self::unimodular_identity(height);
+
+        let mut pivot_line = 0;
+        for column in 0..width {
+            if pivot_line == height {
+                break;
+            }
+            for line in pivot_line + 1..height {
+                let below = hermite[line * width + column];
+                if below != 0 {
+                    let coefficients =
+                        bezout_combination(hermite[pivot_line * width + column], below);
+                    hermite.combine_lines(pivot_line, line, coefficients);
+                    transform.combine_lines(pivot_line, line, coefficients);
+                }
+            }
+            let pivot = hermite[pivot_line * width + column];
+            if pivot == 0 {
+                continue;
+            }
+            if pivot < 0 {
+                hermite.negate_line(pivot_line);
+                transform.negate_line(pivot_line);
+            }
+            let pivot = hermite[pivot_line * width + column];
+            for line in 0..pivot_line {
+                let quotient = hermite[line * width + column].div_euclid(pivot);
+                if quotient != 0 {
+                    hermite.combine_lines(line, pivot_line, [1, -quotient, 0, 1]);
+                    transform.combine_lines(line, pivot_line, [1, -quotient, 0, 1]);
+                }
+            }
+            pivot_line += 1;
+        }
+
+        HermiteNormalForm { hermite, transform }
+    }
+
+    ///
+    /// Returns the Smith normal form of an integer matrix, along with the
+    /// unimodular transforms that produce it.
+    ///
+    /// The Smith normal form is the unique diagonal matrix obtained by integer
+    /// line and column operations such that its diagonal entries are
+    /// non-negative, and each of them divides the next one.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+    /// let snf = mat.smith_normal_form();
+    /// assert_eq!(snf.smith(), &Matrix::from([[2, 0, 0], [0, 6, 0], [0, 0, 12]]));
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix with `k = min(m, n)`, not counting the cost of
+    /// the growing integers nor the repeated passes on a same pivot:
+    /// Time: O(k * (m + n)^2)
+    /// Space: O(m^2 + n^2)
+    ///
+    pub fn smith_normal_form(&self) -> SmithNormalForm {
+        let (height, width) = self.size();
+        let mut smith = self.clone();
+        let mut left_transform = Self::unimodular_identity(height);
+        let mut right_transform = Self::unimodular_identity(width);
+
+        for diagonal in 0..height.min(width) {
+            // Moves the smallest non-zero entry of the remaining block on the diagonal
+            let mut smallest = None;
+            for line in diagonal..height {
+                for column in diagonal..width {
+                    let value = smith[line * width + column].unsigned_abs();
+                    if value != 0 && smallest.is_none_or(|(_, _, min)| value < min) {
+                        smallest = Some((line, column, value));
+                    }
+                }
+            }
+            let Some((line, column, _)) = smallest else {
+                break;
+            };
+            smith.swap_line(diagonal, line);
+            left_transform.swap_line(diagonal, line);
+            smith.swap_column(diagonal, column);
+            right_transform.swap_column(diagonal, column);
+
+            loop {
+                for line in diagonal + 1..height {
+                    let below = smith[line * width + diagonal];
+                    if below != 0 {
+                        let coefficients =
+                            bezout_combination(smith[diagonal * width + diagonal], below);
+                        smith.combine_lines(diagonal, line, coefficients);
+                        left_transform.combine_lines(diagonal, line, coefficients);
+                    }
+                }
+                let mut column_changed = false;
+                for column in diagonal + 1..width {
+                    let right = smith[diagonal * width + column];
+                    if right != 0 {
+                        let coefficients =
+                            bezout_combination(smith[diagonal * width + diagonal], right);
+                        smith.combine_columns(diagonal, column, coefficients);
+                        right_transform.combine_columns(diagonal, column, coefficients);
+                        column_changed = true;
+                    }
+                }
+                if column_changed {
+                    continue;
+                }
+
+                // The pivot must divide all of the remaining entries
+                let pivot = smith[diagonal * width + diagonal];
+                let not_divisible = (diagonal + 1..height).find(|&line| {
+                    (diagonal + 1..width).any(|column| smith[line * width + column] % pivot != 0)
+                });
+                match not_divisible {
+                    Some(line) => {
+                        smith.combine_lines(diagonal, line, [1, 1, 0, 1]);
+                        left_transform.combine_lines(diagonal, line, [1, 1, 0, 1]);
+                    }
+                    None => break,
+                }
+            }
+
+            if smith[diagonal * width + diagonal] < 0 {
+                smith.negate_line(diagonal);
+                left_transform.negate_line(diagonal);
+            }
+        }
+
+        SmithNormalForm {
+            smith,
+            left_transform,
+            right_transform,
+        }
+    }
+}
+
+impl HermiteNormalForm {
+    ///
+    /// Returns the Hermite normal form `H`.
+    ///
+    pub const fn hermite(&self) -> &Matrix<i64> {
+        &self.hermite
+    }
+
+    ///
+    /// Returns the unimodular matrix `U` such that `U * A = H`.
+    ///
+    pub const fn transform(&self) -> &Matrix<i64> {
+        &self.transform
+    }
+
+    ///
+    /// Returns the number of non-zero lines of `H`, which is the rank of `A`.
+    ///
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        let width = self.hermite.dimensions.width;
+        self.hermite
+            .content
+            .chunks(width.max(1))
+            .take_while(|line| line.iter().any(|&elt| elt != 0))
+            .count()
+    }
+}
+
+impl SmithNormalForm {
+    ///
+    /// Returns the Smith normal form `S`.
+    ///
+    pub const fn smith(&self) -> &Matrix<i64> {
+        &self.smith
+    }
+
+    ///
+    /// Returns the unimodular matrix `U` such that `U * A * V = S`.
+    ///
+    pub const fn left_transform(&self) -> &Matrix<i64> {
+        &self.left_transform
+    }
+
+    ///
+    /// Returns the unimodular matrix `V` such that `U * A * V = S`.
+    ///
+    pub const fn right_transform(&self) -> &Matrix<i64> {
+        &self.right_transform
+    }
+
+    ///
+    /// Returns the non-zero diagonal entries of `S`, in order. Each of them
+    /// divides the next one.
+    ///
+    #[must_use]
+    pub fn invariant_factors(&self) -> Vec<i64> {
+        let (height, width) = self.smith.size();
+        (0..height.min(width))
+            .map(|index| self.smith[index * width + index])
+            .take_while(|&elt| elt != 0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{traits::Gcd, Matrix};
+    use pretty_assertions::assert_eq;
+
+    fn assert_unimodular(mat: &Matrix<i64>) {
+        if mat.size() == (0, 0) {
+            return;
+        }
+        let determinant = mat.determinant();
+        assert!(
+            determinant == Ok(1) || determinant == Ok(-1),
+            "{determinant:?} is not a unit"
+        );
+    }
+
+    fn check_hermite(mat: &Matrix<i64>) {
+        let hnf = mat.hermite_normal_form();
+        assert_unimodular(hnf.transform());
+        assert_eq!(&hnf.transform().mul_mat(mat).unwrap(), hnf.hermite());
+
+        let (height, width) = mat.size();
+        let hermite = hnf.hermite();
+        let mut last_pivot = None;
+        for line in 0..height {
+            let pivot = (0..width).find(|&column| hermite[line * width + column] != 0);
+            if let Some(column) = pivot {
+                assert!(last_pivot.is_none_or(|last| last < column));
+                let pivot_value = hermite[line * width + column];
+                assert!(pivot_value > 0);
+                for above in 0..line {
+                    assert!((0..pivot_value).contains(&hermite[above * width + column]));
+                }
+                last_pivot = Some(column);
+            } else {
+                last_pivot = Some(width);
+            }
+        }
+    }
+
+    fn check_smith(mat: &Matrix<i64>) {
+        let snf = mat.smith_normal_form();
+        assert_unimodular(snf.left_transform());
+        assert_unimodular(snf.right_transform());
+        let product = snf
+            .left_transform()
+            .mul_mat(mat)
+            .unwrap()
+            .mul_mat(snf.right_transform())
+            .unwrap();
+        assert_eq!(&product, snf.smith());
+
+        let (height, width) = mat.size();
+        for line in 0..height {
+            for column in 0..width {
+                if line != column {
+                    assert_eq!(snf.smith()[line * width + column], 0);
+                }
+            }
+        }
+        let factors = snf.invariant_factors();
+        for pair in factors.windows(2) {
+            assert!(pair[0] > 0);
+            assert_eq!(pair[1] % pair[0], 0);
+        }
+    }
+
+    #[test]
+    fn hermite() {
+        let mat = Matrix::from([[2, 3, 6, 2], [5, 6, 1, 6], [8, 3, 1, 1]]);
+        let hnf = mat.hermite_normal_form();
+        assert_eq!(
+            hnf.hermite(),
+            &Matrix::from([[1, 0, 50, -11], [0, 3, 28, -2], [0, 0, 61, -13]])
+        );
+        assert_eq!(hnf.rank(), 3);
+        check_hermite(&mat);
+
+        let mat = Matrix::from([[-4, 6], [6, -9], [2, -3]]);
+        let hnf = mat.hermite_normal_form();
+        assert_eq!(hnf.hermite(), &Matrix::from([[2, -3], [0, 0], [0, 0]]));
+        assert_eq!(hnf.rank(), 1);
+        check_hermite(&mat);
+
+        check_hermite(&Matrix::from([[0, 0, 5], [0, 3, 7], [0, -6, 1]]));
+        check_hermite(&Matrix::from([[3, 1], [-7, 2], [11, 4], [0, 5]]));
+        check_hermite(&Matrix::from([[0, 0], [0, 0]]));
+
+        let mat = Matrix::from([[2, 3], [4, 5]]);
+        assert_eq!(
+            mat.hermite_normal_form().hermite(),
+            &Matrix::from([[2, 0], [0, 1]])
+        );
+    }
+
+    #[test]
+    fn smith() {
+        let mat = Matrix::from([[2, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+        let snf = mat.smith_normal_form();
+        assert_eq!(
+            snf.smith(),
+            &Matrix::from([[2, 0, 0], [0, 6, 0], [0, 0, 12]])
+        );
+        assert_eq!(snf.invariant_factors(), vec![2, 6, 12]);
+        check_smith(&mat);
+
+        // Z^2 / <(2, 0), (0, 3)> is Z/6Z
+        let mat = Matrix::from([[2, 0], [0, 3]]);
+        assert_eq!(mat.smith_normal_form().invariant_factors(), vec![1, 6]);
+        check_smith(&mat);
+
+        let mat = Matrix::from([[6, 4, 2], [9, 6, 3]]);
+        assert_eq!(mat.smith_normal_form().invariant_factors(), vec![1]);
+        check_smith(&mat);
+
+        check_smith(&Matrix::from([[3, 1], [-7, 2], [11, 4], [0, 5]]));
+        check_smith(&Matrix::from([[0, 0], [0, 0]]));
+        check_smith(&Matrix::from([[4, 6, 10], [6, 9, 15]]));
+    }
+
+    #[test]
+    fn bezout() {
+        for (a, b) in [(12, 18), (-12, 18), (7, 0), (0, -7), (-5, -15), (1, 1)] {
+            let (divisor, x, y) = super::extended_gcd(a, b);
+            assert_eq!(divisor, a.gcd(&b));
+            assert_eq!(x * a + y * b, divisor);
+        }
+    }
+}
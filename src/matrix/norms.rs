@@ -0,0 +1,283 @@
+//!
+//! Frobenius, induced and max-abs norms of a [`Matrix`], and the estimate of
+//! its condition number.
+//!
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{
+    error::ConditionNumberError,
+    traits::{Abs, Epsilon, IsZero, One, Sqrt},
+    Matrix,
+};
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + Default + Abs + Sqrt + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    ///
+    /// Returns the [`Frobenius norm`](https://en.wikipedia.org/wiki/Matrix_norm#Frobenius_norm)
+    /// of a matrix: the euclidean norm of all of its elements.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 2.], [2., 4.]]);
+    /// assert_eq!(mat.norm_frobenius(), 5.);
+    /// ```
+    ///
+    /// # Complexity
+    /// Linear: O(n) with `n` the number of elements in the matrix.
+    ///
+    #[must_use]
+    pub fn norm_frobenius(&self) -> K {
+        let mut sum = K::default();
+        for elt in &self.content {
+            let abs = elt.abs();
+            sum += &(&abs * &abs);
+        }
+        sum.sqrt()
+    }
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + Default + Abs + PartialOrd + AddAssign<&'a K>,
+{
+    ///
+    /// Returns the norm induced by the [`taxicab norm`](https://en.wikipedia.org/wiki/Taxicab_geometry)
+    /// of vectors, which is the maximum absolute column sum.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, -2], [-3, 4]]);
+    /// assert_eq!(mat.norm_1(), 6);
+    /// ```
+    ///
+    /// # Complexity
+    /// Linear: O(n) with `n` the number of elements in the matrix.
+    ///
+    #[must_use]
+    pub fn norm_1(&self) -> K {
+        let width = self.dimensions.width;
+        let mut max = K::default();
+        for column in 0..width {
+            let mut sum = K::default();
+            for line in 0..self.dimensions.height {
+                sum += &self[line * width + column].abs();
+            }
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    ///
+    /// Returns the norm induced by the [`supremum norm`](https://en.wikipedia.org/wiki/Uniform_norm)
+    /// of vectors, which is the maximum absolute line sum.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, -2], [-3, 4]]);
+    /// assert_eq!(mat.norm_inf(), 7);
+    /// ```
+    ///
+    /// # Complexity
+    /// Linear: O(n) with `n` the number of elements in the matrix.
+    ///
+    #[must_use]
+    pub fn norm_inf(&self) -> K {
+        let mut max = K::default();
+        for line in self.content.chunks(self.dimensions.width.max(1)) {
+            let mut sum = K::default();
+            for elt in line {
+                sum += &elt.abs();
+            }
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    ///
+    /// Returns the biggest absolute value among the elements of the matrix.
+    ///
+    /// It is not submultiplicative, hence not an induced norm.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, -2], [-3, 4]]);
+    /// assert_eq!(mat.norm_max(), 4);
+    /// ```
+    ///
+    /// # Complexity
+    /// Linear: O(n) with `n` the number of elements in the matrix.
+    ///
+    #[must_use]
+    pub fn norm_max(&self) -> K {
+        let mut max = K::default();
+        for elt in &self.content {
+            let abs = elt.abs();
+            if abs > max {
+                max = abs;
+            }
+        }
+        max
+    }
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Sqrt
+        + Abs
+        + Epsilon
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>,
+{
+    ///
+    /// Returns the spectral norm of a matrix, which is the norm induced by
+    /// the euclidean norm of vectors: its largest singular value.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[0., 2.], [3., 0.]]);
+    /// assert_eq!(mat.norm_2(), 3.);
+    /// ```
+    ///
+    /// # Complexity
+    /// See [`svd`](Matrix#method.svd).
+    ///
+    #[must_use]
+    pub fn norm_2(&self) -> K {
+        self.svd().norm_2()
+    }
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Abs
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns an estimate of the reciprocal of the condition number
+    /// `‖A‖₁ * ‖A⁻¹‖₁` of a square matrix, without computing its inverse.
+    ///
+    /// A result close to the machine epsilon warns that the
+    /// [`inverse`](Matrix#method.inverse) would be garbage. It is zero for a
+    /// singular matrix.
+    ///
+    /// If the LU decomposition of the matrix is needed anyway, see
+    /// [`LuDecomposition::reciprocal_condition_number`](super::LuDecomposition#method.reciprocal_condition_number).
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 1.], [1., 1. + 1e-15]]);
+    /// assert!(mat.reciprocal_condition_number().unwrap() < 1e-14);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](ConditionNumberError::NotSquareMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn reciprocal_condition_number(&self) -> Result<K, ConditionNumberError> {
+        let lu = self
+            .lu()
+            .map_err(|_| ConditionNumberError::NotSquareMatrix)?;
+        Ok(lu.reciprocal_condition_number(&self.norm_1()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        assert_eq_float, complex::cpl, error::ConditionNumberError, rational::rat, Matrix,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn norms() {
+        let mat = Matrix::from([[1., -7.], [-2., -3.]]);
+        assert_eq!(mat.norm_1(), 10.);
+        assert_eq!(mat.norm_inf(), 8.);
+        assert_eq!(mat.norm_max(), 7.);
+        assert_eq_float!(mat.norm_frobenius(), 63_f64.sqrt());
+        // Square root of the largest eigenvalue of Aᵀ * A = [[5, -1], [-1, 58]]
+        assert_eq_float!(mat.norm_2(), f64::midpoint(63., 2813_f64.sqrt()).sqrt());
+
+        let mat = Matrix::from([[rat!(1_i64, 2), rat!(-1, 3)], [rat!(1, 4), rat!(0)]]);
+        assert_eq!(mat.norm_1(), rat!(3, 4));
+        assert_eq!(mat.norm_inf(), rat!(5, 6));
+        assert_eq!(mat.norm_max(), rat!(1, 2));
+
+        let mat = Matrix::from([[cpl!(3., 4.), cpl!(0., 0.)], [cpl!(0., 12.), cpl!(0., 0.)]]);
+        assert_eq!(mat.norm_frobenius(), cpl!(13., 0.));
+    }
+
+    #[test]
+    fn condition_number() {
+        let mat = Matrix::from([[4., 0.], [0., 0.5]]);
+        assert_eq!(mat.reciprocal_condition_number(), Ok(1. / 8.));
+
+        // The estimate is exact on small matrices
+        let mat = Matrix::from([[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+        let inverse = mat.inverse().unwrap();
+        assert_eq_float!(
+            mat.reciprocal_condition_number().unwrap(),
+            1. / (mat.norm_1() * inverse.norm_1())
+        );
+
+        // Hilbert matrix
+        let mat = Matrix::from([
+            [1., 1. / 2., 1. / 3., 1. / 4.],
+            [1. / 2., 1. / 3., 1. / 4., 1. / 5.],
+            [1. / 3., 1. / 4., 1. / 5., 1. / 6.],
+            [1. / 4., 1. / 5., 1. / 6., 1. / 7.],
+        ]);
+        let estimate = mat.reciprocal_condition_number().unwrap();
+        let exact = 1. / (mat.norm_1() * mat.inverse().unwrap().norm_1());
+        assert!(estimate >= exact && estimate <= 3. * exact);
+
+        let mat = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(mat.reciprocal_condition_number(), Ok(0.));
+        let mat = Matrix::from([[1., 2.]]);
+        assert_eq!(
+            mat.reciprocal_condition_number(),
+            Err(ConditionNumberError::NotSquareMatrix)
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//!
+//! Integer power of a square [`Matrix`].
+//!
+
+use std::{
+    iter::Sum,
+    ops::{AddAssign, Mul},
+};
+
+use crate::{error::PowError, traits::One, Matrix};
+
+impl<K> Matrix<K>
+where
+    K: Clone + Sum + Default + AddAssign + One,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    ///
+    /// Returns the matrix multiplied `exponent` times by itself, using
+    /// exponentiation by squaring.
+    ///
+    /// The power `0` is the identity matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// // The Fibonacci numbers
+    /// let mat = Matrix::from([[1, 1], [1, 0]]);
+    /// assert_eq!(mat.pow(10).unwrap(), [[89, 55], [55, 34]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](PowError::NotSquareMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix:
+    /// Time: O(n^3 * log(exponent))
+    /// Space: O(n^2)
+    ///
+    pub fn pow(&self, mut exponent: u32) -> Result<Self, PowError> {
+        if !self.is_square() {
+            return Err(PowError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        let mut result = Self {
+            content: vec![K::default(); size * size],
+            dimensions: self.dimensions,
+        };
+        if size == 0 {
+            return Ok(result);
+        }
+        for index in 0..size {
+            result[index * size + index] = K::one();
+        }
+
+        let mut base = self.clone();
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_mat_internal(&base);
+            }
+            exponent >>= 1;
+            if exponent != 0 {
+                base = base.mul_mat_internal(&base);
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{complex::cpl, error::PowError, Matrix};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn example() {
+        let mat = Matrix::from([[1, 1], [1, 0]]);
+        assert_eq!(mat.pow(0), Ok(Matrix::from([[1, 0], [0, 1]])));
+        assert_eq!(mat.pow(1), Ok(mat.clone()));
+        assert_eq!(mat.pow(2), Ok(Matrix::from([[2, 1], [1, 1]])));
+        assert_eq!(
+            mat.pow(30),
+            Ok(Matrix::from([[1_346_269, 832_040], [832_040, 514_229]]))
+        );
+
+        // Nilpotent
+        let mat = Matrix::from([[0., 1., 2.], [0., 0., 3.], [0., 0., 0.]]);
+        assert_eq!(
+            mat.pow(2),
+            Ok(Matrix::from([[0., 0., 3.], [0., 0., 0.], [0., 0., 0.]]))
+        );
+        assert_eq!(mat.pow(3), Ok(Matrix::from([[0.; 3]; 3])));
+
+        // i^7 = -i
+        let mat = Matrix::from([[cpl!(0, 1), cpl!(0, 0)], [cpl!(0, 0), cpl!(0, 1)]]);
+        assert_eq!(
+            mat.pow(7),
+            Ok(Matrix::from([
+                [cpl!(0, -1), cpl!(0, 0)],
+                [cpl!(0, 0), cpl!(0, -1)]
+            ]))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mat = Matrix::from([[1, 2, 3]]);
+        assert_eq!(mat.pow(2), Err(PowError::NotSquareMatrix));
+    }
+}
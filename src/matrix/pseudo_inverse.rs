@@ -0,0 +1,186 @@
+//!
+//! Moore-Penrose pseudo-inverse of a [`Matrix`], and least squares solutions
+//! of linear systems, both using the singular value decomposition.
+//!
+//! Unlike [`inverse`](Matrix#method.inverse) and [`solve`](Matrix#method.solve),
+//! they give an answer for rectangular and rank-deficient matrices.
+//!
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Sub};
+
+use crate::{
+    error::LeastSquaresError,
+    traits::{Abs, Epsilon, One, Sqrt},
+    Matrix, Vector,
+};
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Sqrt
+        + Abs
+        + Epsilon
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>,
+{
+    ///
+    /// Returns the Moore-Penrose pseudo-inverse of a matrix.
+    ///
+    /// The singular values under `tolerance` are considered to be zero. If it
+    /// is `None`, the [`default_tolerance`](crate::matrix::SvdDecomposition#method.default_tolerance)
+    /// of the singular value decomposition is used.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2., 0.], [0., 0.], [0., 4.]]);
+    /// assert_eq!(mat.pseudo_inverse(None), [[0.5, 0., 0.], [0., 0., 0.25]]);
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix, with `k = min(m, n)`:
+    /// Time: O(mnk), for each sweep of the singular value decomposition
+    /// Space: O(mn)
+    ///
+    pub fn pseudo_inverse(&self, tolerance: Option<&K>) -> Self {
+        let svd = self.svd();
+        tolerance.map_or_else(
+            || svd.pseudo_inverse(&svd.default_tolerance()),
+            |tolerance| svd.pseudo_inverse(tolerance),
+        )
+    }
+
+    ///
+    /// Returns the least squares solution `x` of `self * x = vec`: the one
+    /// that minimizes `|self * x - vec|`, and that has the smallest norm among
+    /// those.
+    ///
+    /// For an overdetermined system, this is the best fit. For an
+    /// underdetermined one, this is the minimum norm solution.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, Vector};
+    ///
+    /// // Best line `y = a * x + b` through (0, 1), (1, 2) and (2, 4)
+    /// let mat = Matrix::from([[0_f64, 1.], [1., 1.], [2., 1.]]);
+    /// let res = mat.least_squares(&Vector::from([1., 2., 4.])).unwrap();
+    /// assert!((res[0] - 1.5).abs() < 1e-10);
+    /// assert!((res[1] - 5. / 6.).abs() < 1e-10);
+    /// ```
+    ///
+    /// # Errors
+    /// If the height of the matrix is not the size of `vec`, returns a [`SizeMismatch`](LeastSquaresError::SizeMismatch)
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix, with `k = min(m, n)`:
+    /// Time: O(mnk), for each sweep of the singular value decomposition
+    /// Space: O(mn)
+    ///
+    pub fn least_squares(&self, vec: &Vector<K>) -> Result<Vector<K>, LeastSquaresError> {
+        let (height, width) = self.size();
+        if height != vec.len() {
+            return Err(LeastSquaresError::SizeMismatch(height, vec.len()));
+        }
+        let svd = self.svd();
+        let (u, v_transpose) = (svd.u(), svd.v_transpose());
+        let size = svd.singular_values().len();
+
+        // x = V * Σ⁺ * Uᵀ * vec, one singular triplet at a time
+        let mut solution = vec![K::default(); width];
+        for index in 0..svd.rank() {
+            let mut coefficient = K::default();
+            for (line, elt) in vec.iter().enumerate() {
+                coefficient += &(&u[line * size + index] * elt);
+            }
+            coefficient /= &svd.singular_values()[index];
+            for (column, elt) in solution.iter_mut().enumerate() {
+                *elt += &(&coefficient * &v_transpose[index * width + column]);
+            }
+        }
+        Ok(Vector::from(solution))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{assert_approx, assert_eq_float, error::LeastSquaresError, Matrix, Vector};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn pseudo_inverse() {
+        {
+            let mat = Matrix::from([[4., 7.], [2., 6.]]);
+            assert_approx(&mat.pseudo_inverse(None), &mat.inverse().unwrap());
+        }
+        {
+            let mat = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+            let res = mat.pseudo_inverse(None);
+            assert_approx(
+                &res,
+                &Matrix::from([
+                    [-4. / 3., -1. / 3., 2. / 3.],
+                    [13. / 12., 1. / 3., -5. / 12.],
+                ]),
+            );
+        }
+        {
+            // Rank deficient: A * A⁺ * A = A
+            let mat = Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 1., 1.]]);
+            let res = mat.pseudo_inverse(None);
+            let product = mat.mul_mat(&res).unwrap().mul_mat(&mat).unwrap();
+            assert_approx(&product, &mat);
+            let product = res.mul_mat(&mat).unwrap().mul_mat(&res).unwrap();
+            assert_approx(&product, &res);
+        }
+        {
+            // A large tolerance discards the smallest singular value
+            let mat = Matrix::from([[3., 0.], [0., 1e-3]]);
+            assert_approx(
+                &mat.pseudo_inverse(Some(&1e-2)),
+                &Matrix::from([[1. / 3., 0.], [0., 0.]]),
+            );
+        }
+    }
+
+    #[test]
+    fn overdetermined() {
+        let mat = Matrix::from([[1., 1.], [1., 2.], [1., 3.], [1., 4.]]);
+        let res = mat.least_squares(&Vector::from([6., 5., 7., 10.])).unwrap();
+        assert_eq_float!(res[0], 3.5);
+        assert_eq_float!(res[1], 1.4);
+    }
+
+    #[test]
+    fn underdetermined() {
+        let mat = Matrix::from([[1., 1., 1.]]);
+        let res = mat.least_squares(&Vector::from([3.])).unwrap();
+        for elt in &res {
+            assert_eq_float!(elt, 1.);
+        }
+
+        let mat = Matrix::from([[1., 2.], [2., 4.]]);
+        let res = mat.least_squares(&Vector::from([5., 10.])).unwrap();
+        assert_eq_float!(res[0], 1.);
+        assert_eq_float!(res[1], 2.);
+    }
+
+    #[test]
+    fn errors() {
+        let mat = Matrix::from([[1., 1.], [1., 2.], [1., 3.]]);
+        assert_eq!(
+            mat.least_squares(&Vector::from([1., 2.])),
+            Err(LeastSquaresError::SizeMismatch(3, 2))
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//!
+//! QR decomposition of a [`Matrix`], using Householder reflections.
+//!
+//! # Implementations details
+//!
+//! For a `m` * `n` matrix, `min(m - 1, n)` reflections `H = I - 2 * v * vᵀ`
+//! are applied on the left of the matrix to zero out everything under its
+//! diagonal, giving `R`. `Q` is never built during the decomposition: only the
+//! vectors `v` are kept, and `Q = H1 * H2 * ... * Hk` is computed on demand.
+//!
+//! Two shapes are available, with `k = min(m, n)`:
+//! - The full decomposition: `Q` is `m` * `m` and `R` is `m` * `n`.
+//! - The thin decomposition: `Q` is `m` * `k` and `R` is `k` * `n`.
+//!
+
+use std::ops::{Add, AddAssign, DivAssign, Mul, Neg, SubAssign};
+
+use crate::{
+    traits::{IsZero, One, Sqrt},
+    utils::norm,
+    Matrix,
+};
+
+use super::Dimensions;
+
+///
+/// The result of the QR decomposition of a [`Matrix`] `A`, such that `A = Q * R`,
+/// with `Q` orthogonal and `R` upper triangular.
+///
+/// See [`qr`](Matrix#method.qr).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct QrDecomposition<K: Clone> {
+    /// The unit vector of the `i`th reflection, that acts on lines `i..m`
+    reflectors: Vec<Vec<K>>,
+    r: Matrix<K>,
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone
+        + Default
+        + Sqrt
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Add<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the QR decomposition of a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[3., 1.], [4., 2.], [0., 5.]]);
+    /// let qr = mat.qr();
+    /// assert_eq!(qr.thin_q().size(), (3, 2));
+    /// assert_eq!(qr.thin_r().size(), (2, 2));
+    /// assert_eq!(qr.q().size(), (3, 3));
+    /// assert_eq!(qr.r().size(), (3, 2));
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix:
+    /// Time: O(mn * min(m, n))
+    /// Space: O(mn)
+    ///
+    pub fn qr(&self) -> QrDecomposition<K> {
+        let (height, width) = self.size();
+        let mut r = self.clone();
+        let steps = width.min(height.saturating_sub(1));
+        let mut reflectors = Vec::with_capacity(steps);
+
+        for column in 0..steps {
+            let mut reflector: Vec<K> = (column..height)
+                .map(|line| r[line * width + column].clone())
+                .collect();
+            let column_norm = norm(&reflector);
+            // The sign is chosen to avoid cancellation in `x - alpha * e1`
+            let alpha = if reflector[0] < K::default() {
+                column_norm
+            } else {
+                -column_norm
+            };
+            reflector[0] -= &alpha;
+            let reflector_norm = norm(&reflector);
+            if (&reflector_norm).is_zero() {
+                // The column is already zero under the diagonal
+                reflectors.push(Vec::new());
+                continue;
+            }
+            for elt in &mut reflector {
+                *elt /= &reflector_norm;
+            }
+
+            r.reflect(&reflector, column, column + 1..width);
+            r[column * width + column] = alpha;
+            for line in column + 1..height {
+                r[line * width + column] = K::default();
+            }
+            reflectors.push(reflector);
+        }
+
+        QrDecomposition { reflectors, r }
+    }
+
+    ///
+    /// Applies `I - 2 * v * vᵀ` on the lines `first_line..` of the columns
+    /// `columns`, with `v` the `reflector`.
+    ///
+    pub(super) fn reflect(
+        &mut self,
+        reflector: &[K],
+        first_line: usize,
+        columns: impl Iterator<Item = usize>,
+    ) {
+        let width = self.dimensions.width;
+        for column in columns {
+            let mut dot = K::default();
+            for (offset, elt) in reflector.iter().enumerate() {
+                dot += &(elt * &self[(first_line + offset) * width + column]);
+            }
+            let twice_dot = &dot + &dot;
+            for (offset, elt) in reflector.iter().enumerate() {
+                self[(first_line + offset) * width + column] -= &(elt * &twice_dot);
+            }
+        }
+    }
+}
+
+impl<K: Clone> QrDecomposition<K> {
+    ///
+    /// Returns the `m` * `n` upper triangular matrix `R` of the full decomposition.
+    ///
+    pub const fn r(&self) -> &Matrix<K> {
+        &self.r
+    }
+
+    ///
+    /// Returns the `k` * `n` upper triangular matrix `R` of the thin
+    /// decomposition, with `k = min(m, n)`.
+    ///
+    pub fn thin_r(&self) -> Matrix<K> {
+        let (height, width) = self.r.size();
+        let kept = height.min(width);
+        Matrix {
+            content: self.r.content[..kept * width].to_vec(),
+            dimensions: Dimensions {
+                width,
+                height: kept,
+            },
+        }
+    }
+}
+
+impl<K> QrDecomposition<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Sqrt
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Add<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the `m` * `m` orthogonal matrix `Q` of the full decomposition.
+    ///
+    pub fn q(&self) -> Matrix<K> {
+        self.build_q(self.r.dimensions.height)
+    }
+
+    ///
+    /// Returns the `m` * `k` matrix `Q` of the thin decomposition, with
+    /// `k = min(m, n)`. Its columns are orthonormal.
+    ///
+    pub fn thin_q(&self) -> Matrix<K> {
+        let (height, width) = self.r.size();
+        self.build_q(height.min(width))
+    }
+
+    ///
+    /// Returns the first `width` columns of `Q`, by applying all the
+    /// reflections on these columns of the identity.
+    ///
+    fn build_q(&self, width: usize) -> Matrix<K> {
+        let height = self.r.dimensions.height;
+        let mut q = Matrix {
+            content: vec![K::default(); height * width],
+            dimensions: Dimensions { width, height },
+        };
+        for i in 0..width {
+            q[i * width + i] = K::one();
+        }
+        for (first_line, reflector) in self.reflectors.iter().enumerate().rev() {
+            if !reflector.is_empty() {
+                q.reflect(reflector, first_line, 0..width);
+            }
+        }
+        q
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{assert_approx, assert_eq_float, Matrix};
+    use pretty_assertions::assert_eq;
+
+    fn assert_orthonormal_columns(mat: &Matrix<f64>) {
+        let width = mat.size().1;
+        let product = mat.transpose().mul_mat(mat).unwrap();
+        assert_approx(&product, &Matrix::identity(&1., width).unwrap());
+    }
+
+    fn assert_upper_triangular(mat: &Matrix<f64>) {
+        let (height, width) = mat.size();
+        for line in 0..height {
+            for column in 0..line.min(width) {
+                assert_eq!(mat.get(line, column), Some(&0.));
+            }
+        }
+    }
+
+    #[test]
+    fn square() {
+        let mat = Matrix::from([[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]);
+        let qr = mat.qr();
+        let (q, r) = (qr.q(), qr.r());
+        assert_orthonormal_columns(&q);
+        assert_upper_triangular(r);
+        assert_approx(&q.mul_mat(r).unwrap(), &mat);
+        for (i, expected) in [14., 175., 35.].into_iter().enumerate() {
+            assert_eq_float!(r.get(i, i).unwrap().abs(), expected);
+        }
+    }
+
+    #[test]
+    fn tall() {
+        let mat = Matrix::from([[1., 2.], [3., 4.], [5., 6.], [7., 8.]]);
+        let qr = mat.qr();
+        {
+            let (q, r) = (qr.q(), qr.r());
+            assert_eq!(q.size(), (4, 4));
+            assert_eq!(r.size(), (4, 2));
+            assert_orthonormal_columns(&q);
+            assert_upper_triangular(r);
+            assert_approx(&q.mul_mat(r).unwrap(), &mat);
+        }
+        {
+            let (q, r) = (qr.thin_q(), qr.thin_r());
+            assert_eq!(q.size(), (4, 2));
+            assert_eq!(r.size(), (2, 2));
+            assert_orthonormal_columns(&q);
+            assert_upper_triangular(&r);
+            assert_approx(&q.mul_mat(&r).unwrap(), &mat);
+        }
+    }
+
+    #[test]
+    fn wide() {
+        let mat = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let qr = mat.qr();
+        let (q, r) = (qr.thin_q(), qr.thin_r());
+        assert_eq!(q, qr.q());
+        assert_eq!(&r, qr.r());
+        assert_eq!(q.size(), (2, 2));
+        assert_eq!(r.size(), (2, 3));
+        assert_orthonormal_columns(&q);
+        assert_upper_triangular(&r);
+        assert_approx(&q.mul_mat(&r).unwrap(), &mat);
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let mat = Matrix::from([[1., 2., 0.], [2., 4., 0.], [3., 6., 0.]]);
+        let qr = mat.qr();
+        let (q, r) = (qr.q(), qr.r());
+        assert_orthonormal_columns(&q);
+        assert_upper_triangular(r);
+        assert_approx(&q.mul_mat(r).unwrap(), &mat);
+    }
+}
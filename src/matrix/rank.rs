@@ -5,7 +5,7 @@
 use std::ops::{Div, DivAssign, Mul, MulAssign, SubAssign};
 
 use crate::{
-    traits::{IsZero, One},
+    traits::{ApproxZero, IsZero, One},
     Matrix,
 };
 
@@ -30,14 +30,36 @@ where
     ///
     #[must_use]
     pub fn rank(&self) -> usize {
-        let mul_identity = K::one();
-        let return_matrix = self.reduced_row_echelon();
-        for i in 0..self.dimensions.height.min(self.dimensions.width) {
-            if return_matrix.get(i, i).unwrap() != &mul_identity {
-                return i;
-            }
-        }
-        self.dimensions.width
+        self.rank_with(|x| x.is_zero())
+    }
+
+    fn rank_with(&self, is_zero: impl Fn(&K) -> bool) -> usize {
+        self.pivot_columns_with(is_zero).len()
+    }
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K:
+        Clone + One + Default + MulAssign<&'a K> + SubAssign<&'a K> + DivAssign<&'a K> + ApproxZero,
+    for<'a> &'a K: PartialEq + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the rank of a matrix, where every element whose absolute value
+    /// is at most `tolerance` is considered to be zero during the elimination.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let u = Matrix::from([[1., 2.], [1., 2. + 1e-12]]);
+    /// assert_eq!(u.rank(), 2);
+    /// assert_eq!(u.rank_with_tolerance(&1e-9), 1);
+    /// ```
+    ///
+    #[must_use]
+    pub fn rank_with_tolerance(&self, tolerance: &K::Tolerance) -> usize {
+        self.rank_with(|x| x.is_approx_zero(tolerance))
     }
 }
 
@@ -45,7 +67,7 @@ where
 mod test {
     use pretty_assertions::assert_eq;
 
-    use crate::{complex::cpl, Matrix};
+    use crate::{complex::cpl, Gf, Matrix};
 
     #[test]
     fn example() {
@@ -81,4 +103,27 @@ mod test {
         let res = u.rank();
         assert_eq!(res, 2);
     }
+
+    #[test]
+    fn with_tolerance() {
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9. + 1e-13]]);
+        assert_eq!(u.rank(), 3);
+        assert_eq!(u.rank_with_tolerance(&1e-9), 2);
+        assert_eq!(u.rank_with_tolerance(&0.), 3);
+
+        let u = Matrix::from([
+            [cpl!(1., 1.), cpl!(2., 0.)],
+            [cpl!(1., 1.), cpl!(2., 1e-12)],
+        ]);
+        assert_eq!(u.rank_with_tolerance(&1e-9), 1);
+    }
+
+    #[test]
+    fn with_finite_field() {
+        // Invertible over the reals, but its determinant 2 is zero in GF(2)
+        let u = Matrix::from([[1, 1, 0], [0, 1, 1], [1, 0, 1]].map(|line| line.map(Gf::<2>::new)));
+        assert_eq!(u.rank(), 2);
+        let u = Matrix::from([[1, 1, 0], [0, 1, 1], [1, 0, 1]].map(|line| line.map(Gf::<3>::new)));
+        assert_eq!(u.rank(), 3);
+    }
 }
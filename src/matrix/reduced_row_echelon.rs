@@ -5,7 +5,7 @@
 use std::ops::{Div, DivAssign, Mul, MulAssign, SubAssign};
 
 use crate::{
-    traits::{IsZero, One},
+    traits::{ApproxZero, IsZero, One},
     Matrix,
 };
 
@@ -14,18 +14,16 @@ where
     for<'a> K: Clone + Default + MulAssign<&'a K> + SubAssign<&'a K> + DivAssign<&'a K> + One,
     for<'a> &'a K: PartialEq + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
 {
+    pub(crate) fn row_echelon_internal(&self) -> (Self, usize, K) {
+        self.row_echelon_with(|x| x.is_zero())
+    }
+
     ///
-    /// Returns the row echelon form of a Matrix.
-    ///
-    /// # Example
-    /// ```
-    /// use matrix::Matrix;
-    ///
-    /// let mat = Matrix::from([[1., 2.], [3., 4.]]);
-    /// assert_eq!(mat.row_echelon(), [[1., 2.], [0., 1.]]);
-    /// ```
+    /// Computes the row echelon form, considering as zero every element for
+    /// which `is_zero` is true. These elements are replaced by an exact zero
+    /// when they are met.
     ///
-    pub(crate) fn row_echelon_internal(&self) -> (Self, usize, K) {
+    pub(super) fn row_echelon_with(&self, is_zero: impl Fn(&K) -> bool) -> (Self, usize, K) {
         // First, we skip all zero columns
         let mut first_non_zero_column = 0;
         let mut rows_set = 0;
@@ -40,19 +38,23 @@ where
                     .get_column(i)
                     .unwrap()
                     .skip(rows_set)
-                    .any(|x| !x.is_zero())
+                    .any(|x| !is_zero(x))
                 {
                     end = false;
                     first_non_zero_line = return_matrix
                         .get_column(i)
                         .unwrap()
                         .skip(rows_set)
-                        .position(|x| !x.is_zero())
+                        .position(|x| !is_zero(x))
                         .unwrap()
                         + rows_set;
                     first_non_zero_column = i;
                     break;
                 }
+                // Every remaining element of the column is considered to be zero
+                for line in rows_set..self.dimensions.height {
+                    return_matrix[line * self.dimensions.width + i] = K::default();
+                }
             }
             if end {
                 return (return_matrix, 0, factor);
@@ -78,11 +80,18 @@ where
                     let first_number_of_new_line = return_matrix
                         .get(non_treated_line, first_non_zero_column)
                         .unwrap();
-                    if first_number_of_new_line.is_zero() {
-                        continue;
+                    if is_zero(first_number_of_new_line) {
+                        None
+                    } else {
+                        let pivot = return_matrix.get(rows_set, first_non_zero_column).unwrap();
+                        Some(first_number_of_new_line / pivot)
                     }
-                    let pivot = return_matrix.get(rows_set, first_non_zero_column).unwrap();
-                    first_number_of_new_line / pivot
+                };
+                let Some(coeff) = coeff else {
+                    return_matrix
+                        [non_treated_line * self.dimensions.width + first_non_zero_column] =
+                        K::default();
+                    continue;
                 };
                 for elt_index in first_non_zero_column..return_matrix.dimensions.width {
                     let tmp = &coeff * return_matrix.get(rows_set, elt_index).unwrap();
@@ -95,6 +104,17 @@ where
         (return_matrix, swap_number, factor)
     }
 
+    ///
+    /// Returns the row echelon form of a Matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 2.], [3., 4.]]);
+    /// assert_eq!(mat.row_echelon(), [[1., 2.], [0., 1.]]);
+    /// ```
+    ///
     pub fn row_echelon(&self) -> Self {
         self.row_echelon_internal().0
     }
@@ -114,14 +134,22 @@ where
     /// ```
     ///
     pub fn reduced_row_echelon(&self) -> Self {
-        let mut return_matrix = self.row_echelon();
+        self.reduced_row_echelon_with(|x| x.is_zero())
+    }
+
+    ///
+    /// Computes the reduced row echelon form, considering as zero every
+    /// element for which `is_zero` is true.
+    ///
+    pub(super) fn reduced_row_echelon_with(&self, is_zero: impl Fn(&K) -> bool) -> Self {
+        let mut return_matrix = self.row_echelon_with(&is_zero).0;
         // For each line
         for index_line in 1..return_matrix.dimensions.height {
             // Take the pivot
             let Some(pivot_position) = return_matrix
                 .get_line(index_line)
                 .unwrap()
-                .position(|x| !x.is_zero())
+                .position(|x| !is_zero(x))
             else {
                 continue;
             };
@@ -139,11 +167,50 @@ where
     }
 }
 
+impl<K> Matrix<K>
+where
+    for<'a> K:
+        Clone + Default + MulAssign<&'a K> + SubAssign<&'a K> + DivAssign<&'a K> + One + ApproxZero,
+    for<'a> &'a K: PartialEq + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the row echelon form of a matrix, where every element whose
+    /// absolute value is at most `tolerance` is considered to be zero.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 2.], [1., 2. + 1e-12]]);
+    /// assert_eq!(mat.row_echelon_with_tolerance(&1e-9), [[1., 2.], [0., 0.]]);
+    /// ```
+    ///
+    pub fn row_echelon_with_tolerance(&self, tolerance: &K::Tolerance) -> Self {
+        self.row_echelon_with(|x| x.is_approx_zero(tolerance)).0
+    }
+
+    ///
+    /// Returns the reduced row echelon form of a matrix, where every element
+    /// whose absolute value is at most `tolerance` is considered to be zero.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 2.], [1., 2. + 1e-12]]);
+    /// assert_eq!(mat.reduced_row_echelon_with_tolerance(&1e-9), [[1., 2.], [0., 0.]]);
+    /// ```
+    ///
+    pub fn reduced_row_echelon_with_tolerance(&self, tolerance: &K::Tolerance) -> Self {
+        self.reduced_row_echelon_with(|x| x.is_approx_zero(tolerance))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use pretty_assertions::assert_eq;
 
-    use crate::Matrix;
+    use crate::{rational::rat, Gf, Matrix};
 
     #[test]
     fn example() {
@@ -184,6 +251,36 @@ mod test {
         }
     }
 
+    #[test]
+    fn with_rational() {
+        let u = Matrix::from([
+            [rat!(2_i64), rat!(1), rat!(-1)],
+            [rat!(1), rat!(3), rat!(2)],
+            [rat!(1), rat!(-2), rat!(-3)],
+        ]);
+        let res = u.reduced_row_echelon();
+        assert_eq!(
+            res,
+            [
+                [rat!(1), rat!(0), rat!(-1)],
+                [rat!(0), rat!(1), rat!(1)],
+                [rat!(0), rat!(0), rat!(0)]
+            ]
+        );
+        let u = Matrix::from([[rat!(3_i64), rat!(1)], [rat!(0), rat!(3)]]);
+        assert_eq!(u.row_echelon(), [[rat!(1), rat!(1, 3)], [rat!(0), rat!(1)]]);
+    }
+
+    #[test]
+    fn with_finite_field() {
+        let u = Matrix::from([[2, 1, 6], [1, 3, 2], [1, 5, 4]].map(|line| line.map(Gf::<7>::new)));
+        let res = u.reduced_row_echelon();
+        assert_eq!(
+            res,
+            [[1, 0, 6], [0, 1, 1], [0, 0, 0]].map(|line| line.map(Gf::<7>::new))
+        );
+    }
+
     #[test]
     #[ignore = "rounding problem, and ordering for two complex numbers is not defined"]
     fn with_complex() {
@@ -0,0 +1,211 @@
+//!
+//! Resolution of linear systems `A * x = b`, using the reduced row echelon
+//! form of the augmented matrix `[A | b]`.
+//!
+
+use std::ops::{Div, DivAssign, Mul, MulAssign, SubAssign};
+
+use crate::{
+    error::SolveError,
+    traits::{IsZero, One},
+    Matrix, Vector,
+};
+
+use super::Dimensions;
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + Default + MulAssign<&'a K> + SubAssign<&'a K> + DivAssign<&'a K> + One,
+    for<'a> &'a K: PartialEq + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the solution `x` of `self * x = vec`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, Vector};
+    ///
+    /// let mat = Matrix::from([[2., 0.], [0., 4.]]);
+    /// assert_eq!(mat.solve(&Vector::from([2., 2.])).unwrap(), [1., 0.5]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the height of the matrix is not the size of `vec`, returns a [`SizeMismatch`](SolveError::SizeMismatch)
+    /// If the system has no solution, returns a [`NoSolution`](SolveError::NoSolution)
+    /// If the system has infinitely many solutions, returns an [`InfinitelyManySolutions`](SolveError::InfinitelyManySolutions),
+    /// containing a particular solution and a basis of the null space of the matrix
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix:
+    /// Time: O(m^2 * n)
+    /// Space: O(mn)
+    ///
+    pub fn solve(&self, vec: &Vector<K>) -> Result<Vector<K>, SolveError<K>> {
+        if self.dimensions.height != vec.len() {
+            return Err(SolveError::SizeMismatch(self.dimensions.height, vec.len()));
+        }
+        match self.solve_internal(&Self::from(vec.clone())) {
+            Ok(solution) => Ok(Vector::from(solution.content)),
+            Err(SolveError::InfinitelyManySolutions {
+                particular,
+                null_space,
+            }) => Err(SolveError::InfinitelyManySolutions {
+                particular: Vector::from(particular.content),
+                null_space,
+            }),
+            Err(SolveError::SizeMismatch(matrix, rhs)) => {
+                Err(SolveError::SizeMismatch(matrix, rhs))
+            }
+            Err(SolveError::NoSolution) => Err(SolveError::NoSolution),
+        }
+    }
+
+    ///
+    /// Returns the solution `X` of `self * X = rhs`. Each column of `X` is the
+    /// solution for the corresponding column of `rhs`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2., 0.], [0., 4.]]);
+    /// let rhs = Matrix::from([[2., 4.], [2., 1.]]);
+    /// assert_eq!(mat.solve_many(&rhs).unwrap(), [[1., 2.], [0.5, 0.25]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the height of the two matrices differ, returns a [`SizeMismatch`](SolveError::SizeMismatch)
+    /// If one of the systems has no solution, returns a [`NoSolution`](SolveError::NoSolution)
+    /// If the systems have infinitely many solutions, returns an [`InfinitelyManySolutions`](SolveError::InfinitelyManySolutions),
+    /// containing a particular solution for each column and a basis of the null space of the matrix
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix, and a `m` * `p` right hand side:
+    /// Time: O(m^2 * (n + p))
+    /// Space: O(m * (n + p))
+    ///
+    pub fn solve_many(&self, rhs: &Self) -> Result<Self, SolveError<K, Self>> {
+        if self.dimensions.height != rhs.dimensions.height {
+            return Err(SolveError::SizeMismatch(
+                self.dimensions.height,
+                rhs.dimensions.height,
+            ));
+        }
+        self.solve_internal(rhs)
+    }
+
+    fn solve_internal(&self, rhs: &Self) -> Result<Self, SolveError<K, Self>> {
+        let width = self.dimensions.width;
+        let rhs_width = rhs.dimensions.width;
+        let total_width = width + rhs_width;
+        // Heights were checked by the caller
+        let reduced = Self::augmented_matrix(self, rhs)
+            .unwrap_or_else(|_| unreachable!())
+            .reduced_row_echelon();
+
+        // A pivot in the right hand side means a line `0 = b`, with `b` non-zero.
+        // The pivots are increasing, so only the last one can be there
+        let pivots = Self::pivots_of(&reduced, |x| x.is_zero());
+        if pivots.last().is_some_and(|&column| column >= width) {
+            return Err(SolveError::NoSolution);
+        }
+
+        let mut particular = vec![K::default(); width * rhs_width];
+        for (line, &column) in pivots.iter().enumerate() {
+            let begin = line * total_width + width;
+            particular[column * rhs_width..(column + 1) * rhs_width]
+                .clone_from_slice(&reduced.content[begin..begin + rhs_width]);
+        }
+        let particular = Self {
+            content: particular,
+            dimensions: Dimensions {
+                width: rhs_width,
+                height: width,
+            },
+        };
+        if pivots.len() == width {
+            return Ok(particular);
+        }
+
+        let null_space = Self::null_space_of(&reduced, &pivots, width);
+        Err(SolveError::InfinitelyManySolutions {
+            particular,
+            null_space,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{assert_eq_float, error::SolveError, Matrix, Vector};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unique_solution() {
+        {
+            let mat = Matrix::from([[2., 1., -1.], [-3., -1., 2.], [-2., 1., 2.]]);
+            let res = mat.solve(&Vector::from([8., -11., -3.])).unwrap();
+            for (found, expected) in res.iter().zip([2., 3., -1.]) {
+                assert_eq_float!(found, expected);
+            }
+        }
+        {
+            // Overdetermined, but consistent
+            let mat = Matrix::from([[1., 1.], [1., -1.], [2., 0.]]);
+            let res = mat.solve(&Vector::from([3., 1., 4.])).unwrap();
+            assert_eq!(res, [2., 1.]);
+        }
+    }
+
+    #[test]
+    fn many_right_hand_sides() {
+        let mat = Matrix::from([[1., 1.], [1., -1.]]);
+        let rhs = Matrix::from([[3., 2., 0.], [1., 0., 2.]]);
+        let res = mat.solve_many(&rhs).unwrap();
+        assert_eq!(res, [[2., 1., 1.], [1., 1., -1.]]);
+    }
+
+    #[test]
+    fn no_solution() {
+        let mat = Matrix::from([[1., 1.], [1., 1.]]);
+        assert_eq!(
+            mat.solve(&Vector::from([1., 2.])),
+            Err(SolveError::NoSolution)
+        );
+        let rhs = Matrix::from([[1., 1.], [1., 2.]]);
+        assert_eq!(mat.solve_many(&rhs), Err(SolveError::NoSolution));
+    }
+
+    #[test]
+    fn infinitely_many_solutions() {
+        let mat = Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 1., 1.]]);
+        let vec = Vector::from([6., 12., 3.]);
+        let Err(SolveError::InfinitelyManySolutions {
+            particular,
+            null_space,
+        }) = mat.solve(&vec)
+        else {
+            panic!("the system should have infinitely many solutions");
+        };
+        assert_eq!(particular, [0., 3., 0.]);
+        assert_eq!(null_space, [Vector::from([1., -2., 1.])]);
+        assert_eq!(mat.mul_vec(&particular).unwrap(), vec);
+        for vector in &null_space {
+            assert_eq!(mat.mul_vec(vector).unwrap(), [0., 0., 0.]);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let mat = Matrix::from([[1., 1.], [1., -1.]]);
+        assert_eq!(
+            mat.solve(&Vector::from([1., 2., 3.])),
+            Err(SolveError::SizeMismatch(2, 3))
+        );
+        assert_eq!(
+            mat.solve_many(&Matrix::from([[1.], [2.], [3.]])),
+            Err(SolveError::SizeMismatch(2, 3))
+        );
+    }
+}
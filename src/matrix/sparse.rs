@@ -0,0 +1,317 @@
+//!
+//! Sparse matrices, stored in the compressed sparse row (CSR) format.
+//!
+//! # Implementations details
+//!
+//! Only the non-zero elements are stored, line after line, along with their
+//! column. `line_offsets[i]..line_offsets[i + 1]` is the range of the
+//! elements of line `i`, whose columns are increasing.
+//!
+//! The compressed sparse column (CSC) storage of a matrix is the CSR storage
+//! of its transpose: [`transpose`](SparseMatrix#method.transpose) converts
+//! from one to the other.
+//!
+
+use std::ops::AddAssign;
+
+use crate::{error::SparseMatrixCreationError, traits::IsZero, Matrix};
+
+use super::Dimensions;
+
+mod operations;
+
+///
+/// A matrix that only stores its non-zero elements.
+///
+/// # Example
+/// ```
+/// use matrix::{matrix::SparseMatrix, Vector};
+///
+/// let mat = SparseMatrix::from_triplets(3, 3, &[(0, 0, 2.), (1, 2, 1.), (2, 1, -1.)]).unwrap();
+/// assert_eq!(mat.non_zero_count(), 3);
+/// assert_eq!(mat.mul_vec(&Vector::from([1., 2., 3.])).unwrap(), [2., 3., -2.]);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct SparseMatrix<K> {
+    dimensions: Dimensions,
+    line_offsets: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<K>,
+}
+
+impl<K> SparseMatrix<K> {
+    ///
+    /// Creates a `height` * `width` matrix filled with zeros.
+    ///
+    pub fn zeros(height: usize, width: usize) -> Self {
+        Self {
+            dimensions: Dimensions { width, height },
+            line_offsets: vec![0; height + 1],
+            columns: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    ///
+    /// Returns the number of stored elements.
+    ///
+    #[must_use]
+    pub const fn non_zero_count(&self) -> usize {
+        self.values.len()
+    }
+
+    ///
+    /// Returns the offsets of the lines in [`columns`](SparseMatrix#method.columns)
+    /// and [`values`](SparseMatrix#method.values). It has `height + 1` elements.
+    ///
+    #[must_use]
+    pub fn line_offsets(&self) -> &[usize] {
+        &self.line_offsets
+    }
+
+    ///
+    /// Returns the column of each stored element.
+    ///
+    #[must_use]
+    pub fn columns(&self) -> &[usize] {
+        &self.columns
+    }
+
+    ///
+    /// Returns the stored elements, line after line.
+    ///
+    #[must_use]
+    pub fn values(&self) -> &[K] {
+        &self.values
+    }
+
+    ///
+    /// Returns the element at `(line, column)`, or `None` if it is zero or
+    /// out of the matrix.
+    ///
+    /// # Complexity
+    /// Time: O(log(k)) with `k` the number of elements stored in the line.
+    ///
+    #[must_use]
+    pub fn get(&self, line: usize, column: usize) -> Option<&K> {
+        if line >= self.dimensions.height {
+            return None;
+        }
+        let range = self.line_offsets[line]..self.line_offsets[line + 1];
+        self.columns[range.clone()]
+            .binary_search(&column)
+            .ok()
+            .map(|index| &self.values[range.start + index])
+    }
+
+    ///
+    /// Returns an iterator over the stored elements, as `(line, column, value)`,
+    /// line after line.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{matrix::SparseMatrix, Matrix};
+    ///
+    /// let mat = SparseMatrix::from(&Matrix::from([[0, 1], [2, 0]]));
+    /// assert_eq!(
+    ///     mat.iter().collect::<Vec<_>>(),
+    ///     [(0, 1, &1), (1, 0, &2)]
+    /// );
+    /// ```
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &K)> {
+        self.line_offsets
+            .windows(2)
+            .enumerate()
+            .flat_map(move |(line, offsets)| {
+                (offsets[0]..offsets[1])
+                    .map(move |index| (line, self.columns[index], &self.values[index]))
+            })
+    }
+}
+
+impl<K> SparseMatrix<K>
+where
+    for<'a> K: Clone + AddAssign<&'a K>,
+    for<'a> &'a K: IsZero,
+{
+    ///
+    /// Creates a `height` * `width` matrix from `(line, column, value)`
+    /// triplets, in any order.
+    ///
+    /// The values of the triplets sharing the same position are summed, as
+    /// when assembling finite-element matrices, and the zeros are not stored.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{matrix::SparseMatrix, Matrix};
+    ///
+    /// let mat = SparseMatrix::from_triplets(2, 2, &[(1, 1, 1), (0, 1, 3), (1, 1, 2)]).unwrap();
+    /// assert_eq!(mat.to_dense(), [[0, 3], [0, 3]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If a triplet is outside of the matrix, returns an [`IndexOutOfBounds`](SparseMatrixCreationError::IndexOutOfBounds)
+    ///
+    /// # Complexity
+    /// Time: O(k * log(k) + height) with `k` the number of triplets.
+    /// Space: O(k + height)
+    ///
+    pub fn from_triplets(
+        height: usize,
+        width: usize,
+        triplets: &[(usize, usize, K)],
+    ) -> Result<Self, SparseMatrixCreationError> {
+        if let Some((line, column, _)) = triplets
+            .iter()
+            .find(|(line, column, _)| *line >= height || *column >= width)
+        {
+            return Err(SparseMatrixCreationError::IndexOutOfBounds(*line, *column));
+        }
+        let mut sorted: Vec<&(usize, usize, K)> = triplets.iter().collect();
+        sorted.sort_by_key(|(line, column, _)| (*line, *column));
+
+        let mut merged: Vec<(usize, usize, K)> = Vec::with_capacity(sorted.len());
+        for (line, column, value) in sorted {
+            match merged.last_mut() {
+                Some((last_line, last_column, sum))
+                    if (*last_line, *last_column) == (*line, *column) =>
+                {
+                    *sum += value;
+                }
+                _ => merged.push((*line, *column, value.clone())),
+            }
+        }
+
+        let mut result = Self::zeros(height, width);
+        // The sums may have cancelled out
+        for (line, column, value) in merged.into_iter().filter(|(_, _, value)| !value.is_zero()) {
+            result.line_offsets[line + 1] += 1;
+            result.columns.push(column);
+            result.values.push(value);
+        }
+        for line in 0..height {
+            result.line_offsets[line + 1] += result.line_offsets[line];
+        }
+        Ok(result)
+    }
+}
+
+impl<K> From<&Matrix<K>> for SparseMatrix<K>
+where
+    K: Clone,
+    for<'a> &'a K: IsZero,
+{
+    fn from(value: &Matrix<K>) -> Self {
+        let Dimensions { width, height } = value.dimensions;
+        let mut result = Self::zeros(height, width);
+        for line in 0..height {
+            for (column, elt) in value.content[line * width..(line + 1) * width]
+                .iter()
+                .enumerate()
+            {
+                if !elt.is_zero() {
+                    result.columns.push(column);
+                    result.values.push(elt.clone());
+                }
+            }
+            result.line_offsets[line + 1] = result.values.len();
+        }
+        result
+    }
+}
+
+impl<K> SparseMatrix<K>
+where
+    K: Clone + Default,
+{
+    ///
+    /// Returns the dense [`Matrix`] holding the same elements.
+    ///
+    /// # Complexity
+    /// Time: O(mn) for a `m` * `n` matrix.
+    /// Space: O(mn)
+    ///
+    pub fn to_dense(&self) -> Matrix<K> {
+        let width = self.dimensions.width;
+        let mut content = vec![K::default(); width * self.dimensions.height];
+        for (line, column, value) in self.iter() {
+            content[line * width + column] = value.clone();
+        }
+        Matrix {
+            content,
+            dimensions: self.dimensions,
+        }
+    }
+}
+
+impl<K> From<&SparseMatrix<K>> for Matrix<K>
+where
+    K: Clone + Default,
+{
+    fn from(value: &SparseMatrix<K>) -> Self {
+        value.to_dense()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{error::SparseMatrixCreationError, matrix::SparseMatrix, Matrix};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_triplets() {
+        let mat = SparseMatrix::from_triplets(
+            3,
+            4,
+            &[
+                (2, 3, 1),
+                (0, 1, 2),
+                (2, 0, 3),
+                (0, 1, 4),
+                (1, 2, 5),
+                (1, 2, -5),
+            ],
+        )
+        .unwrap();
+        assert_eq!(mat.line_offsets(), [0, 1, 1, 3]);
+        assert_eq!(mat.columns(), [1, 0, 3]);
+        assert_eq!(mat.values(), [6, 3, 1]);
+        assert_eq!(mat.get(0, 1), Some(&6));
+        assert_eq!(mat.get(1, 2), None);
+        assert_eq!(mat.get(3, 0), None);
+        assert_eq!(mat.to_dense(), [[0, 6, 0, 0], [0, 0, 0, 0], [3, 0, 0, 1]]);
+
+        // Cancellation on the last line
+        let mat =
+            SparseMatrix::from_triplets(2, 2, &[(0, 0, 1.), (1, 1, 1.), (1, 1, -1.)]).unwrap();
+        assert_eq!(mat.line_offsets(), [0, 1, 1]);
+        assert_eq!(mat.to_dense(), [[1., 0.], [0., 0.]]);
+
+        assert_eq!(
+            SparseMatrix::from_triplets(2, 2, &[(0, 0, 1), (0, 2, 1)]),
+            Err(SparseMatrixCreationError::IndexOutOfBounds(0, 2))
+        );
+    }
+
+    #[test]
+    fn dense_conversion() {
+        let dense = Matrix::from([[0., 1., 0.], [0., 0., 0.], [2., 0., 3.]]);
+        let sparse = SparseMatrix::from(&dense);
+        assert_eq!(sparse.non_zero_count(), 3);
+        assert_eq!(
+            sparse.iter().collect::<Vec<_>>(),
+            [(0, 1, &1.), (2, 0, &2.), (2, 2, &3.)]
+        );
+        assert_eq!(Matrix::from(&sparse), dense);
+        assert_eq!(SparseMatrix::<f64>::zeros(2, 3).to_dense(), [[0.; 3]; 2]);
+    }
+}
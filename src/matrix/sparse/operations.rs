@@ -0,0 +1,270 @@
+use std::ops::{AddAssign, Mul};
+
+use crate::{
+    error::{MulMatError, MulVecError},
+    linear_operator::LinearOperator,
+    solvers::LineEntries,
+    traits::IsZero,
+    Vector,
+};
+
+use super::{Dimensions, SparseMatrix};
+
+impl<K: Clone> SparseMatrix<K> {
+    ///
+    /// Returns the transpose of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{matrix::SparseMatrix, Matrix};
+    ///
+    /// let mat = SparseMatrix::from(&Matrix::from([[1, 0, 2], [0, 3, 0]]));
+    /// assert_eq!(mat.transpose().to_dense(), [[1, 0], [0, 3], [2, 0]]);
+    /// ```
+    ///
+    /// # Complexity
+    /// Time: O(k + n) with `k` the number of stored elements and `n` the width.
+    /// Space: O(k + n)
+    ///
+    pub fn transpose(&self) -> Self {
+        let Dimensions { width, height } = self.dimensions;
+        // Counting sort on the columns
+        let mut line_offsets = vec![0; width + 1];
+        for column in &self.columns {
+            line_offsets[column + 1] += 1;
+        }
+        for column in 0..width {
+            line_offsets[column + 1] += line_offsets[column];
+        }
+        let mut next = line_offsets.clone();
+        let mut columns = vec![0; self.values.len()];
+        let mut values: Vec<Option<K>> = vec![None; self.values.len()];
+        for (line, column, value) in self.iter() {
+            columns[next[column]] = line;
+            values[next[column]] = Some(value.clone());
+            next[column] += 1;
+        }
+        Self {
+            dimensions: Dimensions {
+                width: height,
+                height: width,
+            },
+            line_offsets,
+            columns,
+            values: values.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<K> SparseMatrix<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    ///
+    /// Multiplies a [`Vector`] by the matrix.
+    ///
+    /// # Errors
+    /// If the width of `self` is not equal to the length of `vec`, returns a
+    /// [`SizeMismatch`](MulVecError::SizeMismatch)
+    ///
+    /// # Complexity
+    /// Time: O(k + m) with `k` the number of stored elements and `m` the height.
+    /// Space: O(m)
+    ///
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Result<Vector<K>, MulVecError> {
+        if self.dimensions.width != vec.len() {
+            return Err(MulVecError::SizeMismatch(self.dimensions.width, vec.len()));
+        }
+        Ok(self.mul_vec_internal(vec))
+    }
+
+    fn mul_vec_internal(&self, vec: &Vector<K>) -> Vector<K> {
+        let result: Vec<K> = self
+            .line_offsets
+            .windows(2)
+            .map(|offsets| {
+                let mut sum = K::default();
+                for index in offsets[0]..offsets[1] {
+                    sum += &(&self.values[index] * &vec[self.columns[index]]);
+                }
+                sum
+            })
+            .collect();
+        Vector::from(result)
+    }
+}
+
+impl<K> LinearOperator<K> for SparseMatrix<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        self.mul_vec_internal(vec)
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        let mut result = vec![K::default(); self.dimensions.width];
+        for (line, column, value) in self.iter() {
+            result[column] += &(value * &vec[line]);
+        }
+        Vector::from(result)
+    }
+}
+
+impl<K> LineEntries<K> for SparseMatrix<K> {
+    fn line_entries<'a>(&'a self, line: usize) -> impl Iterator<Item = (usize, &'a K)>
+    where
+        K: 'a,
+    {
+        let range = self.line_offsets[line]..self.line_offsets[line + 1];
+        self.columns[range.clone()]
+            .iter()
+            .copied()
+            .zip(&self.values[range])
+    }
+}
+
+impl<K> SparseMatrix<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the product of two sparse matrices, with Gustavson's
+    /// algorithm: each line of the result is the combination of the lines
+    /// of `other` selected by the elements of the same line of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{matrix::SparseMatrix, Matrix};
+    ///
+    /// let lhs = SparseMatrix::from(&Matrix::from([[1, 0], [0, 2]]));
+    /// let rhs = SparseMatrix::from(&Matrix::from([[0, 3], [4, 0]]));
+    /// assert_eq!(lhs.mul_mat(&rhs).unwrap().to_dense(), [[0, 3], [8, 0]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the width of `self` is not equal to the height of `other`, returns a
+    /// [`SizeMismatch`](MulMatError::SizeMismatch)
+    ///
+    /// # Complexity
+    /// Time: O(f + m) with `f` the number of products of non-zero elements,
+    /// and `m` the height, plus the sorting of the columns of each line.
+    /// Space: O(k + n) with `k` the number of elements of the result, and `n`
+    /// the width of `other`.
+    ///
+    pub fn mul_mat(&self, other: &Self) -> Result<Self, MulMatError> {
+        if self.dimensions.width != other.dimensions.height {
+            return Err(MulMatError::SizeMismatch(
+                self.dimensions.width,
+                other.dimensions.height,
+            ));
+        }
+        let width = other.dimensions.width;
+        let mut result = Self::zeros(self.dimensions.height, width);
+        let mut accumulator: Vec<Option<K>> = vec![None; width];
+        let mut touched = Vec::new();
+        for (line, offsets) in self.line_offsets.windows(2).enumerate() {
+            for index in offsets[0]..offsets[1] {
+                let factor = &self.values[index];
+                let inner = self.columns[index];
+                for other_index in other.line_offsets[inner]..other.line_offsets[inner + 1] {
+                    let column = other.columns[other_index];
+                    let product = factor * &other.values[other_index];
+                    match &mut accumulator[column] {
+                        Some(sum) => *sum += &product,
+                        slot @ None => {
+                            *slot = Some(product);
+                            touched.push(column);
+                        }
+                    }
+                }
+            }
+            touched.sort_unstable();
+            for &column in &touched {
+                if let Some(value) = accumulator[column].take() {
+                    if !(&value).is_zero() {
+                        result.columns.push(column);
+                        result.values.push(value);
+                    }
+                }
+            }
+            touched.clear();
+            result.line_offsets[line + 1] = result.values.len();
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        error::{MulMatError, MulVecError},
+        matrix::SparseMatrix,
+        Matrix, Vector,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transpose() {
+        let dense = Matrix::from([[0, 1, 0, 2], [3, 0, 0, 0], [0, 4, 5, 0]]);
+        let sparse = SparseMatrix::from(&dense);
+        assert_eq!(sparse.transpose().to_dense(), dense.transpose());
+        assert_eq!(sparse.transpose().transpose(), sparse);
+    }
+
+    #[test]
+    fn mul_vec() {
+        let dense = Matrix::from([[0., 1., 0., 2.], [3., 0., 0., 0.], [0., 4., 5., 0.]]);
+        let sparse = SparseMatrix::from(&dense);
+        let vec = Vector::from([1., -1., 2., 0.5]);
+        assert_eq!(sparse.mul_vec(&vec), dense.mul_vec(&vec));
+        assert_eq!(
+            sparse.mul_vec(&Vector::from([1., 2.])),
+            Err(MulVecError::SizeMismatch(4, 2))
+        );
+    }
+
+    #[test]
+    fn mul_mat() {
+        let lhs = Matrix::from([[1, 0, 2], [0, 0, 0], [-1, 3, 0]]);
+        let rhs = Matrix::from([[0, 2], [1, 0], [0, -1]]);
+        let product = SparseMatrix::from(&lhs)
+            .mul_mat(&SparseMatrix::from(&rhs))
+            .unwrap();
+        assert_eq!(Ok(product.to_dense()), lhs.mul_mat(&rhs));
+        // 1 * 2 + 2 * -1 cancels out: only the non-zeros are kept
+        assert_eq!(product.non_zero_count(), 2);
+
+        // 1D Laplacian: (-1, 2, -1) squared
+        let size = 50;
+        let mut triplets = Vec::new();
+        for index in 0..size {
+            triplets.push((index, index, 2_i64));
+            if index + 1 < size {
+                triplets.push((index, index + 1, -1));
+                triplets.push((index + 1, index, -1));
+            }
+        }
+        let laplacian = SparseMatrix::from_triplets(size, size, &triplets).unwrap();
+        let square = laplacian.mul_mat(&laplacian).unwrap();
+        assert_eq!(
+            Ok(square.to_dense()),
+            laplacian.to_dense().mul_mat(&laplacian.to_dense())
+        );
+        assert_eq!(square.get(10, 10), Some(&6));
+        assert_eq!(square.get(10, 12), Some(&1));
+
+        assert_eq!(
+            SparseMatrix::from(&lhs).mul_mat(&SparseMatrix::from(&rhs.transpose())),
+            Err(MulMatError::SizeMismatch(3, 2))
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//!
+//! Square matrices whose zeros follow a known pattern, stored compactly:
+//! - [`UpperTriangular`] and [`LowerTriangular`], solved by back and forward
+//!   substitution,
+//! - [`Tridiagonal`], solved by the Thomas algorithm,
+//! - [`Banded`], solved by an elimination that stays within the band.
+//!
+//! Spline fitting and the discretization of 1D differential equations
+//! produce them all the time: their systems are solved in O(n) instead of
+//! the O(n^3) of a dense [`lu`](crate::Matrix#method.lu).
+//!
+//! All of them are [`LinearOperator`](crate::linear_operator::LinearOperator)s.
+//!
+
+use std::ops::{AddAssign, Mul};
+
+use crate::Vector;
+
+mod banded;
+mod triangular;
+mod tridiagonal;
+
+pub use banded::Banded;
+pub use triangular::{LowerTriangular, UpperTriangular};
+pub use tridiagonal::Tridiagonal;
+
+///
+/// Returns the vector of size `size` whose element `output` is the sum of
+/// `value * vec[input]`, for each `(output, input, value)` of `entries`.
+///
+fn scatter_product<'a, K>(
+    entries: impl Iterator<Item = (usize, usize, &'a K)>,
+    vec: &[K],
+    size: usize,
+) -> Vector<K>
+where
+    for<'b> K: 'a + Clone + Default + AddAssign<&'b K>,
+    for<'b> &'b K: Mul<&'b K, Output = K>,
+{
+    let mut result = vec![K::default(); size];
+    for (output, input, value) in entries {
+        result[output] += &(value * &vec[input]);
+    }
+    Vector::from(result)
+}
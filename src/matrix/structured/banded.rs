@@ -0,0 +1,454 @@
+use std::ops::{AddAssign, Div, Mul, MulAssign, Neg, SubAssign};
+
+use crate::{
+    error::{MulVecError, StructuredSolveError},
+    linear_operator::LinearOperator,
+    matrix::Dimensions,
+    traits::{Abs, IsZero, One},
+    Matrix, Vector,
+};
+
+use super::{scatter_product, Tridiagonal};
+
+///
+/// A square matrix whose non-zero elements are at most `lower_bandwidth`
+/// below its diagonal, and at most `upper_bandwidth` above it.
+///
+/// Each line stores the `lower_bandwidth + upper_bandwidth + 1` elements of
+/// the band, the ones falling out of the matrix being zero.
+///
+/// # Example
+/// ```
+/// use matrix::{matrix::Banded, Matrix, Vector};
+///
+/// let mat = Matrix::from([[4., 1., 0.], [2., 5., 1.], [0., 2., 6.]]);
+/// let banded = Banded::from_matrix(&mat, 1, 1).unwrap();
+/// assert_eq!(banded.mul_vec(&Vector::from([1., 1., 1.])).unwrap(), [5., 8., 8.]);
+/// assert_eq!(banded.determinant(), 100.);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Banded<K> {
+    size: usize,
+    lower_bandwidth: usize,
+    upper_bandwidth: usize,
+    content: Vec<K>,
+}
+
+impl<K> Banded<K> {
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    ///
+    /// Returns the number of diagonals below the main one.
+    ///
+    #[must_use]
+    pub const fn lower_bandwidth(&self) -> usize {
+        self.lower_bandwidth
+    }
+
+    ///
+    /// Returns the number of diagonals above the main one.
+    ///
+    #[must_use]
+    pub const fn upper_bandwidth(&self) -> usize {
+        self.upper_bandwidth
+    }
+
+    const fn is_in_band(&self, line: usize, column: usize) -> bool {
+        line < self.size
+            && column < self.size
+            && line <= column + self.lower_bandwidth
+            && column <= line + self.upper_bandwidth
+    }
+
+    const fn index(&self, line: usize, column: usize) -> usize {
+        line * (self.lower_bandwidth + self.upper_bandwidth + 1) + column + self.lower_bandwidth
+            - line
+    }
+
+    ///
+    /// Returns the element at `(line, column)`, or `None` if it is outside of
+    /// the band or out of the matrix.
+    ///
+    #[must_use]
+    pub fn get(&self, line: usize, column: usize) -> Option<&K> {
+        if !self.is_in_band(line, column) {
+            return None;
+        }
+        Some(&self.content[self.index(line, column)])
+    }
+
+    ///
+    /// Returns a mutable reference to the element at `(line, column)`, or
+    /// `None` if it is outside of the band or out of the matrix.
+    ///
+    #[must_use]
+    pub fn get_mut(&mut self, line: usize, column: usize) -> Option<&mut K> {
+        if !self.is_in_band(line, column) {
+            return None;
+        }
+        let index = self.index(line, column);
+        Some(&mut self.content[index])
+    }
+
+    ///
+    /// Returns an iterator over the elements of the band, as
+    /// `(line, column, value)`, line after line.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &K)> {
+        (0..self.size).flat_map(move |line| {
+            let first = line.saturating_sub(self.lower_bandwidth);
+            let last = (line + self.upper_bandwidth).min(self.size - 1);
+            (first..=last)
+                .map(move |column| (line, column, &self.content[self.index(line, column)]))
+        })
+    }
+}
+
+impl<K: Clone + Default> Banded<K> {
+    ///
+    /// Creates a `size` * `size` banded matrix filled with zeros.
+    ///
+    pub fn zeros(size: usize, lower_bandwidth: usize, upper_bandwidth: usize) -> Self {
+        Self {
+            size,
+            lower_bandwidth,
+            upper_bandwidth,
+            content: vec![K::default(); size * (lower_bandwidth + upper_bandwidth + 1)],
+        }
+    }
+
+    ///
+    /// Returns the band of a square matrix, or `None` if the matrix is not a
+    /// square. The elements outside of the band are dropped.
+    ///
+    #[must_use]
+    pub fn from_matrix(
+        mat: &Matrix<K>,
+        lower_bandwidth: usize,
+        upper_bandwidth: usize,
+    ) -> Option<Self> {
+        if !mat.is_square() {
+            return None;
+        }
+        let size = mat.dimensions.height;
+        let mut result = Self::zeros(size, lower_bandwidth, upper_bandwidth);
+        for line in 0..size {
+            let first = line.saturating_sub(lower_bandwidth);
+            let last = (line + upper_bandwidth).min(size - 1);
+            for column in first..=last {
+                let index = result.index(line, column);
+                result.content[index] = mat.content[line * size + column].clone();
+            }
+        }
+        Some(result)
+    }
+
+    ///
+    /// Returns the dense [`Matrix`] holding the same elements.
+    ///
+    pub fn to_dense(&self) -> Matrix<K> {
+        let size = self.size;
+        let mut content = vec![K::default(); size * size];
+        for (line, column, value) in self.iter() {
+            content[line * size + column] = value.clone();
+        }
+        Matrix {
+            content,
+            dimensions: Dimensions {
+                width: size,
+                height: size,
+            },
+        }
+    }
+}
+
+impl<K: Clone + Default> From<&Banded<K>> for Matrix<K> {
+    fn from(value: &Banded<K>) -> Self {
+        value.to_dense()
+    }
+}
+
+impl<K: Clone + Default> From<&Tridiagonal<K>> for Banded<K> {
+    fn from(value: &Tridiagonal<K>) -> Self {
+        let mut result = Self::zeros(value.size(), 1, 1);
+        for (line, column, value) in value.iter() {
+            let index = result.index(line, column);
+            result.content[index] = value.clone();
+        }
+        result
+    }
+}
+
+impl<K> Banded<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    ///
+    /// Returns the product of the matrix by `vec`.
+    ///
+    /// # Errors
+    /// If the size of `vec` doesn't match the matrix, returns a [`SizeMismatch`](MulVecError::SizeMismatch)
+    ///
+    /// # Complexity
+    /// For a bandwidth `k = lower_bandwidth + upper_bandwidth + 1`:
+    /// Time: O(n * k)
+    /// Space: O(n)
+    ///
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Result<Vector<K>, MulVecError> {
+        if vec.len() != self.size {
+            return Err(MulVecError::SizeMismatch(self.size, vec.len()));
+        }
+        Ok(scatter_product(self.iter(), vec, self.size))
+    }
+}
+
+impl<K> LinearOperator<K> for Banded<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            width: self.size,
+            height: self.size,
+        }
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        scatter_product(self.iter(), vec, self.size)
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        let entries = self
+            .iter()
+            .map(|(line, column, value)| (column, line, value));
+        scatter_product(entries, vec, self.size)
+    }
+}
+
+impl<K> Banded<K>
+where
+    for<'a> K: Clone + Default + Abs + PartialOrd + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Performs a Gaussian elimination with partial pivoting on the matrix,
+    /// applying the same operations to `rhs` if it isn't empty.
+    ///
+    /// Returns the resulting upper triangular matrix, whose line `i` stores
+    /// the columns `i - lower_bandwidth..=i + lower_bandwidth + upper_bandwidth`,
+    /// and the number of swaps, or `None` if the matrix is singular.
+    ///
+    fn eliminate(&self, rhs: &mut [K]) -> Option<(Vec<K>, usize)> {
+        let (size, lower) = (self.size, self.lower_bandwidth);
+        // The swaps push the upper bandwidth to `lower + upper`
+        let width = 2 * lower + self.upper_bandwidth + 1;
+        let index = |line: usize, column: usize| line * width + column + lower - line;
+        let mut band = vec![K::default(); size * width];
+        for (line, column, value) in self.iter() {
+            band[index(line, column)] = value.clone();
+        }
+
+        let mut swaps = 0;
+        for step in 0..size {
+            let last_line = (step + lower).min(size - 1);
+            let last_column = (step + lower + self.upper_bandwidth).min(size - 1);
+            let pivot_line = (step + 1..=last_line).fold(step, |best, line| {
+                if band[index(line, step)].abs() > band[index(best, step)].abs() {
+                    line
+                } else {
+                    best
+                }
+            });
+            if (&band[index(pivot_line, step)]).is_zero() {
+                return None;
+            }
+            if pivot_line != step {
+                for column in step..=last_column {
+                    band.swap(index(step, column), index(pivot_line, column));
+                }
+                if !rhs.is_empty() {
+                    rhs.swap(step, pivot_line);
+                }
+                swaps += 1;
+            }
+            for line in step + 1..=last_line {
+                let factor = &band[index(line, step)] / &band[index(step, step)];
+                for column in step..=last_column {
+                    let tmp = &factor * &band[index(step, column)];
+                    band[index(line, column)] -= &tmp;
+                }
+                if !rhs.is_empty() {
+                    let tmp = &factor * &rhs[step];
+                    rhs[line] -= &tmp;
+                }
+            }
+        }
+        Some((band, swaps))
+    }
+
+    ///
+    /// Returns the solution `x` of `A * x = vec`, with a Gaussian elimination
+    /// with partial pivoting that stays within the band.
+    ///
+    /// # Errors
+    /// If the size of `vec` doesn't match the matrix, returns a [`SizeMismatch`](StructuredSolveError::SizeMismatch)
+    /// If the matrix is singular, returns a [`SingularMatrix`](StructuredSolveError::SingularMatrix)
+    ///
+    /// # Complexity
+    /// With `l` and `u` the lower and upper bandwidths:
+    /// Time: O(n * l * (l + u))
+    /// Space: O(n * (l + u))
+    ///
+    pub fn solve(&self, vec: &Vector<K>) -> Result<Vector<K>, StructuredSolveError> {
+        let size = self.size;
+        if vec.len() != size {
+            return Err(StructuredSolveError::SizeMismatch(size, vec.len()));
+        }
+        let mut result = vec.to_vec();
+        let (band, _) = self
+            .eliminate(&mut result)
+            .ok_or(StructuredSolveError::SingularMatrix)?;
+        let lower = self.lower_bandwidth;
+        let width = 2 * lower + self.upper_bandwidth + 1;
+        let index = |line: usize, column: usize| line * width + column + lower - line;
+        for line in (0..size).rev() {
+            let last_column = (line + lower + self.upper_bandwidth).min(size - 1);
+            for column in line + 1..=last_column {
+                let tmp = &band[index(line, column)] * &result[column];
+                result[line] -= &tmp;
+            }
+            result[line] = &result[line] / &band[index(line, line)];
+        }
+        Ok(Vector::from(result))
+    }
+}
+
+impl<K> Banded<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Abs
+        + PartialOrd
+        + Neg<Output = K>
+        + SubAssign<&'a K>
+        + MulAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the determinant of the matrix: the product of the pivots of
+    /// its elimination, whose sign changes with each swap.
+    ///
+    /// # Complexity
+    /// With `l` and `u` the lower and upper bandwidths:
+    /// Time: O(n * l * (l + u))
+    /// Space: O(n * (l + u))
+    ///
+    #[must_use]
+    pub fn determinant(&self) -> K {
+        let Some((band, swaps)) = self.eliminate(&mut []) else {
+            return K::default();
+        };
+        let width = 2 * self.lower_bandwidth + self.upper_bandwidth + 1;
+        let mut determinant = K::one();
+        for line in 0..self.size {
+            determinant *= &band[line * width + self.lower_bandwidth];
+        }
+        if swaps.is_multiple_of(2) {
+            determinant
+        } else {
+            -determinant
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        error::{MulVecError, StructuredSolveError},
+        linear_operator::LinearOperator,
+        matrix::{Banded, Tridiagonal},
+        Matrix, Vector,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn storage() {
+        let mat = Matrix::from([
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+            [13, 14, 15, 16],
+        ]);
+        let mut banded = Banded::from_matrix(&mat, 1, 2).unwrap();
+        assert_eq!(
+            banded.to_dense(),
+            [[1, 2, 3, 0], [5, 6, 7, 8], [0, 10, 11, 12], [0, 0, 15, 16]]
+        );
+        assert_eq!(banded.get(3, 1), None);
+        assert_eq!(banded.get(1, 3), Some(&8));
+        assert_eq!(banded.get_mut(0, 3), None);
+        if let Some(value) = banded.get_mut(3, 2) {
+            *value = 0;
+        }
+        assert_eq!(banded.get(3, 2), Some(&0));
+
+        let vec = Vector::from([1, -1, 2, 1]);
+        let dense = banded.to_dense();
+        assert_eq!(banded.mul_vec(&vec), dense.mul_vec(&vec));
+        assert_eq!(
+            banded.apply_transpose(&vec),
+            dense.transpose().mul_vec(&vec).unwrap()
+        );
+        assert_eq!(
+            banded.mul_vec(&Vector::from([1])),
+            Err(MulVecError::SizeMismatch(4, 1))
+        );
+        assert_eq!(Banded::from_matrix(&Matrix::from([[1, 2]]), 0, 0), None);
+    }
+
+    #[test]
+    fn solve() {
+        // Needs a swap on the first step
+        let mat = Matrix::from([
+            [1_f64, 4., 0., 0., 0.],
+            [3., 1., 2., 0., 0.],
+            [0., 2., 5., 1., 0.],
+            [0., 0., 1., 1., 7.],
+            [0., 0., 0., 2., 3.],
+        ]);
+        let banded = Banded::from_matrix(&mat, 1, 2).unwrap();
+        let rhs = Vector::from([1., 2., 3., 4., 5.]);
+        let solution = banded.solve(&rhs).unwrap();
+        let check = mat.mul_vec(&solution).unwrap();
+        for (value, expected) in check.iter().zip(rhs.iter()) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        let determinant = mat.determinant().unwrap();
+        assert!((banded.determinant() - determinant).abs() < 1e-10);
+
+        // A zero pivot for the Thomas algorithm, but not a singular matrix
+        let tridiagonal = Tridiagonal::new(vec![1.], vec![0., 1.], vec![1.]).unwrap();
+        let banded = Banded::from(&tridiagonal);
+        assert_eq!(
+            banded.solve(&Vector::from([1., 3.])),
+            Ok(Vector::from([2., 1.]))
+        );
+        assert_eq!(banded.determinant(), -1.);
+
+        let singular = Banded::from_matrix(&Matrix::from([[1., 2.], [2., 4.]]), 1, 1).unwrap();
+        assert_eq!(
+            singular.solve(&Vector::from([1., 1.])),
+            Err(StructuredSolveError::SingularMatrix)
+        );
+        assert_eq!(singular.determinant(), 0.);
+    }
+}
@@ -0,0 +1,516 @@
+use std::ops::{AddAssign, Div, Mul, MulAssign, SubAssign};
+
+use crate::{
+    error::{MulVecError, StructuredMatrixCreationError, StructuredSolveError},
+    linear_operator::LinearOperator,
+    matrix::Dimensions,
+    traits::{IsZero, One},
+    Matrix, Vector,
+};
+
+use super::scatter_product;
+
+///
+/// A square matrix whose elements below the diagonal are zero.
+///
+/// Only the `n * (n + 1) / 2` other elements are stored, line after line.
+///
+/// # Example
+/// ```
+/// use matrix::{matrix::UpperTriangular, Vector};
+///
+/// // [[2, 1], [0, 4]]
+/// let mat = UpperTriangular::from_packed(2, vec![2., 1., 4.]).unwrap();
+/// assert_eq!(mat.solve(&Vector::from([4., 8.])).unwrap(), [1., 2.]);
+/// assert_eq!(mat.determinant(), 8.);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct UpperTriangular<K> {
+    size: usize,
+    content: Vec<K>,
+}
+
+///
+/// A square matrix whose elements above the diagonal are zero.
+///
+/// Only the `n * (n + 1) / 2` other elements are stored, line after line.
+///
+/// # Example
+/// ```
+/// use matrix::{matrix::LowerTriangular, Vector};
+///
+/// // [[2, 0], [1, 4]]
+/// let mat = LowerTriangular::from_packed(2, vec![2., 1., 4.]).unwrap();
+/// assert_eq!(mat.solve(&Vector::from([2., 9.])).unwrap(), [1., 2.]);
+/// assert_eq!(mat.determinant(), 8.);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct LowerTriangular<K> {
+    size: usize,
+    content: Vec<K>,
+}
+
+impl<K> UpperTriangular<K> {
+    ///
+    /// Creates a `size` * `size` upper triangular matrix from the elements on
+    /// and above its diagonal, line after line.
+    ///
+    /// # Errors
+    /// If `content` doesn't have `size * (size + 1) / 2` elements, returns a [`SizeMismatch`](StructuredMatrixCreationError::SizeMismatch)
+    ///
+    pub fn from_packed(
+        size: usize,
+        content: Vec<K>,
+    ) -> Result<Self, StructuredMatrixCreationError> {
+        let expected = size * (size + 1) / 2;
+        if content.len() != expected {
+            return Err(StructuredMatrixCreationError::SizeMismatch(
+                expected,
+                content.len(),
+            ));
+        }
+        Ok(Self { size, content })
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    ///
+    /// Returns the stored elements, line after line.
+    ///
+    #[must_use]
+    pub fn packed(&self) -> &[K] {
+        &self.content
+    }
+
+    const fn index(&self, line: usize, column: usize) -> usize {
+        line * (2 * self.size + 1 - line) / 2 + column - line
+    }
+
+    ///
+    /// Returns the element at `(line, column)`, or `None` if it is below the
+    /// diagonal or out of the matrix.
+    ///
+    #[must_use]
+    pub fn get(&self, line: usize, column: usize) -> Option<&K> {
+        if line > column || column >= self.size {
+            return None;
+        }
+        Some(&self.content[self.index(line, column)])
+    }
+
+    ///
+    /// Returns an iterator over the stored elements, as `(line, column, value)`,
+    /// line after line.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &K)> {
+        let size = self.size;
+        (0..size)
+            .flat_map(move |line| (line..size).map(move |column| (line, column)))
+            .zip(&self.content)
+            .map(|((line, column), value)| (line, column, value))
+    }
+}
+
+impl<K> LowerTriangular<K> {
+    ///
+    /// Creates a `size` * `size` lower triangular matrix from the elements on
+    /// and below its diagonal, line after line.
+    ///
+    /// # Errors
+    /// If `content` doesn't have `size * (size + 1) / 2` elements, returns a [`SizeMismatch`](StructuredMatrixCreationError::SizeMismatch)
+    ///
+    pub fn from_packed(
+        size: usize,
+        content: Vec<K>,
+    ) -> Result<Self, StructuredMatrixCreationError> {
+        let expected = size * (size + 1) / 2;
+        if content.len() != expected {
+            return Err(StructuredMatrixCreationError::SizeMismatch(
+                expected,
+                content.len(),
+            ));
+        }
+        Ok(Self { size, content })
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    ///
+    /// Returns the stored elements, line after line.
+    ///
+    #[must_use]
+    pub fn packed(&self) -> &[K] {
+        &self.content
+    }
+
+    const fn index(line: usize, column: usize) -> usize {
+        line * (line + 1) / 2 + column
+    }
+
+    ///
+    /// Returns the element at `(line, column)`, or `None` if it is above the
+    /// diagonal or out of the matrix.
+    ///
+    #[must_use]
+    pub fn get(&self, line: usize, column: usize) -> Option<&K> {
+        if column > line || line >= self.size {
+            return None;
+        }
+        Some(&self.content[Self::index(line, column)])
+    }
+
+    ///
+    /// Returns an iterator over the stored elements, as `(line, column, value)`,
+    /// line after line.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &K)> {
+        (0..self.size)
+            .flat_map(|line| (0..=line).map(move |column| (line, column)))
+            .zip(&self.content)
+            .map(|((line, column), value)| (line, column, value))
+    }
+}
+
+impl<K: Clone> UpperTriangular<K> {
+    ///
+    /// Returns the upper triangular part of a square matrix, or `None` if the
+    /// matrix is not a square.
+    ///
+    #[must_use]
+    pub fn from_matrix(mat: &Matrix<K>) -> Option<Self> {
+        if !mat.is_square() {
+            return None;
+        }
+        let size = mat.dimensions.height;
+        let content = (0..size)
+            .flat_map(|line| mat.content[line * size + line..(line + 1) * size].iter())
+            .cloned()
+            .collect();
+        Some(Self { size, content })
+    }
+
+    ///
+    /// Returns the transpose of the matrix, which is lower triangular.
+    ///
+    /// # Complexity
+    /// Time: O(n^2)
+    /// Space: O(n^2)
+    ///
+    pub fn transpose(&self) -> LowerTriangular<K> {
+        let content = (0..self.size)
+            .flat_map(|line| (0..=line).map(move |column| (column, line)))
+            .map(|(line, column)| self.content[self.index(line, column)].clone())
+            .collect();
+        LowerTriangular {
+            size: self.size,
+            content,
+        }
+    }
+}
+
+impl<K: Clone> LowerTriangular<K> {
+    ///
+    /// Returns the lower triangular part of a square matrix, or `None` if the
+    /// matrix is not a square.
+    ///
+    #[must_use]
+    pub fn from_matrix(mat: &Matrix<K>) -> Option<Self> {
+        if !mat.is_square() {
+            return None;
+        }
+        let size = mat.dimensions.height;
+        let content = (0..size)
+            .flat_map(|line| mat.content[line * size..=line * size + line].iter())
+            .cloned()
+            .collect();
+        Some(Self { size, content })
+    }
+
+    ///
+    /// Returns the transpose of the matrix, which is upper triangular.
+    ///
+    /// # Complexity
+    /// Time: O(n^2)
+    /// Space: O(n^2)
+    ///
+    pub fn transpose(&self) -> UpperTriangular<K> {
+        let size = self.size;
+        let content = (0..size)
+            .flat_map(|line| (line..size).map(move |column| (column, line)))
+            .map(|(line, column)| self.content[Self::index(line, column)].clone())
+            .collect();
+        UpperTriangular { size, content }
+    }
+}
+
+macro_rules! impl_triangular {
+    ($type: ident) => {
+        impl<K: Clone + Default> $type<K> {
+            ///
+            /// Returns the dense [`Matrix`] holding the same elements.
+            ///
+            pub fn to_dense(&self) -> Matrix<K> {
+                let size = self.size;
+                let mut content = vec![K::default(); size * size];
+                for (line, column, value) in self.iter() {
+                    content[line * size + column] = value.clone();
+                }
+                Matrix {
+                    content,
+                    dimensions: Dimensions {
+                        width: size,
+                        height: size,
+                    },
+                }
+            }
+        }
+
+        impl<K: Clone + Default> From<&$type<K>> for Matrix<K> {
+            fn from(value: &$type<K>) -> Self {
+                value.to_dense()
+            }
+        }
+
+        impl<K> $type<K>
+        where
+            for<'a> K: Clone + Default + AddAssign<&'a K>,
+            for<'a> &'a K: Mul<&'a K, Output = K>,
+        {
+            ///
+            /// Returns the product of the matrix by `vec`.
+            ///
+            /// # Errors
+            /// If the size of `vec` doesn't match the matrix, returns a [`SizeMismatch`](MulVecError::SizeMismatch)
+            ///
+            /// # Complexity
+            /// Time: O(n^2)
+            /// Space: O(n)
+            ///
+            pub fn mul_vec(&self, vec: &Vector<K>) -> Result<Vector<K>, MulVecError> {
+                if vec.len() != self.size {
+                    return Err(MulVecError::SizeMismatch(self.size, vec.len()));
+                }
+                Ok(scatter_product(self.iter(), vec, self.size))
+            }
+        }
+
+        impl<K> LinearOperator<K> for $type<K>
+        where
+            for<'a> K: Clone + Default + AddAssign<&'a K>,
+            for<'a> &'a K: Mul<&'a K, Output = K>,
+        {
+            fn dimensions(&self) -> Dimensions {
+                Dimensions {
+                    width: self.size,
+                    height: self.size,
+                }
+            }
+
+            fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+                scatter_product(self.iter(), vec, self.size)
+            }
+
+            fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+                let entries = self
+                    .iter()
+                    .map(|(line, column, value)| (column, line, value));
+                scatter_product(entries, vec, self.size)
+            }
+        }
+
+        impl<K> $type<K>
+        where
+            for<'a> K: Clone + One + MulAssign<&'a K>,
+        {
+            ///
+            /// Returns the determinant of the matrix: the product of its
+            /// diagonal.
+            ///
+            /// # Complexity
+            /// Time: O(n)
+            /// Space: O(1)
+            ///
+            #[must_use]
+            pub fn determinant(&self) -> K {
+                let mut determinant = K::one();
+                for line in 0..self.size {
+                    if let Some(value) = self.get(line, line) {
+                        determinant *= value;
+                    }
+                }
+                determinant
+            }
+        }
+    };
+}
+
+impl_triangular!(UpperTriangular);
+impl_triangular!(LowerTriangular);
+
+impl<K> UpperTriangular<K>
+where
+    for<'a> K: Clone + Default + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the solution `x` of `U * x = vec`, by back substitution.
+    ///
+    /// # Errors
+    /// If the size of `vec` doesn't match the matrix, returns a [`SizeMismatch`](StructuredSolveError::SizeMismatch)
+    /// If there is a zero on the diagonal, returns a [`SingularMatrix`](StructuredSolveError::SingularMatrix)
+    ///
+    /// # Complexity
+    /// Time: O(n^2)
+    /// Space: O(n)
+    ///
+    pub fn solve(&self, vec: &Vector<K>) -> Result<Vector<K>, StructuredSolveError> {
+        let size = self.size;
+        if vec.len() != size {
+            return Err(StructuredSolveError::SizeMismatch(size, vec.len()));
+        }
+        if (0..size).any(|line| (&self.content[self.index(line, line)]).is_zero()) {
+            return Err(StructuredSolveError::SingularMatrix);
+        }
+        let mut result = vec.to_vec();
+        for line in (0..size).rev() {
+            for column in line + 1..size {
+                let tmp = &self.content[self.index(line, column)] * &result[column];
+                result[line] -= &tmp;
+            }
+            result[line] = &result[line] / &self.content[self.index(line, line)];
+        }
+        Ok(Vector::from(result))
+    }
+}
+
+impl<K> LowerTriangular<K>
+where
+    for<'a> K: Clone + Default + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the solution `x` of `L * x = vec`, by forward substitution.
+    ///
+    /// # Errors
+    /// If the size of `vec` doesn't match the matrix, returns a [`SizeMismatch`](StructuredSolveError::SizeMismatch)
+    /// If there is a zero on the diagonal, returns a [`SingularMatrix`](StructuredSolveError::SingularMatrix)
+    ///
+    /// # Complexity
+    /// Time: O(n^2)
+    /// Space: O(n)
+    ///
+    pub fn solve(&self, vec: &Vector<K>) -> Result<Vector<K>, StructuredSolveError> {
+        let size = self.size;
+        if vec.len() != size {
+            return Err(StructuredSolveError::SizeMismatch(size, vec.len()));
+        }
+        if (0..size).any(|line| (&self.content[Self::index(line, line)]).is_zero()) {
+            return Err(StructuredSolveError::SingularMatrix);
+        }
+        let mut result = vec.to_vec();
+        for line in 0..size {
+            for column in 0..line {
+                let tmp = &self.content[Self::index(line, column)] * &result[column];
+                result[line] -= &tmp;
+            }
+            result[line] = &result[line] / &self.content[Self::index(line, line)];
+        }
+        Ok(Vector::from(result))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        error::{MulVecError, StructuredMatrixCreationError, StructuredSolveError},
+        linear_operator::LinearOperator,
+        matrix::{LowerTriangular, UpperTriangular},
+        rational::rat,
+        Matrix, Vector,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn storage() {
+        let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let upper = UpperTriangular::from_matrix(&mat).unwrap();
+        assert_eq!(upper.packed(), [1, 2, 3, 5, 6, 9]);
+        assert_eq!(upper.get(1, 2), Some(&6));
+        assert_eq!(upper.get(2, 1), None);
+        assert_eq!(upper.to_dense(), [[1, 2, 3], [0, 5, 6], [0, 0, 9]]);
+
+        let lower = LowerTriangular::from_matrix(&mat).unwrap();
+        assert_eq!(lower.packed(), [1, 4, 5, 7, 8, 9]);
+        assert_eq!(lower.get(2, 1), Some(&8));
+        assert_eq!(lower.get(1, 2), None);
+        assert_eq!(lower.to_dense(), [[1, 0, 0], [4, 5, 0], [7, 8, 9]]);
+
+        assert_eq!(upper.transpose().to_dense(), upper.to_dense().transpose());
+        assert_eq!(lower.transpose().to_dense(), lower.to_dense().transpose());
+        assert_eq!(
+            UpperTriangular::from_packed(3, vec![1, 2, 3]),
+            Err(StructuredMatrixCreationError::SizeMismatch(6, 3))
+        );
+        assert_eq!(UpperTriangular::from_matrix(&Matrix::from([[1, 2]])), None);
+    }
+
+    #[test]
+    fn products() {
+        let upper = UpperTriangular::from_packed(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let vec = Vector::from([1, -1, 2]);
+        let dense = upper.to_dense();
+        assert_eq!(upper.mul_vec(&vec), dense.mul_vec(&vec));
+        assert_eq!(
+            upper.apply_transpose(&vec),
+            dense.transpose().mul_vec(&vec).unwrap()
+        );
+        assert_eq!(
+            upper.mul_vec(&Vector::from([1])),
+            Err(MulVecError::SizeMismatch(3, 1))
+        );
+
+        let lower = upper.transpose();
+        assert_eq!(lower.mul_vec(&vec), dense.transpose().mul_vec(&vec));
+        assert_eq!(lower.apply_transpose(&vec), upper.apply(&vec));
+    }
+
+    #[test]
+    fn solve() {
+        let upper = UpperTriangular::from_packed(
+            3,
+            vec![rat!(2), rat!(1), rat!(-1), rat!(3), rat!(2), rat!(4)],
+        )
+        .unwrap();
+        let rhs = Vector::from([rat!(1), rat!(2), rat!(3)]);
+        let solution = upper.solve(&rhs).unwrap();
+        assert_eq!(upper.mul_vec(&solution), Ok(rhs.clone()));
+        assert_eq!(upper.determinant(), rat!(24));
+
+        let lower = upper.transpose();
+        let solution = lower.solve(&rhs).unwrap();
+        assert_eq!(lower.mul_vec(&solution), Ok(rhs));
+        assert_eq!(lower.determinant(), rat!(24));
+
+        let singular = LowerTriangular::from_packed(2, vec![1., 2., 0.]).unwrap();
+        assert_eq!(
+            singular.solve(&Vector::from([1., 1.])),
+            Err(StructuredSolveError::SingularMatrix)
+        );
+        assert_eq!(singular.determinant(), 0.);
+        assert_eq!(
+            singular.solve(&Vector::from([1.])),
+            Err(StructuredSolveError::SizeMismatch(2, 1))
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use std::ops::{AddAssign, Div, Mul, Sub, SubAssign};
+
+use crate::{
+    error::{MulVecError, StructuredMatrixCreationError, StructuredSolveError},
+    linear_operator::LinearOperator,
+    matrix::Dimensions,
+    traits::{IsZero, One},
+    Matrix, Vector,
+};
+
+use super::scatter_product;
+
+///
+/// A square matrix whose only non-zero elements are on its diagonal, and
+/// right above and below it.
+///
+/// Only these three diagonals are stored.
+///
+/// # Example
+/// ```
+/// use matrix::{matrix::Tridiagonal, Vector};
+///
+/// // [[2, -1, 0], [-1, 2, -1], [0, -1, 2]]
+/// let mat = Tridiagonal::new(vec![-1_f64, -1.], vec![2., 2., 2.], vec![-1., -1.]).unwrap();
+/// let solution = mat.solve(&Vector::from([0., 0., 4.])).unwrap();
+/// for (value, expected) in solution.iter().zip([1., 2., 3.]) {
+///     assert!((value - expected).abs() < 1e-12);
+/// }
+/// assert_eq!(mat.determinant(), 4.);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Tridiagonal<K> {
+    lower: Vec<K>,
+    diagonal: Vec<K>,
+    upper: Vec<K>,
+}
+
+impl<K> Tridiagonal<K> {
+    ///
+    /// Creates a tridiagonal matrix from its three diagonals: `lower[i]` is
+    /// at `(i + 1, i)`, `diagonal[i]` at `(i, i)` and `upper[i]` at `(i, i + 1)`.
+    ///
+    /// # Errors
+    /// If `lower` or `upper` doesn't have one element less than `diagonal`, returns a [`SizeMismatch`](StructuredMatrixCreationError::SizeMismatch)
+    ///
+    pub fn new(
+        lower: Vec<K>,
+        diagonal: Vec<K>,
+        upper: Vec<K>,
+    ) -> Result<Self, StructuredMatrixCreationError> {
+        let expected = diagonal.len().saturating_sub(1);
+        for side in [&lower, &upper] {
+            if side.len() != expected {
+                return Err(StructuredMatrixCreationError::SizeMismatch(
+                    expected,
+                    side.len(),
+                ));
+            }
+        }
+        Ok(Self {
+            lower,
+            diagonal,
+            upper,
+        })
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    ///
+    /// Returns the diagonal below the main one.
+    ///
+    #[must_use]
+    pub fn lower(&self) -> &[K] {
+        &self.lower
+    }
+
+    ///
+    /// Returns the main diagonal.
+    ///
+    #[must_use]
+    pub fn diagonal(&self) -> &[K] {
+        &self.diagonal
+    }
+
+    ///
+    /// Returns the diagonal above the main one.
+    ///
+    #[must_use]
+    pub fn upper(&self) -> &[K] {
+        &self.upper
+    }
+
+    ///
+    /// Returns the element at `(line, column)`, or `None` if it is outside of
+    /// the three diagonals or out of the matrix.
+    ///
+    #[must_use]
+    pub fn get(&self, line: usize, column: usize) -> Option<&K> {
+        if line == column {
+            self.diagonal.get(line)
+        } else if line == column + 1 {
+            self.lower.get(column)
+        } else if column == line + 1 {
+            self.upper.get(line)
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns an iterator over the stored elements, as `(line, column, value)`,
+    /// line after line.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &K)> {
+        (0..self.size()).flat_map(move |line| {
+            let lower = line
+                .checked_sub(1)
+                .map(|column| (line, column, &self.lower[column]));
+            let upper = self.upper.get(line).map(|value| (line, line + 1, value));
+            lower
+                .into_iter()
+                .chain(Some((line, line, &self.diagonal[line])))
+                .chain(upper)
+        })
+    }
+}
+
+impl<K: Clone + Default> Tridiagonal<K> {
+    ///
+    /// Returns the dense [`Matrix`] holding the same elements.
+    ///
+    pub fn to_dense(&self) -> Matrix<K> {
+        let size = self.size();
+        let mut content = vec![K::default(); size * size];
+        for (line, column, value) in self.iter() {
+            content[line * size + column] = value.clone();
+        }
+        Matrix {
+            content,
+            dimensions: Dimensions {
+                width: size,
+                height: size,
+            },
+        }
+    }
+}
+
+impl<K: Clone + Default> From<&Tridiagonal<K>> for Matrix<K> {
+    fn from(value: &Tridiagonal<K>) -> Self {
+        value.to_dense()
+    }
+}
+
+impl<K> Tridiagonal<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    ///
+    /// Returns the product of the matrix by `vec`.
+    ///
+    /// # Errors
+    /// If the size of `vec` doesn't match the matrix, returns a [`SizeMismatch`](MulVecError::SizeMismatch)
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    /// Space: O(n)
+    ///
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Result<Vector<K>, MulVecError> {
+        if vec.len() != self.size() {
+            return Err(MulVecError::SizeMismatch(self.size(), vec.len()));
+        }
+        Ok(scatter_product(self.iter(), vec, self.size()))
+    }
+}
+
+impl<K> LinearOperator<K> for Tridiagonal<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            width: self.size(),
+            height: self.size(),
+        }
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        scatter_product(self.iter(), vec, self.size())
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        let entries = self
+            .iter()
+            .map(|(line, column, value)| (column, line, value));
+        scatter_product(entries, vec, self.size())
+    }
+}
+
+impl<K> Tridiagonal<K>
+where
+    for<'a> K: Clone + Default + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the solution `x` of `A * x = vec`, with the Thomas algorithm:
+    /// a Gaussian elimination without pivoting, that only touches the three
+    /// diagonals.
+    ///
+    /// It is stable for diagonally dominant or symmetric positive definite
+    /// matrices. For other ones, a [`Banded`](crate::matrix::Banded) matrix
+    /// solves with pivoting.
+    ///
+    /// # Errors
+    /// If the size of `vec` doesn't match the matrix, returns a [`SizeMismatch`](StructuredSolveError::SizeMismatch)
+    /// If the elimination meets a zero pivot, returns a [`ZeroPivot`](StructuredSolveError::ZeroPivot)
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    /// Space: O(n)
+    ///
+    pub fn solve(&self, vec: &Vector<K>) -> Result<Vector<K>, StructuredSolveError> {
+        let size = self.size();
+        if vec.len() != size {
+            return Err(StructuredSolveError::SizeMismatch(size, vec.len()));
+        }
+        if size == 0 {
+            return Ok(vec.clone());
+        }
+        // The upper diagonal and the right hand side, once the lower diagonal
+        // is eliminated and the diagonal divided out
+        let mut upper = Vec::with_capacity(size - 1);
+        let mut result = Vec::with_capacity(size);
+        for line in 0..size {
+            let mut pivot = self.diagonal[line].clone();
+            let mut value = vec[line].clone();
+            if line > 0 {
+                let lower = &self.lower[line - 1];
+                pivot -= &(lower * &upper[line - 1]);
+                value -= &(lower * &result[line - 1]);
+            }
+            if (&pivot).is_zero() {
+                return Err(StructuredSolveError::ZeroPivot(line));
+            }
+            if line + 1 < size {
+                upper.push(&self.upper[line] / &pivot);
+            }
+            result.push(&value / &pivot);
+        }
+        for line in (0..size - 1).rev() {
+            let tmp = &upper[line] * &result[line + 1];
+            result[line] -= &tmp;
+        }
+        Ok(Vector::from(result))
+    }
+}
+
+impl<K> Tridiagonal<K>
+where
+    K: Clone + One,
+    for<'a> &'a K: Sub<&'a K, Output = K> + Mul<&'a K, Output = K>,
+{
+    ///
+    /// Returns the determinant of the matrix, with the three-term recurrence
+    /// `f(i) = d(i) * f(i - 1) - l(i - 1) * u(i - 1) * f(i - 2)` on its
+    /// leading principal minors.
+    ///
+    /// It doesn't divide, which makes it exact over integers.
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    /// Space: O(1)
+    ///
+    #[must_use]
+    pub fn determinant(&self) -> K {
+        let mut previous = K::one();
+        let mut current = K::one();
+        for (line, diagonal) in self.diagonal.iter().enumerate() {
+            let next = if line == 0 {
+                diagonal.clone()
+            } else {
+                let coupling = &self.lower[line - 1] * &self.upper[line - 1];
+                &(diagonal * &current) - &(&coupling * &previous)
+            };
+            previous = current;
+            current = next;
+        }
+        current
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        error::{StructuredMatrixCreationError, StructuredSolveError},
+        linear_operator::LinearOperator,
+        matrix::Tridiagonal,
+        Vector,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn storage() {
+        let mat = Tridiagonal::new(vec![1, 2], vec![3, 4, 5], vec![6, 7]).unwrap();
+        assert_eq!(mat.to_dense(), [[3, 6, 0], [1, 4, 7], [0, 2, 5]]);
+        assert_eq!(mat.get(2, 1), Some(&2));
+        assert_eq!(mat.get(0, 2), None);
+        assert_eq!(
+            mat.determinant(),
+            mat.to_dense().fraction_free_determinant().unwrap()
+        );
+        let vec = Vector::from([1, -1, 2]);
+        assert_eq!(mat.mul_vec(&vec), mat.to_dense().mul_vec(&vec));
+        assert_eq!(
+            mat.apply_transpose(&vec),
+            mat.to_dense().transpose().mul_vec(&vec).unwrap()
+        );
+
+        assert_eq!(
+            Tridiagonal::new(vec![1], vec![3, 4, 5], vec![6, 7]),
+            Err(StructuredMatrixCreationError::SizeMismatch(2, 1))
+        );
+        let empty = Tridiagonal::<i32>::new(vec![], vec![], vec![]).unwrap();
+        assert_eq!(empty.determinant(), 1);
+    }
+
+    #[test]
+    fn thomas() {
+        // Second derivative with Dirichlet conditions: -u'' = 2 on ]0, 1[,
+        // whose solution x * (1 - x) is exact on the grid
+        let size = 99;
+        let step = 1. / 100.;
+        let mat =
+            Tridiagonal::new(vec![-1_f64; size - 1], vec![2.; size], vec![-1.; size - 1]).unwrap();
+        let rhs = Vector::fill(&(2. * step * step), size);
+        let solution = mat.solve(&rhs).unwrap();
+        let mut x = 0.;
+        for value in &solution {
+            x += step;
+            assert!((value - x * (1. - x)).abs() < 1e-12);
+        }
+
+        let mat = Tridiagonal::new(vec![1.], vec![0., 1.], vec![1.]).unwrap();
+        assert_eq!(
+            mat.solve(&Vector::from([1., 1.])),
+            Err(StructuredSolveError::ZeroPivot(0))
+        );
+        assert_eq!(
+            mat.solve(&Vector::from([1.])),
+            Err(StructuredSolveError::SizeMismatch(2, 1))
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//!
+//! Bases of the four fundamental subspaces of a [`Matrix`].
+//!
+//! # Implementations details
+//!
+//! Everything is read from the reduced row echelon form `R` of the matrix:
+//! - its pivot columns give the column space, taken in the original matrix,
+//! - its non-zero lines span the row space,
+//! - each free column `f` gives a vector of the null space, with a `1` at
+//!   `f` and `-R[i][f]` at the pivot column of each line `i`.
+//!
+//! The left null space is the null space of the transpose.
+//!
+
+use std::ops::{Div, DivAssign, Mul, MulAssign, SubAssign};
+
+use crate::{
+    traits::{ApproxZero, IsZero, One},
+    Matrix, Vector,
+};
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone + One + Default + MulAssign<&'a K> + SubAssign<&'a K> + DivAssign<&'a K>,
+    for<'a> &'a K: PartialEq + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the indices of the pivot columns of a matrix, in increasing
+    /// order. There are as many as the rank of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let u = Matrix::from([[1., 2., 0., 3.], [2., 4., 1., 1.]]);
+    /// assert_eq!(u.pivot_columns(), [0, 2]);
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix:
+    /// Time: O(mn * min(m, n))
+    /// Space: O(mn)
+    ///
+    #[must_use]
+    pub fn pivot_columns(&self) -> Vec<usize> {
+        self.pivot_columns_with(|x| x.is_zero())
+    }
+
+    ///
+    /// Returns a basis of the null space (or kernel) of a matrix: the vectors
+    /// `x` such that `A * x = 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, Vector};
+    ///
+    /// let u = Matrix::from([[1., 2.], [2., 4.]]);
+    /// assert_eq!(u.null_space(), [Vector::from([-2., 1.])]);
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix:
+    /// Time: O(mn * min(m, n) + n^2)
+    /// Space: O(mn + n^2)
+    ///
+    #[must_use]
+    pub fn null_space(&self) -> Vec<Vector<K>> {
+        self.null_space_with(|x| x.is_zero())
+    }
+
+    ///
+    /// Returns a basis of the column space (or image) of a matrix: its pivot
+    /// columns.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, Vector};
+    ///
+    /// let u = Matrix::from([[1., 2.], [2., 4.]]);
+    /// assert_eq!(u.column_space(), [Vector::from([1., 2.])]);
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix:
+    /// Time: O(mn * min(m, n))
+    /// Space: O(mn)
+    ///
+    #[must_use]
+    pub fn column_space(&self) -> Vec<Vector<K>> {
+        self.column_space_with(|x| x.is_zero())
+    }
+
+    ///
+    /// Returns a basis of the row space of a matrix: the non-zero lines of
+    /// its reduced row echelon form.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, Vector};
+    ///
+    /// let u = Matrix::from([[1., 2.], [2., 4.]]);
+    /// assert_eq!(u.row_space(), [Vector::from([1., 2.])]);
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix:
+    /// Time: O(mn * min(m, n))
+    /// Space: O(mn)
+    ///
+    #[must_use]
+    pub fn row_space(&self) -> Vec<Vector<K>> {
+        self.row_space_with(|x| x.is_zero())
+    }
+
+    ///
+    /// Returns a basis of the left null space (or cokernel) of a matrix: the
+    /// vectors `y` such that `yᵀ * A = 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, Vector};
+    ///
+    /// let u = Matrix::from([[1., 2.], [2., 4.]]);
+    /// assert_eq!(u.left_null_space(), [Vector::from([-2., 1.])]);
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix:
+    /// Time: O(mn * min(m, n) + m^2)
+    /// Space: O(mn + m^2)
+    ///
+    #[must_use]
+    pub fn left_null_space(&self) -> Vec<Vector<K>> {
+        self.transpose().null_space()
+    }
+
+    ///
+    /// Returns the pivot columns of a reduced row echelon form.
+    ///
+    pub(super) fn pivots_of(reduced: &Self, is_zero: impl Fn(&K) -> bool) -> Vec<usize> {
+        reduced
+            .content
+            .chunks(reduced.dimensions.width.max(1))
+            .map_while(|line| line.iter().position(|x| !is_zero(x)))
+            .collect()
+    }
+
+    ///
+    /// Returns the basis of the null space given by the free columns among
+    /// the first `width` columns of a reduced row echelon form, of pivot
+    /// columns `pivots`.
+    ///
+    pub(super) fn null_space_of(reduced: &Self, pivots: &[usize], width: usize) -> Vec<Vector<K>> {
+        let stride = reduced.dimensions.width;
+        let mut pivots_iter = pivots.iter().peekable();
+        let mut basis = Vec::with_capacity(width - pivots.len());
+        for free in 0..width {
+            if pivots_iter.next_if_eq(&&free).is_some() {
+                continue;
+            }
+            let mut content = vec![K::default(); width];
+            content[free] = K::one();
+            for (line, pivot) in pivots.iter().enumerate() {
+                content[*pivot] -= &reduced[line * stride + free];
+            }
+            basis.push(Vector::from(content));
+        }
+        basis
+    }
+
+    pub(super) fn pivot_columns_with(&self, is_zero: impl Fn(&K) -> bool) -> Vec<usize> {
+        Self::pivots_of(&self.reduced_row_echelon_with(&is_zero), is_zero)
+    }
+
+    fn null_space_with(&self, is_zero: impl Fn(&K) -> bool) -> Vec<Vector<K>> {
+        let reduced = self.reduced_row_echelon_with(&is_zero);
+        let pivots = Self::pivots_of(&reduced, is_zero);
+        Self::null_space_of(&reduced, &pivots, self.dimensions.width)
+    }
+
+    fn column_space_with(&self, is_zero: impl Fn(&K) -> bool) -> Vec<Vector<K>> {
+        let width = self.dimensions.width;
+        self.pivot_columns_with(is_zero)
+            .into_iter()
+            .map(|column| {
+                Vector::from(
+                    (0..self.dimensions.height)
+                        .map(|line| self[line * width + column].clone())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect()
+    }
+
+    fn row_space_with(&self, is_zero: impl Fn(&K) -> bool) -> Vec<Vector<K>> {
+        let reduced = self.reduced_row_echelon_with(&is_zero);
+        reduced
+            .content
+            .chunks(reduced.dimensions.width.max(1))
+            .take_while(|line| line.iter().any(|x| !is_zero(x)))
+            .map(Vector::from)
+            .collect()
+    }
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K:
+        Clone + One + Default + MulAssign<&'a K> + SubAssign<&'a K> + DivAssign<&'a K> + ApproxZero,
+    for<'a> &'a K: PartialEq + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the pivot columns of a matrix, where every element whose
+    /// absolute value is at most `tolerance` is considered to be zero during
+    /// the elimination.
+    ///
+    #[must_use]
+    pub fn pivot_columns_with_tolerance(&self, tolerance: &K::Tolerance) -> Vec<usize> {
+        self.pivot_columns_with(|x| x.is_approx_zero(tolerance))
+    }
+
+    ///
+    /// Returns a basis of the null space of a matrix, where every element
+    /// whose absolute value is at most `tolerance` is considered to be zero
+    /// during the elimination.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, Vector};
+    ///
+    /// let u = Matrix::from([[1., 2.], [1., 2. + 1e-12]]);
+    /// assert!(u.null_space().is_empty());
+    /// assert_eq!(u.null_space_with_tolerance(&1e-9), [Vector::from([-2., 1.])]);
+    /// ```
+    ///
+    #[must_use]
+    pub fn null_space_with_tolerance(&self, tolerance: &K::Tolerance) -> Vec<Vector<K>> {
+        self.null_space_with(|x| x.is_approx_zero(tolerance))
+    }
+
+    ///
+    /// Returns a basis of the column space of a matrix, where every element
+    /// whose absolute value is at most `tolerance` is considered to be zero
+    /// during the elimination.
+    ///
+    #[must_use]
+    pub fn column_space_with_tolerance(&self, tolerance: &K::Tolerance) -> Vec<Vector<K>> {
+        self.column_space_with(|x| x.is_approx_zero(tolerance))
+    }
+
+    ///
+    /// Returns a basis of the row space of a matrix, where every element
+    /// whose absolute value is at most `tolerance` is considered to be zero
+    /// during the elimination.
+    ///
+    #[must_use]
+    pub fn row_space_with_tolerance(&self, tolerance: &K::Tolerance) -> Vec<Vector<K>> {
+        self.row_space_with(|x| x.is_approx_zero(tolerance))
+    }
+
+    ///
+    /// Returns a basis of the left null space of a matrix, where every
+    /// element whose absolute value is at most `tolerance` is considered to
+    /// be zero during the elimination.
+    ///
+    #[must_use]
+    pub fn left_null_space_with_tolerance(&self, tolerance: &K::Tolerance) -> Vec<Vector<K>> {
+        self.transpose().null_space_with_tolerance(tolerance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use crate::{rational::rat, Gf, Matrix, Vector};
+
+    #[test]
+    fn example() {
+        let u = Matrix::from([
+            [rat!(1_i64), rat!(2), rat!(0), rat!(3)],
+            [rat!(2), rat!(4), rat!(1), rat!(1)],
+            [rat!(3), rat!(6), rat!(1), rat!(4)],
+        ]);
+        assert_eq!(u.pivot_columns(), [0, 2]);
+        assert_eq!(
+            u.null_space(),
+            [
+                Vector::from([rat!(-2), rat!(1), rat!(0), rat!(0)]),
+                Vector::from([rat!(-3), rat!(0), rat!(5), rat!(1)]),
+            ]
+        );
+        assert_eq!(
+            u.column_space(),
+            [
+                Vector::from([rat!(1), rat!(2), rat!(3)]),
+                Vector::from([rat!(0), rat!(1), rat!(1)]),
+            ]
+        );
+        assert_eq!(
+            u.row_space(),
+            [
+                Vector::from([rat!(1), rat!(2), rat!(0), rat!(3)]),
+                Vector::from([rat!(0), rat!(0), rat!(1), rat!(-5)]),
+            ]
+        );
+        assert_eq!(
+            u.left_null_space(),
+            [Vector::from([rat!(-1), rat!(-1), rat!(1)])]
+        );
+        for vector in u.null_space() {
+            assert_eq!(u.mul_vec(&vector), Ok(Vector::from([rat!(0); 3])));
+        }
+    }
+
+    #[test]
+    fn dimensions() {
+        // rank + nullity = number of columns
+        let u = Matrix::from([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
+        assert_eq!(u.pivot_columns(), [0, 1, 2]);
+        assert!(u.null_space().is_empty());
+        assert!(u.left_null_space().is_empty());
+        assert_eq!(u.row_space().len(), 3);
+
+        let u = Matrix::from([[0., 1., 0.]]);
+        assert_eq!(u.pivot_columns(), [1]);
+        assert_eq!(u.rank(), 1);
+        assert_eq!(
+            u.null_space(),
+            [Vector::from([1., 0., 0.]), Vector::from([0., 0., 1.])]
+        );
+
+        let u = Matrix::from([[0., 0.], [0., 0.]]);
+        assert!(u.pivot_columns().is_empty());
+        assert_eq!(u.null_space().len(), 2);
+        assert!(u.column_space().is_empty());
+        assert!(u.row_space().is_empty());
+    }
+
+    #[test]
+    fn with_tolerance() {
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9. + 1e-13]]);
+        assert_eq!(u.pivot_columns_with_tolerance(&1e-9), [0, 1]);
+        assert_eq!(u.column_space_with_tolerance(&1e-9).len(), 2);
+        assert_eq!(u.row_space_with_tolerance(&1e-9).len(), 2);
+        let kernel = u.null_space_with_tolerance(&1e-9);
+        assert_eq!(kernel.len(), 1);
+        assert!(u.mul_vec(&kernel[0]).is_ok_and(|res| res.norm() < 1e-9));
+        assert_eq!(u.left_null_space_with_tolerance(&1e-9).len(), 1);
+    }
+
+    #[test]
+    fn with_finite_field() {
+        // The parity check matrix of the Hamming(7, 4) code has its kernel
+        // spanned by the lines of the generator matrix
+        let h = Matrix::from(
+            [
+                [1, 1, 0, 1, 1, 0, 0],
+                [1, 0, 1, 1, 0, 1, 0],
+                [0, 1, 1, 1, 0, 0, 1],
+            ]
+            .map(|line| line.map(Gf::<2>::new)),
+        );
+        let kernel = h.null_space();
+        assert_eq!(kernel.len(), 4);
+        for vector in kernel {
+            assert_eq!(h.mul_vec(&vector), Ok(Vector::from([Gf::new(0); 3])));
+        }
+    }
+}
@@ -0,0 +1,522 @@
+//!
+//! Singular value decomposition of a real [`Matrix`], using one-sided Jacobi
+//! rotations.
+//!
+//! # Implementations details
+//!
+//! For a `m` * `n` matrix with `m >= n`, plane rotations are applied on the
+//! right of the matrix until all of its columns are orthogonal to each other.
+//! The norms of these columns are the singular values, the normalized columns
+//! form `U`, and the product of the rotations forms `V`.
+//! A wide matrix is handled by decomposing its transpose.
+//!
+//! The decomposition is the thin one: with `k = min(m, n)`, `U` is `m` * `k`,
+//! `Σ` is `k` * `k` and `Vᵀ` is `k` * `n`.
+//!
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Sub};
+
+use crate::{
+    traits::{Abs, Epsilon, One, Sqrt},
+    utils::dot,
+    Matrix, Vector,
+};
+
+use super::Dimensions;
+
+/// Past this number of sweeps, the rotations are considered to have converged.
+const MAX_SWEEPS: usize = 64;
+
+///
+/// The result of the singular value decomposition of a [`Matrix`] `A`, such
+/// that `A = U * Σ * Vᵀ`.
+///
+/// See [`svd`](Matrix#method.svd).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct SvdDecomposition<K: Clone> {
+    u: Matrix<K>,
+    singular_values: Vector<K>,
+    v_transpose: Matrix<K>,
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Sqrt
+        + Abs
+        + Epsilon
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>,
+{
+    ///
+    /// Returns the singular value decomposition of a matrix.
+    ///
+    /// The singular values are sorted in decreasing order.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[0., 2.], [3., 0.]]);
+    /// let svd = mat.svd();
+    /// assert_eq!(svd.singular_values(), &[3., 2.]);
+    /// assert_eq!(svd.u(), &Matrix::from([[0., 1.], [1., 0.]]));
+    /// assert_eq!(svd.v_transpose(), &Matrix::from([[1., 0.], [0., 1.]]));
+    /// ```
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix, each sweep of rotations takes
+    /// Time: O(mn * min(m, n))
+    /// Space: O(mn)
+    ///
+    pub fn svd(&self) -> SvdDecomposition<K> {
+        let (height, width) = self.size();
+        if height < width {
+            let transposed = self.transpose().svd();
+            return SvdDecomposition {
+                u: transposed.v_transpose.transpose(),
+                singular_values: transposed.singular_values,
+                v_transpose: transposed.u.transpose(),
+            };
+        }
+
+        let mut u: Vec<Vec<K>> = (0..width)
+            .map(|column| {
+                (0..height)
+                    .map(|line| self[line * width + column].clone())
+                    .collect()
+            })
+            .collect();
+        let mut v: Vec<Vec<K>> = (0..width)
+            .map(|column| {
+                (0..width)
+                    .map(|line| {
+                        if line == column {
+                            K::one()
+                        } else {
+                            K::default()
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for first in 0..width {
+                for second in first + 1..width {
+                    let alpha = dot(&u[first], &u[first]);
+                    let beta = dot(&u[second], &u[second]);
+                    let gamma = dot(&u[first], &u[second]);
+                    if gamma.abs() <= &K::epsilon() * &(&alpha * &beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let (cos, sin) = jacobi_rotation(&alpha, &beta, &gamma);
+                    rotate(&mut u, first, second, &cos, &sin);
+                    rotate(&mut v, first, second, &cos, &sin);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order: Vec<(K, usize)> = u
+            .iter()
+            .map(|column| dot(column, column).sqrt())
+            .zip(0..width)
+            .collect();
+        order.sort_by(|(lhs, _), (rhs, _)| {
+            rhs.partial_cmp(lhs).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let tolerance = order.first().map_or_else(K::default, |(largest, _)| {
+            default_tolerance(largest, height)
+        });
+
+        let mut u_columns: Vec<Vec<K>> = Vec::with_capacity(width);
+        for (singular_value, index) in &order {
+            let mut column = std::mem::take(&mut u[*index]);
+            if singular_value > &tolerance {
+                for elt in &mut column {
+                    *elt /= singular_value;
+                }
+            } else {
+                column = orthonormal_complement(&u_columns, height);
+            }
+            u_columns.push(column);
+        }
+
+        let mut u_content = vec![K::default(); height * width];
+        for (column, elts) in u_columns.into_iter().enumerate() {
+            for (line, elt) in elts.into_iter().enumerate() {
+                u_content[line * width + column] = elt;
+            }
+        }
+        let mut v_transpose_content = Vec::with_capacity(width * width);
+        let mut singular_values = Vec::with_capacity(width);
+        for (singular_value, index) in order {
+            v_transpose_content.append(&mut v[index]);
+            singular_values.push(singular_value);
+        }
+
+        SvdDecomposition {
+            u: Self {
+                content: u_content,
+                dimensions: Dimensions { width, height },
+            },
+            singular_values: Vector::from(singular_values),
+            v_transpose: Self {
+                content: v_transpose_content,
+                dimensions: Dimensions {
+                    width,
+                    height: width,
+                },
+            },
+        }
+    }
+}
+
+///
+/// Returns the `(cos, sin)` of the rotation that makes the columns of squared
+/// norms `alpha` and `beta`, and of dot product `gamma`, orthogonal.
+///
+/// Equivalently, this is the rotation that zeroes out `gamma` in the symmetric
+/// matrix `[[alpha, gamma], [gamma, beta]]`.
+///
+pub(super) fn jacobi_rotation<K>(alpha: &K, beta: &K, gamma: &K) -> (K, K)
+where
+    for<'a> K: Default + One + Sqrt + Abs + PartialOrd + Neg<Output = K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>,
+{
+    let one = K::one();
+    let zeta = &(beta - alpha) / &(gamma + gamma);
+    let denominator = &zeta.abs() + &(&one + &(&zeta * &zeta)).sqrt();
+    let tan = if zeta < K::default() {
+        -(&one / &denominator)
+    } else {
+        &one / &denominator
+    };
+    let cos = &one / &(&one + &(&tan * &tan)).sqrt();
+    let sin = &cos * &tan;
+    (cos, sin)
+}
+
+fn rotate<K>(columns: &mut [Vec<K>], first: usize, second: usize, cos: &K, sin: &K)
+where
+    for<'a> &'a K: Add<&'a K, Output = K> + Sub<&'a K, Output = K> + Mul<&'a K, Output = K>,
+{
+    let (left, right) = columns.split_at_mut(second);
+    for (lhs, rhs) in left[first].iter_mut().zip(right[0].iter_mut()) {
+        let new_lhs = &(cos * lhs) - &(sin * rhs);
+        let new_rhs = &(sin * lhs) + &(cos * rhs);
+        (*lhs, *rhs) = (new_lhs, new_rhs);
+    }
+}
+
+///
+/// Returns a unit vector orthogonal to all `columns`, which are expected to be
+/// orthonormal, and fewer than `size`.
+///
+fn orthonormal_complement<K>(columns: &[Vec<K>], size: usize) -> Vec<K>
+where
+    for<'a> K: Clone + Default + One + Sqrt + PartialOrd + AddAssign<&'a K> + DivAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K> + Sub<&'a K, Output = K> + Mul<&'a K, Output = K>,
+{
+    let mut fallback = Vec::new();
+    for axis in 0..size {
+        let mut candidate = vec![K::default(); size];
+        candidate[axis] = K::one();
+        for column in columns {
+            let projection = dot(column, &candidate);
+            for (elt, column_elt) in candidate.iter_mut().zip(column) {
+                *elt = &*elt - &(&projection * column_elt);
+            }
+        }
+        let squared_norm = dot(&candidate, &candidate);
+        // At least one axis keeps more than half of its squared length
+        if &squared_norm + &squared_norm > K::one() {
+            let norm = squared_norm.sqrt();
+            for elt in &mut candidate {
+                *elt /= &norm;
+            }
+            return candidate;
+        }
+        fallback = candidate;
+    }
+    fallback
+}
+
+///
+/// Returns `max(m, n) * ε * σ_max`.
+///
+fn default_tolerance<K>(largest_singular_value: &K, largest_dimension: usize) -> K
+where
+    for<'a> K: Default + Epsilon + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    let step = largest_singular_value * &K::epsilon();
+    let mut tolerance = K::default();
+    for _ in 0..largest_dimension {
+        tolerance += &step;
+    }
+    tolerance
+}
+
+impl<K: Clone> SvdDecomposition<K> {
+    ///
+    /// Returns the `m` * `k` matrix `U`, whose columns are orthonormal.
+    ///
+    pub const fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    ///
+    /// Returns the `k` singular values, in decreasing order.
+    ///
+    pub const fn singular_values(&self) -> &Vector<K> {
+        &self.singular_values
+    }
+
+    ///
+    /// Returns the `k` * `n` matrix `Vᵀ`, whose lines are orthonormal.
+    ///
+    pub const fn v_transpose(&self) -> &Matrix<K> {
+        &self.v_transpose
+    }
+}
+
+impl<K> SvdDecomposition<K>
+where
+    K: Clone + Default,
+{
+    ///
+    /// Returns the `k` * `k` diagonal matrix `Σ`.
+    ///
+    pub fn sigma(&self) -> Matrix<K> {
+        let size = self.singular_values.len();
+        let mut content = vec![K::default(); size * size];
+        for (index, value) in self.singular_values.iter().enumerate() {
+            content[index * size + index] = value.clone();
+        }
+        Matrix {
+            content,
+            dimensions: Dimensions {
+                width: size,
+                height: size,
+            },
+        }
+    }
+
+    ///
+    /// Returns the spectral norm of the decomposed matrix, which is its
+    /// largest singular value.
+    ///
+    #[must_use]
+    pub fn norm_2(&self) -> K {
+        self.singular_values.first().cloned().unwrap_or_default()
+    }
+}
+
+impl<K> SvdDecomposition<K>
+where
+    for<'a> K: Clone + Default + Epsilon + PartialOrd + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K>,
+{
+    ///
+    /// Returns the tolerance under which a singular value is considered to
+    /// be zero: `max(m, n) * ε * σ_max`.
+    ///
+    #[must_use]
+    pub fn default_tolerance(&self) -> K {
+        let largest_dimension = self
+            .u
+            .dimensions
+            .height
+            .max(self.v_transpose.dimensions.width);
+        default_tolerance(&self.norm_2(), largest_dimension)
+    }
+
+    ///
+    /// Returns the number of singular values above `tolerance`.
+    ///
+    #[must_use]
+    pub fn rank_with_tolerance(&self, tolerance: &K) -> usize {
+        self.singular_values
+            .iter()
+            .take_while(|value| *value > tolerance)
+            .count()
+    }
+
+    ///
+    /// Returns the numerical rank of the decomposed matrix, which is the number
+    /// of singular values above the [`default_tolerance`](SvdDecomposition#method.default_tolerance).
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1., 2.], [1. + 1e-17, 2.]]);
+    /// assert_eq!(mat.svd().rank(), 1);
+    /// ```
+    ///
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.rank_with_tolerance(&self.default_tolerance())
+    }
+
+    ///
+    /// Returns the condition number of the decomposed matrix in the 2-norm,
+    /// which is `σ_max / σ_min`.
+    ///
+    /// For a singular matrix of floating point numbers, this is infinite.
+    ///
+    #[must_use]
+    pub fn condition_number(&self) -> K {
+        match (self.singular_values.first(), self.singular_values.last()) {
+            (Some(largest), Some(smallest)) => largest / smallest,
+            _ => K::default(),
+        }
+    }
+
+    ///
+    /// Returns the Moore-Penrose pseudo-inverse `V * Σ⁺ * Uᵀ` of the decomposed
+    /// matrix, where `Σ⁺` inverts the singular values above `tolerance`, and
+    /// discards the others.
+    ///
+    /// # Complexity
+    /// For a `m` * `n` matrix, with `k = min(m, n)`:
+    /// Time: O(mnk)
+    /// Space: O(mn)
+    ///
+    pub fn pseudo_inverse(&self, tolerance: &K) -> Matrix<K> {
+        let (height, size) = self.u.size();
+        let width = self.v_transpose.dimensions.width;
+        let mut content = vec![K::default(); width * height];
+        for index in 0..self.rank_with_tolerance(tolerance) {
+            let singular_value = &self.singular_values[index];
+            for line in 0..width {
+                let factor = &self.v_transpose[index * width + line] / singular_value;
+                for column in 0..height {
+                    content[line * height + column] += &(&factor * &self.u[column * size + index]);
+                }
+            }
+        }
+        Matrix {
+            content,
+            dimensions: Dimensions {
+                width: height,
+                height: width,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{assert_approx, assert_eq_float, Matrix};
+    use pretty_assertions::assert_eq;
+
+    fn assert_decomposes(mat: &Matrix<f64>) {
+        let svd = mat.svd();
+        let k = mat.size().0.min(mat.size().1);
+        let identity = Matrix::identity(&1., k).unwrap();
+        assert_approx(&svd.u().transpose().mul_mat(svd.u()).unwrap(), &identity);
+        assert_approx(
+            &svd.v_transpose()
+                .mul_mat(&svd.v_transpose().transpose())
+                .unwrap(),
+            &identity,
+        );
+        let product = svd
+            .u()
+            .mul_mat(&svd.sigma())
+            .unwrap()
+            .mul_mat(svd.v_transpose())
+            .unwrap();
+        assert_approx(&product, mat);
+        assert!(svd
+            .singular_values()
+            .windows(2)
+            .all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn decomposition() {
+        assert_decomposes(&Matrix::from([[3., 0.], [4., 5.]]));
+        assert_decomposes(&Matrix::from([
+            [1., 2., 3.],
+            [4., 5., 6.],
+            [7., 8., 10.],
+            [-1., 0., 2.],
+        ]));
+        assert_decomposes(&Matrix::from([[1., 2., 3.], [4., 5., 6.]]));
+        assert_decomposes(&Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 1., 1.]]));
+        assert_decomposes(&Matrix::from([[0., 0.], [0., 0.], [0., 0.]]));
+    }
+
+    #[test]
+    fn singular_values() {
+        let svd = Matrix::from([[3., 0.], [4., 5.]]).svd();
+        assert_eq_float!(svd.singular_values()[0], 45_f64.sqrt());
+        assert_eq_float!(svd.singular_values()[1], 5_f64.sqrt());
+        assert_eq_float!(svd.norm_2(), 45_f64.sqrt());
+        assert_eq_float!(svd.condition_number(), 3.);
+    }
+
+    #[test]
+    fn rank() {
+        {
+            let svd = Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 1., 1.]]).svd();
+            assert_eq!(svd.rank(), 2);
+        }
+        {
+            let svd = Matrix::from([[0.1, 0.2], [0.3, 0.6], [0.7, 1.4]]).svd();
+            assert_eq!(svd.rank(), 1);
+            assert_eq!(svd.rank_with_tolerance(&2.), 0);
+        }
+        {
+            let svd = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]).svd();
+            assert_eq!(svd.rank(), 3);
+        }
+    }
+
+    #[test]
+    fn pseudo_inverse() {
+        {
+            let mat = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+            let svd = mat.svd();
+            let res = svd.pseudo_inverse(&svd.default_tolerance());
+            assert_approx(&res, &mat.inverse().unwrap());
+        }
+        {
+            let svd = Matrix::from([[1., 2.], [2., 4.]]).svd();
+            let res = svd.pseudo_inverse(&svd.default_tolerance());
+            assert_approx(&res, &Matrix::from([[0.04, 0.08], [0.08, 0.16]]));
+        }
+        {
+            let mat = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+            let svd = mat.svd();
+            let res = svd.pseudo_inverse(&svd.default_tolerance());
+            assert_eq!(res.size(), (2, 3));
+            assert_approx(
+                &res.mul_mat(&mat).unwrap(),
+                &Matrix::identity(&1., 2).unwrap(),
+            );
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//!
+//! Eigen decomposition of a real symmetric [`Matrix`], using the cyclic Jacobi
+//! eigenvalue algorithm.
+//!
+//! # Implementations details
+//!
+//! Each rotation `Jᵀ * A * J` zeroes out one off-diagonal pair of the matrix.
+//! The rotations are applied cyclically over all pairs until the off-diagonal
+//! part is negligible: the diagonal then holds the eigenvalues, and the product
+//! of the rotations holds the eigenvectors.
+//!
+
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+use crate::{
+    error::SymmetricEigenError,
+    traits::{Abs, Epsilon, One, Sqrt},
+    Matrix, Vector,
+};
+
+use super::svd::jacobi_rotation;
+
+/// Past this number of sweeps, the rotations are considered to have converged.
+const MAX_SWEEPS: usize = 64;
+
+///
+/// The result of the eigen decomposition of a symmetric [`Matrix`] `A`, such
+/// that `A = V * D * Vᵀ`.
+///
+/// See [`symmetric_eigen`](Matrix#method.symmetric_eigen).
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct SymmetricEigen<K: Clone> {
+    eigenvalues: Vector<K>,
+    eigenvectors: Matrix<K>,
+}
+
+impl<K> Matrix<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Sqrt
+        + Abs
+        + Epsilon
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>,
+{
+    ///
+    /// Returns the eigenvalues of a symmetric matrix, in increasing order, and
+    /// an orthonormal basis of the corresponding eigenvectors.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2., 0.], [0., 1.]]);
+    /// let eigen = mat.symmetric_eigen().unwrap();
+    /// assert_eq!(eigen.eigenvalues(), &[1., 2.]);
+    /// assert_eq!(eigen.eigenvectors(), &Matrix::from([[0., 1.], [1., 0.]]));
+    /// ```
+    ///
+    /// # Errors
+    /// If the matrix is not a square, returns a [`NotSquareMatrix`](SymmetricEigenError::NotSquareMatrix)
+    /// If the matrix is not equal to its transpose, returns a [`NotSymmetricMatrix`](SymmetricEigenError::NotSymmetricMatrix)
+    ///
+    /// # Complexity
+    /// For an `n` * `n` matrix, each sweep of rotations takes
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<K>, SymmetricEigenError> {
+        if !self.is_square() {
+            return Err(SymmetricEigenError::NotSquareMatrix);
+        }
+        let size = self.dimensions.height;
+        for line in 0..size {
+            for column in line + 1..size {
+                if self[line * size + column] != self[column * size + line] {
+                    return Err(SymmetricEigenError::NotSymmetricMatrix);
+                }
+            }
+        }
+
+        let mut diagonalized = self.clone();
+        let mut eigenvectors = Self {
+            content: vec![K::default(); size * size],
+            dimensions: self.dimensions,
+        };
+        for index in 0..size {
+            eigenvectors[index * size + index] = K::one();
+        }
+
+        let mut squared_norm = K::default();
+        for elt in self {
+            squared_norm += &(elt * elt);
+        }
+        let threshold = &K::epsilon() * &squared_norm.sqrt();
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for first in 0..size {
+                for second in first + 1..size {
+                    let off_diagonal = diagonalized[first * size + second].clone();
+                    if off_diagonal.abs() <= threshold {
+                        continue;
+                    }
+                    rotated = true;
+                    let (cos, sin) = jacobi_rotation(
+                        &diagonalized[first * size + first],
+                        &diagonalized[second * size + second],
+                        &off_diagonal,
+                    );
+                    diagonalized.rotate_columns(first, second, &cos, &sin);
+                    diagonalized.rotate_lines(first, second, &cos, &sin);
+                    diagonalized[first * size + second] = K::default();
+                    diagonalized[second * size + first] = K::default();
+                    eigenvectors.rotate_columns(first, second, &cos, &sin);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|&lhs, &rhs| {
+            diagonalized[lhs * size + lhs]
+                .partial_cmp(&diagonalized[rhs * size + rhs])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let eigenvalues = order
+            .iter()
+            .map(|&index| diagonalized[index * size + index].clone())
+            .collect::<Vec<_>>();
+        let mut content = Vec::with_capacity(size * size);
+        for line in 0..size {
+            for &index in &order {
+                content.push(eigenvectors[line * size + index].clone());
+            }
+        }
+
+        Ok(SymmetricEigen {
+            eigenvalues: Vector::from(eigenvalues),
+            eigenvectors: Self {
+                content,
+                dimensions: self.dimensions,
+            },
+        })
+    }
+
+    ///
+    /// Replaces the columns `p` and `q` by `c * p - s * q` and `s * p + c * q`.
+    ///
+    fn rotate_columns(&mut self, first: usize, second: usize, cos: &K, sin: &K) {
+        let width = self.dimensions.width;
+        for line in 0..self.dimensions.height {
+            let lhs = &self[line * width + first];
+            let rhs = &self[line * width + second];
+            let new_lhs = &(cos * lhs) - &(sin * rhs);
+            let new_rhs = &(sin * lhs) + &(cos * rhs);
+            self[line * width + first] = new_lhs;
+            self[line * width + second] = new_rhs;
+        }
+    }
+
+    ///
+    /// Replaces the lines `p` and `q` by `c * p - s * q` and `s * p + c * q`.
+    ///
+    fn rotate_lines(&mut self, first: usize, second: usize, cos: &K, sin: &K) {
+        let width = self.dimensions.width;
+        for column in 0..width {
+            let lhs = &self[first * width + column];
+            let rhs = &self[second * width + column];
+            let new_lhs = &(cos * lhs) - &(sin * rhs);
+            let new_rhs = &(sin * lhs) + &(cos * rhs);
+            self[first * width + column] = new_lhs;
+            self[second * width + column] = new_rhs;
+        }
+    }
+}
+
+impl<K: Clone> SymmetricEigen<K> {
+    ///
+    /// Returns the eigenvalues, in increasing order.
+    ///
+    pub const fn eigenvalues(&self) -> &Vector<K> {
+        &self.eigenvalues
+    }
+
+    ///
+    /// Returns the matrix whose `i`th column is the unit eigenvector of the
+    /// `i`th eigenvalue. Its columns are orthonormal.
+    ///
+    pub const fn eigenvectors(&self) -> &Matrix<K> {
+        &self.eigenvectors
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{assert_approx, assert_eq_float, error::SymmetricEigenError, Matrix, Vector};
+    use pretty_assertions::assert_eq;
+
+    fn assert_decomposes(mat: &Matrix<f64>) {
+        let eigen = mat.symmetric_eigen().unwrap();
+        let size = mat.size().0;
+        let vectors = eigen.eigenvectors();
+        assert_approx(
+            &vectors.transpose().mul_mat(vectors).unwrap(),
+            &Matrix::identity(&1., size).unwrap(),
+        );
+        for (index, value) in eigen.eigenvalues().iter().enumerate() {
+            let vector = Vector::from(
+                (0..size)
+                    .map(|line| *vectors.get(line, index).unwrap())
+                    .collect::<Vec<_>>(),
+            );
+            let product = mat.mul_vec(&vector).unwrap();
+            for (found, expected) in product.iter().zip(vector.iter()) {
+                assert_eq_float!(found, value * expected);
+            }
+        }
+        assert!(eigen
+            .eigenvalues()
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn eigenvalues() {
+        {
+            let eigen = Matrix::from([[2., 1.], [1., 2.]])
+                .symmetric_eigen()
+                .unwrap();
+            assert_eq_float!(eigen.eigenvalues()[0], 1.);
+            assert_eq_float!(eigen.eigenvalues()[1], 3.);
+        }
+        {
+            let mat = Matrix::from([[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+            let eigen = mat.symmetric_eigen().unwrap();
+            let sqrt_2 = 2_f64.sqrt();
+            for (found, expected) in eigen
+                .eigenvalues()
+                .iter()
+                .zip([2. - sqrt_2, 2., 2. + sqrt_2])
+            {
+                assert_eq_float!(found, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn eigenvectors() {
+        assert_decomposes(&Matrix::from([[2., 1.], [1., 2.]]));
+        assert_decomposes(&Matrix::from([
+            [4., 1., -2., 2.],
+            [1., 2., 0., 1.],
+            [-2., 0., 3., -2.],
+            [2., 1., -2., -1.],
+        ]));
+        // Repeated eigenvalues
+        assert_decomposes(&Matrix::from([[1., 1., 1.], [1., 1., 1.], [1., 1., 1.]]));
+        assert_decomposes(&Matrix::from([[0., 0.], [0., 0.]]));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Matrix::from([[1., 2., 3.], [2., 4., 6.]]).symmetric_eigen(),
+            Err(SymmetricEigenError::NotSquareMatrix)
+        );
+        assert_eq!(
+            Matrix::from([[1., 2.], [3., 4.]]).symmetric_eigen(),
+            Err(SymmetricEigenError::NotSymmetricMatrix)
+        );
+    }
+}
@@ -14,7 +14,7 @@ use self::{
     columns::{MatrixColumn, MatrixColumnMut},
     iterator::{MatrixIterByColumn, MatrixIterByColumnMut},
 };
-use super::Dimensions;
+use super::{Dimensions, MatrixAccess};
 
 pub mod columns;
 pub mod iterator;
@@ -402,65 +402,33 @@ impl<'a, K: Clone> Matrix<K> {
         columns: Range<usize>,
         lines: Range<usize>,
     ) -> Result<Self, SubmatrixError> {
-        if self.dimensions.height < lines.end
-            || self.dimensions.width < columns.end
-            || columns.start == columns.end
-            || lines.start == lines.end
-        {
+        if columns.is_empty() || lines.is_empty() {
             return Err(SubmatrixError::InvalidRanges);
         }
-        let column = (columns.start, columns.end);
-        let line = (lines.start, lines.end);
-        let mut content: Vec<K> =
-            Vec::with_capacity((columns.end - columns.start) * (lines.end - lines.start));
-        for line in self
-            .content
-            .chunks(self.dimensions.width)
-            .skip(line.0)
-            .take(line.1)
-        {
-            content.extend_from_slice(&line[columns.clone()]);
-        }
-        Ok(Self {
-            content,
-            dimensions: Dimensions {
-                width: column.1 - column.0,
-                height: line.1 - line.0,
-            },
-        })
+        self.view(lines, columns)
+            .map(|view| view.to_matrix())
+            .ok_or(SubmatrixError::InvalidRanges)
     }
 
     ///
     /// Returns a clone of the matrix, without all elements on the line `line`
     /// or on the column `column`.
     ///
+    /// [`MatrixView::without_line_column`](super::MatrixView::without_line_column)
+    /// gives the same elements without copying them.
+    ///
     /// # Errors
     /// If the matrix has only one line or one column, returns [`TooSmallMatrix`](WithoutLineColumnError::TooSmallMatrix)
+    /// If `line` or `column` is not in the matrix, returns an [`IndexOutOfBounds`](WithoutLineColumnError::IndexOutOfBounds)
     ///
     pub fn without_line_column(
         &self,
         column: usize,
         line: usize,
     ) -> Result<Self, WithoutLineColumnError> {
-        if self.dimensions.height < 2 || self.dimensions.width < 2 {
-            return Err(WithoutLineColumnError::TooSmallMatrix);
-        }
-        let content: Vec<K> = self
-            .content
-            .iter()
-            .enumerate()
-            .filter(|(index, _)| {
-                index % self.dimensions.width != column && index / self.dimensions.width != line
-            })
-            .map(|(_, elt)| elt.clone())
-            .collect();
-        Ok(Self {
-            content,
-            dimensions: Dimensions {
-                width: self.dimensions.width - 1,
-                height: self.dimensions.height - 1,
-            },
-        })
+        self.as_view()
+            .without_line_column(column, line)
+            .map(|minor| minor.to_matrix())
     }
 
     ///
@@ -873,7 +841,7 @@ impl<'a, K: Clone> Matrix<K> {
 
 #[cfg(test)]
 mod test {
-    use crate::Matrix;
+    use crate::{error::SubmatrixError, Matrix};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -939,5 +907,10 @@ mod test {
             let submat = matrix.submatrix(0..1, 0..3).unwrap();
             assert_eq!(submat, [[1], [4], [7]]);
         }
+        assert_eq!(matrix.submatrix(0..2, 1..2), Ok(Matrix::from([[4, 5]])));
+        assert_eq!(
+            matrix.submatrix(0..4, 1..2),
+            Err(SubmatrixError::InvalidRanges)
+        );
     }
 }
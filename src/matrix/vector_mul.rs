@@ -40,7 +40,7 @@ where
         if self.dimensions.width != vec.len() {
             return Err(MulVecError::SizeMismatch(self.dimensions.width, vec.len()));
         }
-        Ok(self.mul_vec_internal(vec.iter(), vec.len()))
+        Ok(self.mul_vec_internal(vec.iter()))
     }
 
     ///
@@ -68,16 +68,16 @@ where
     /// Space: O(m)
     ///
     pub unsafe fn mul_vec_unchecked(&self, vec: &Vector<K>) -> Vector<K> {
-        self.mul_vec_internal(vec.iter(), vec.size())
+        self.mul_vec_internal(vec.iter())
     }
 
     #[inline(always)]
-    pub(super) fn mul_vec_internal<'a, T>(&self, vec: T, size: usize) -> Vector<K>
+    pub(super) fn mul_vec_internal<'a, T>(&self, vec: T) -> Vector<K>
     where
         T: Iterator<Item = &'a K>,
         K: 'a,
     {
-        let mut result_vec: Vector<K> = Vector::fill(&K::default(), size);
+        let mut result_vec: Vector<K> = Vector::fill(&K::default(), self.dimensions.height);
         for (vector_index, vector_elt) in vec.enumerate() {
             let col = self.get_column(vector_index).unwrap();
             for (matrix_elt, result_vec) in col.zip(result_vec.iter_mut()) {
@@ -89,6 +89,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test {
     use crate::{complex::cpl, error::MulVecError, Matrix, Vector};
 
@@ -135,4 +136,20 @@ mod test {
         let res = u.mul_vec(&v).unwrap();
         assert_eq!(res, [cpl!(-15, -10), cpl!(-4, -29)]);
     }
+
+    #[test]
+    fn rectangular() {
+        {
+            let u = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+            let v = Vector::from([1., -1.]);
+            let res = u.mul_vec(&v).unwrap();
+            assert_eq!(res, [-1., -1., -1.]);
+        }
+        {
+            let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+            let v = Vector::from([1., 0., -1.]);
+            let res = u.mul_vec(&v).unwrap();
+            assert_eq!(res, [-2., -2.]);
+        }
+    }
 }
@@ -0,0 +1,914 @@
+//!
+//! Views on the elements of a [`Matrix`], without copying them.
+//!
+//! A [`MatrixView`] or a [`MatrixViewMut`] sees the element `(line, column)`
+//! at `line * line_stride + column * column_stride` from its first element.
+//! Changing the strides gives submatrices, transposes, or every `k`-th line
+//! or column, all for the price of a few integers.
+//!
+//! A [`MatrixView`] multiplies, and computes its trace, determinant, rank,
+//! inverse, LU decomposition and row echelon forms like a [`Matrix`]. Views
+//! are also [`MatrixAccess`] and [`LinearOperator`]s, which the iterative
+//! [`solvers`](crate::solvers) take. The other algorithms need a [`Matrix`],
+//! which [`to_matrix`](MatrixView::to_matrix) copies.
+//!
+//! # Implementations details
+//!
+//! Views hold a pointer rather than a slice: the elements of a submatrix are
+//! not contiguous, and the disjoint halves of a split mutable view are
+//! interleaved in memory, which two `&mut [K]` can't express. Every view
+//! is created from a [`Matrix`] whose `(line, column) -> offset` map is
+//! injective, and only shrinks or reorders it, so distinct positions always
+//! point to distinct elements.
+//!
+
+use std::{
+    marker::PhantomData,
+    ops::{AddAssign, Mul, Range},
+};
+
+use crate::{
+    error::WithoutLineColumnError, linear_operator::LinearOperator, solvers::LineEntries, Matrix,
+    Vector,
+};
+
+use super::{Dimensions, MatrixAccess};
+
+mod algorithms;
+
+///
+/// A read-only view on some elements of a [`Matrix`].
+///
+/// # Example
+/// ```
+/// use matrix::Matrix;
+///
+/// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+/// let view = mat.view(1..3, 0..2).unwrap();
+/// assert_eq!(view.to_matrix(), [[4, 5], [7, 8]]);
+/// assert_eq!(view.transpose().to_matrix(), [[4, 7], [5, 8]]);
+/// assert_eq!(mat.as_view().col(2).unwrap().to_matrix(), [[3], [6], [9]]);
+/// ```
+///
+#[derive(Debug)]
+#[must_use]
+pub struct MatrixView<'a, K> {
+    pointer: *const K,
+    dimensions: Dimensions,
+    line_stride: usize,
+    column_stride: usize,
+    marker: PhantomData<&'a K>,
+}
+
+///
+/// A view on some elements of a [`Matrix`], through which they can be
+/// modified.
+///
+/// # Example
+/// ```
+/// use matrix::Matrix;
+///
+/// let mut mat = Matrix::from([[1, 2], [3, 4]]);
+/// let (mut left, mut right) = mat.as_view_mut().split_at_column(1).unwrap();
+/// std::mem::swap(left.get_mut(0, 0).unwrap(), right.get_mut(1, 0).unwrap());
+/// assert_eq!(mat, [[4, 2], [3, 1]]);
+/// ```
+///
+#[derive(Debug)]
+#[must_use]
+pub struct MatrixViewMut<'a, K> {
+    pointer: *mut K,
+    dimensions: Dimensions,
+    line_stride: usize,
+    column_stride: usize,
+    marker: PhantomData<&'a mut K>,
+}
+
+///
+/// A [`MatrixView`] without one of its lines and one of its columns, as
+/// returned by [`MatrixView::without_line_column`].
+///
+#[derive(Debug)]
+#[must_use]
+pub struct WithoutLineColumn<'a, K> {
+    view: MatrixView<'a, K>,
+    line: usize,
+    column: usize,
+}
+
+impl<K> Clone for WithoutLineColumn<'_, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for WithoutLineColumn<'_, K> {}
+
+// SAFETY: a view behaves like a `&[K]`, and a mutable one like a `&mut [K]`
+unsafe impl<K: Sync> Send for MatrixView<'_, K> {}
+unsafe impl<K: Sync> Sync for MatrixView<'_, K> {}
+unsafe impl<K: Send> Send for MatrixViewMut<'_, K> {}
+unsafe impl<K: Sync> Sync for MatrixViewMut<'_, K> {}
+
+impl<K> Clone for MatrixView<'_, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for MatrixView<'_, K> {}
+
+///
+/// Returns the position of the first element of the `lines` * `columns`
+/// block of a `dimensions` view, or `None` if the block is not in the view.
+///
+fn block_offset(
+    dimensions: Dimensions,
+    (line_stride, column_stride): (usize, usize),
+    lines: &Range<usize>,
+    columns: &Range<usize>,
+) -> Option<usize> {
+    if lines.end.checked_sub(lines.start).is_none() || lines.end > dimensions.height {
+        return None;
+    }
+    if columns.end.checked_sub(columns.start).is_none() || columns.end > dimensions.width {
+        return None;
+    }
+    if lines.is_empty() || columns.is_empty() {
+        return Some(0);
+    }
+    Some(lines.start * line_stride + columns.start * column_stride)
+}
+
+impl<'a, K> MatrixView<'a, K> {
+    #[must_use]
+    pub const fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    ///
+    /// Returns the element at `(line, column)`, or `None` if it is out of the
+    /// view.
+    ///
+    #[must_use]
+    pub const fn get(&self, line: usize, column: usize) -> Option<&'a K> {
+        if line >= self.dimensions.height || column >= self.dimensions.width {
+            return None;
+        }
+        let offset = line * self.line_stride + column * self.column_stride;
+        // SAFETY: the position is in the view, so it points to an element of the matrix
+        Some(unsafe { &*self.pointer.add(offset) })
+    }
+
+    ///
+    /// Returns the view on the `lines` * `columns` block of this view, or
+    /// `None` if one of the ranges is out of bounds.
+    ///
+    /// # Complexity
+    /// Constant
+    ///
+    #[must_use]
+    pub fn view(&self, lines: Range<usize>, columns: Range<usize>) -> Option<Self> {
+        let offset = block_offset(
+            self.dimensions,
+            (self.line_stride, self.column_stride),
+            &lines,
+            &columns,
+        )?;
+        Some(Self {
+            pointer: self.pointer.wrapping_add(offset),
+            dimensions: Dimensions {
+                width: columns.len(),
+                height: lines.len(),
+            },
+            ..*self
+        })
+    }
+
+    ///
+    /// Returns the view on the line `line`, or `None` if it is out of bounds.
+    ///
+    #[must_use]
+    pub fn row(&self, line: usize) -> Option<Self> {
+        self.view(line..line.checked_add(1)?, 0..self.dimensions.width)
+    }
+
+    ///
+    /// Returns the view on the column `column`, or `None` if it is out of
+    /// bounds.
+    ///
+    #[must_use]
+    pub fn col(&self, column: usize) -> Option<Self> {
+        self.view(0..self.dimensions.height, column..column.checked_add(1)?)
+    }
+
+    ///
+    /// Returns the transposed view: its `(line, column)` element is the
+    /// `(column, line)` element of this one.
+    ///
+    /// # Complexity
+    /// Constant
+    ///
+    pub const fn transpose(&self) -> Self {
+        Self {
+            pointer: self.pointer,
+            dimensions: Dimensions {
+                width: self.dimensions.height,
+                height: self.dimensions.width,
+            },
+            line_stride: self.column_stride,
+            column_stride: self.line_stride,
+            marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns the view on every `line_step`-th line and every
+    /// `column_step`-th column, starting with the first ones, or `None` if
+    /// one of the steps is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let corners = mat.as_view().step_by(2, 2).unwrap();
+    /// assert_eq!(corners.to_matrix(), [[1, 3], [7, 9]]);
+    /// ```
+    ///
+    #[must_use]
+    pub const fn step_by(&self, line_step: usize, column_step: usize) -> Option<Self> {
+        if line_step == 0 || column_step == 0 {
+            return None;
+        }
+        let dimensions = Dimensions {
+            width: self.dimensions.width.div_ceil(column_step),
+            height: self.dimensions.height.div_ceil(line_step),
+        };
+        // A step past the last line or column only keeps the first one, whose
+        // stride is never used: this keeps the product from overflowing
+        Some(Self {
+            pointer: self.pointer,
+            dimensions,
+            line_stride: if dimensions.height > 1 {
+                self.line_stride * line_step
+            } else {
+                self.line_stride
+            },
+            column_stride: if dimensions.width > 1 {
+                self.column_stride * column_step
+            } else {
+                self.column_stride
+            },
+            marker: PhantomData,
+        })
+    }
+
+    ///
+    /// Returns an iterator over the elements of the view, line after line.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &'a K> {
+        let view = *self;
+        (0..self.dimensions.height).flat_map(move |line| {
+            (0..view.dimensions.width).filter_map(move |column| view.get(line, column))
+        })
+    }
+
+    ///
+    /// Returns the view without the line `line` and the column `column`.
+    ///
+    /// The remaining elements are not evenly spaced, so this is not a
+    /// [`MatrixView`], but a [`WithoutLineColumn`] that maps its positions to
+    /// the ones of this view.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{matrix::MatrixAccess, Matrix};
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let minor = mat.as_view().without_line_column(1, 0).unwrap();
+    /// assert_eq!(minor.to_matrix(), [[4, 6], [7, 9]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the view has only one line or one column, returns [`TooSmallMatrix`](WithoutLineColumnError::TooSmallMatrix)
+    /// If `line` or `column` is not in the view, returns an [`IndexOutOfBounds`](WithoutLineColumnError::IndexOutOfBounds)
+    ///
+    /// # Complexity
+    /// Constant
+    ///
+    pub const fn without_line_column(
+        &self,
+        column: usize,
+        line: usize,
+    ) -> Result<WithoutLineColumn<'a, K>, WithoutLineColumnError> {
+        if self.dimensions.height < 2 || self.dimensions.width < 2 {
+            return Err(WithoutLineColumnError::TooSmallMatrix);
+        }
+        if line >= self.dimensions.height || column >= self.dimensions.width {
+            return Err(WithoutLineColumnError::IndexOutOfBounds(line, column));
+        }
+        Ok(WithoutLineColumn {
+            view: *self,
+            line,
+            column,
+        })
+    }
+}
+
+impl<K: Clone> MatrixView<'_, K> {
+    ///
+    /// Returns a [`Matrix`] holding a copy of the elements of the view.
+    ///
+    /// # Complexity
+    /// Time: O(mn) for a `m` * `n` view.
+    /// Space: O(mn)
+    ///
+    pub fn to_matrix(&self) -> Matrix<K> {
+        MatrixAccess::to_matrix(self)
+    }
+}
+
+impl<K: Clone> From<MatrixView<'_, K>> for Matrix<K> {
+    fn from(value: MatrixView<'_, K>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<K> MatrixViewMut<'_, K> {
+    #[must_use]
+    pub const fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    ///
+    /// Returns a read-only view on the same elements.
+    ///
+    pub const fn as_view(&self) -> MatrixView<'_, K> {
+        MatrixView {
+            pointer: self.pointer,
+            dimensions: self.dimensions,
+            line_stride: self.line_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns a mutable view on the same elements, borrowing this one.
+    ///
+    pub const fn reborrow(&mut self) -> MatrixViewMut<'_, K> {
+        MatrixViewMut {
+            pointer: self.pointer,
+            dimensions: self.dimensions,
+            line_stride: self.line_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns the element at `(line, column)`, or `None` if it is out of the
+    /// view.
+    ///
+    #[must_use]
+    pub const fn get(&self, line: usize, column: usize) -> Option<&K> {
+        self.as_view().get(line, column)
+    }
+
+    ///
+    /// Returns a mutable reference to the element at `(line, column)`, or
+    /// `None` if it is out of the view.
+    ///
+    #[must_use]
+    pub fn get_mut(&mut self, line: usize, column: usize) -> Option<&mut K> {
+        if line >= self.dimensions.height || column >= self.dimensions.width {
+            return None;
+        }
+        let offset = line * self.line_stride + column * self.column_stride;
+        // SAFETY: the position is in the view, which borrows its elements mutably
+        Some(unsafe { &mut *self.pointer.add(offset) })
+    }
+
+    ///
+    /// Returns the mutable view on the `lines` * `columns` block of this
+    /// view, or `None` if one of the ranges is out of bounds.
+    ///
+    #[must_use]
+    pub fn into_view(self, lines: Range<usize>, columns: Range<usize>) -> Option<Self> {
+        let offset = block_offset(
+            self.dimensions,
+            (self.line_stride, self.column_stride),
+            &lines,
+            &columns,
+        )?;
+        Some(Self {
+            pointer: self.pointer.wrapping_add(offset),
+            dimensions: Dimensions {
+                width: columns.len(),
+                height: lines.len(),
+            },
+            ..self
+        })
+    }
+
+    ///
+    /// Returns the mutable view on the line `line`, or `None` if it is out of
+    /// bounds.
+    ///
+    #[must_use]
+    pub fn row(self, line: usize) -> Option<Self> {
+        let width = self.dimensions.width;
+        self.into_view(line..line.checked_add(1)?, 0..width)
+    }
+
+    ///
+    /// Returns the mutable view on the column `column`, or `None` if it is
+    /// out of bounds.
+    ///
+    #[must_use]
+    pub fn col(self, column: usize) -> Option<Self> {
+        let height = self.dimensions.height;
+        self.into_view(0..height, column..column.checked_add(1)?)
+    }
+
+    ///
+    /// Returns the transposed mutable view.
+    ///
+    pub const fn transpose(self) -> Self {
+        Self {
+            pointer: self.pointer,
+            dimensions: Dimensions {
+                width: self.dimensions.height,
+                height: self.dimensions.width,
+            },
+            line_stride: self.column_stride,
+            column_stride: self.line_stride,
+            marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Splits the view into the lines before `line`, and the ones from `line`
+    /// on, or returns `None` if `line` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    /// let (mut top, mut bottom) = mat.as_view_mut().split_at_line(1).unwrap();
+    /// for (top, bottom) in top.iter_mut().zip(bottom.iter_mut()) {
+    ///     *bottom -= *top;
+    /// }
+    /// assert_eq!(mat, [[1, 2], [2, 2], [5, 6]]);
+    /// ```
+    ///
+    #[must_use]
+    pub fn split_at_line(self, line: usize) -> Option<(Self, Self)> {
+        let Dimensions { width, height } = self.dimensions;
+        if line > height {
+            return None;
+        }
+        let offset = block_offset(
+            self.dimensions,
+            (self.line_stride, self.column_stride),
+            &(line..height),
+            &(0..width),
+        )?;
+        let first = Self {
+            dimensions: Dimensions {
+                width,
+                height: line,
+            },
+            ..self
+        };
+        let second = Self {
+            pointer: self.pointer.wrapping_add(offset),
+            dimensions: Dimensions {
+                width,
+                height: height - line,
+            },
+            ..self
+        };
+        Some((first, second))
+    }
+
+    ///
+    /// Splits the view into the columns before `column`, and the ones from
+    /// `column` on, or returns `None` if `column` is out of bounds.
+    ///
+    #[must_use]
+    pub fn split_at_column(self, column: usize) -> Option<(Self, Self)> {
+        let (left, right) = self.transpose().split_at_line(column)?;
+        Some((left.transpose(), right.transpose()))
+    }
+
+    ///
+    /// Returns an iterator over the elements of the view, line after line.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &K> {
+        self.as_view().iter()
+    }
+
+    ///
+    /// Returns an iterator over mutable references to the elements of the
+    /// view, line after line.
+    ///
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut K> {
+        let Dimensions { width, height } = self.dimensions;
+        let (pointer, line_stride, column_stride) =
+            (self.pointer, self.line_stride, self.column_stride);
+        (0..height).flat_map(move |line| {
+            (0..width).map(move |column| {
+                let offset = line * line_stride + column * column_stride;
+                // SAFETY: each position of the view is yielded once, and
+                // points to a distinct element borrowed by the view
+                unsafe { &mut *pointer.add(offset) }
+            })
+        })
+    }
+}
+
+impl<K: Clone> MatrixViewMut<'_, K> {
+    ///
+    /// Returns a [`Matrix`] holding a copy of the elements of the view.
+    ///
+    pub fn to_matrix(&self) -> Matrix<K> {
+        self.as_view().to_matrix()
+    }
+
+    ///
+    /// Overwrites the elements of the view with those of `source`, or returns
+    /// `None` if their dimensions differ.
+    ///
+    #[must_use]
+    pub fn assign(&mut self, source: &MatrixView<'_, K>) -> Option<()> {
+        if self.dimensions != source.dimensions {
+            return None;
+        }
+        for (elt, value) in self.iter_mut().zip(source.iter()) {
+            elt.clone_from(value);
+        }
+        Some(())
+    }
+}
+
+impl<K> Matrix<K> {
+    ///
+    /// Returns a view on all the elements of the matrix.
+    ///
+    pub const fn as_view(&self) -> MatrixView<'_, K> {
+        MatrixView {
+            pointer: self.content.as_ptr(),
+            dimensions: self.dimensions,
+            line_stride: self.dimensions.width,
+            column_stride: 1,
+            marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns a mutable view on all the elements of the matrix.
+    ///
+    pub const fn as_view_mut(&mut self) -> MatrixViewMut<'_, K> {
+        MatrixViewMut {
+            pointer: self.content.as_mut_ptr(),
+            dimensions: self.dimensions,
+            line_stride: self.dimensions.width,
+            column_stride: 1,
+            marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns the view on the `lines` * `columns` block of the matrix, or
+    /// `None` if one of the ranges is out of bounds.
+    ///
+    /// # Complexity
+    /// Constant
+    ///
+    #[must_use]
+    pub fn view(&self, lines: Range<usize>, columns: Range<usize>) -> Option<MatrixView<'_, K>> {
+        self.as_view().view(lines, columns)
+    }
+
+    ///
+    /// Returns the mutable view on the `lines` * `columns` block of the
+    /// matrix, or `None` if one of the ranges is out of bounds.
+    ///
+    #[must_use]
+    pub fn view_mut(
+        &mut self,
+        lines: Range<usize>,
+        columns: Range<usize>,
+    ) -> Option<MatrixViewMut<'_, K>> {
+        self.as_view_mut().into_view(lines, columns)
+    }
+}
+
+impl<K> MatrixAccess<K> for MatrixView<'_, K> {
+    fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn get(&self, line: usize, column: usize) -> Option<&K> {
+        Self::get(self, line, column)
+    }
+}
+
+impl<K> MatrixAccess<K> for MatrixViewMut<'_, K> {
+    fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn get(&self, line: usize, column: usize) -> Option<&K> {
+        Self::get(self, line, column)
+    }
+}
+
+impl<K> MatrixAccess<K> for WithoutLineColumn<'_, K> {
+    fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            width: self.view.dimensions.width - 1,
+            height: self.view.dimensions.height - 1,
+        }
+    }
+
+    fn get(&self, line: usize, column: usize) -> Option<&K> {
+        let dimensions = MatrixAccess::dimensions(self);
+        if line >= dimensions.height || column >= dimensions.width {
+            return None;
+        }
+        let line = line + usize::from(line >= self.line);
+        let column = column + usize::from(column >= self.column);
+        self.view.get(line, column)
+    }
+}
+
+impl<K> LinearOperator<K> for MatrixView<'_, K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        let result: Vec<K> = (0..self.dimensions.height)
+            .map(|line| {
+                let mut sum = K::default();
+                for (column, value) in self.line_entries(line) {
+                    sum += &(value * &vec[column]);
+                }
+                sum
+            })
+            .collect();
+        Vector::from(result)
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        self.transpose().apply(vec)
+    }
+}
+
+impl<K> LinearOperator<K> for MatrixViewMut<'_, K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn apply(&self, vec: &Vector<K>) -> Vector<K> {
+        self.as_view().apply(vec)
+    }
+
+    fn apply_transpose(&self, vec: &Vector<K>) -> Vector<K> {
+        self.as_view().apply_transpose(vec)
+    }
+}
+
+impl<K> LineEntries<K> for MatrixView<'_, K> {
+    fn line_entries<'a>(&'a self, line: usize) -> impl Iterator<Item = (usize, &'a K)>
+    where
+        K: 'a,
+    {
+        (0..self.dimensions.width)
+            .filter_map(move |column| self.get(line, column).map(|value| (column, value)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        error::{MulMatError, TraceError, WithoutLineColumnError},
+        linear_operator::LinearOperator,
+        matrix::{Dimensions, MatrixAccess},
+        solvers::{conjugate_gradient, gauss_seidel},
+        Matrix, Vector,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn views() {
+        let mat = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let view = mat.view(1..3, 1..4).unwrap();
+        assert_eq!(
+            view.dimensions(),
+            Dimensions {
+                width: 3,
+                height: 2
+            }
+        );
+        assert_eq!(view.get(1, 2), Some(&12));
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.to_matrix(), [[6, 7, 8], [10, 11, 12]]);
+        assert_eq!(
+            view.row(1).map(|row| row.to_matrix()),
+            Some(Matrix::from([[10, 11, 12]]))
+        );
+        assert_eq!(
+            view.col(0)
+                .map(|col| col.iter().copied().collect::<Vec<_>>()),
+            Some(vec![6, 10])
+        );
+        assert!(view.row(2).is_none());
+        assert!(view.row(usize::MAX).is_none());
+        assert!(view.col(usize::MAX).is_none());
+
+        // Views of views
+        let transposed = view.transpose();
+        assert_eq!(transposed.to_matrix(), [[6, 10], [7, 11], [8, 12]]);
+        assert_eq!(
+            transposed.view(1..3, 1..2).map(|view| view.to_matrix()),
+            Some(Matrix::from([[11], [12]]))
+        );
+        let stepped = mat.as_view().step_by(2, 3).unwrap();
+        assert_eq!(stepped.to_matrix(), [[1, 4], [9, 12]]);
+        assert_eq!(stepped.transpose().to_matrix(), [[1, 9], [4, 12]]);
+        assert!(mat.as_view().step_by(0, 1).is_none());
+        assert_eq!(
+            mat.as_view().step_by(usize::MAX, 1).unwrap().to_matrix(),
+            [[1, 2, 3, 4]]
+        );
+        assert_eq!(
+            mat.as_view().step_by(2, usize::MAX).unwrap().to_matrix(),
+            [[1], [9]]
+        );
+
+        // Empty and invalid ranges
+        assert_eq!(
+            mat.view(3..3, 0..4).map(|view| view.dimensions()),
+            Some(Dimensions {
+                width: 4,
+                height: 0
+            })
+        );
+        assert!(mat.view(0..4, 0..1).is_none());
+        assert!(mat.view(0..1, 2..5).is_none());
+    }
+
+    #[test]
+    fn mutable_views() {
+        let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let (top, bottom) = mat.as_view_mut().split_at_line(1).unwrap();
+        let (mut bottom_left, mut bottom_right) = bottom.split_at_column(2).unwrap();
+        for elt in bottom_left.iter_mut() {
+            *elt *= 10;
+        }
+        for elt in bottom_right.iter_mut() {
+            *elt = -*elt;
+        }
+        assert_eq!(top.to_matrix(), [[1, 2, 3]]);
+        assert_eq!(mat, [[1, 2, 3], [40, 50, -6], [70, 80, -9]]);
+
+        // Write a transposed block in place
+        let copy = mat.clone();
+        let mut block = mat.view_mut(0..2, 0..2).unwrap();
+        assert_eq!(
+            block.assign(&copy.view(0..2, 0..2).unwrap().transpose()),
+            Some(())
+        );
+        assert_eq!(mat, [[1, 40, 3], [2, 50, -6], [70, 80, -9]]);
+
+        let mut column = mat.as_view_mut().col(2).unwrap().transpose();
+        if let Some(elt) = column.get_mut(0, 2) {
+            *elt = 0;
+        }
+        assert_eq!(column.to_matrix(), [[3, -6, 0]]);
+        assert!(mat.as_view_mut().split_at_line(4).is_none());
+        assert!(mat.as_view_mut().row(usize::MAX).is_none());
+        assert!(mat.as_view_mut().col(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn algorithms() {
+        // The top left block is symmetric positive definite
+        let mat = Matrix::from([[4_f64, 1., 9.], [1., 3., 9.], [9., 9., 9.]]);
+        let block = mat.view(0..2, 0..2).unwrap();
+        let rhs = Vector::from([1., 2.]);
+        let report = conjugate_gradient(&block, &rhs, &1e-12, 10).unwrap();
+        assert!((report.solution()[0] - 1. / 11.).abs() < 1e-12);
+        let report = gauss_seidel(&block.transpose(), &rhs, &1e-12, 100).unwrap();
+        assert!((report.solution()[1] - 7. / 11.).abs() < 1e-10);
+
+        let view = mat.view(1..3, 0..3).unwrap();
+        assert_eq!(view.apply(&Vector::from([1., 1., 1.])), [13., 27.]);
+        assert_eq!(
+            view.apply_transpose(&Vector::from([1., 1.])),
+            [10., 12., 18.]
+        );
+        assert_eq!(Matrix::from(view), [[1., 3., 9.], [9., 9., 9.]]);
+    }
+
+    #[test]
+    fn dense_algorithms() {
+        let mat = Matrix::from([[2., 1., 0., 5.], [1., 3., 1., 5.], [0., 1., 4., 5.]]);
+        let block = mat.view(0..3, 0..3).unwrap();
+        let copy = block.to_matrix();
+        let vec = Vector::from([1., -1., 2.]);
+        assert_eq!(block.mul_vec(&vec), copy.mul_vec(&vec));
+        assert_eq!(
+            block.mul_mat(&block.transpose()),
+            copy.mul_mat(&copy.transpose())
+        );
+        assert_eq!(block.mul_mat(&mat), copy.mul_mat(&mat));
+        assert_eq!(
+            block.mul_mat(&block.row(0).unwrap()),
+            Err(MulMatError::SizeMismatch(3, 1))
+        );
+        assert_eq!(block.trace(), Ok(9.));
+        assert_eq!(mat.as_view().trace(), Err(TraceError::NotSquareMatrix));
+        assert_eq!(block.determinant(), copy.determinant());
+        assert_eq!(block.rank(), 3);
+        assert_eq!(block.inverse(), copy.inverse());
+        assert_eq!(block.reduced_row_echelon(), copy.reduced_row_echelon());
+        assert_eq!(
+            block.lu().map(|lu| lu.determinant()),
+            copy.lu().map(|lu| lu.determinant())
+        );
+
+        // Bigger than the closed forms
+        let mat = Matrix::from([
+            [2, 1, 0, 0, 0, 7],
+            [1, 2, 1, 0, 0, 7],
+            [0, 1, 2, 1, 0, 7],
+            [0, 0, 1, 2, 1, 7],
+            [0, 0, 0, 1, 2, 7],
+        ]);
+        let block = mat.view(0..5, 0..5).unwrap();
+        assert_eq!(block.determinant(), Ok(6));
+    }
+
+    #[test]
+    fn without_line_column() {
+        let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let minor = mat.as_view().without_line_column(1, 2).unwrap();
+        assert_eq!(
+            minor.dimensions(),
+            Dimensions {
+                width: 2,
+                height: 2
+            }
+        );
+        assert_eq!(minor.get(1, 1), Some(&6));
+        assert_eq!(minor.get(2, 0), None);
+        assert_eq!(minor.to_matrix(), [[1, 3], [4, 6]]);
+        assert_eq!(mat.without_line_column(1, 2), Ok(minor.to_matrix()));
+
+        let view = mat.view(0..1, 0..3).unwrap();
+        assert_eq!(
+            view.without_line_column(0, 0)
+                .map(|minor| minor.to_matrix()),
+            Err(WithoutLineColumnError::TooSmallMatrix)
+        );
+        assert_eq!(
+            mat.without_line_column(3, 0),
+            Err(WithoutLineColumnError::IndexOutOfBounds(0, 3))
+        );
+    }
+
+    #[test]
+    fn not_clone() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Token(u8);
+
+        let mut mat = Matrix {
+            content: vec![Token(1), Token(2), Token(3), Token(4)],
+            dimensions: Dimensions {
+                width: 2,
+                height: 2,
+            },
+        };
+        assert_eq!(mat.as_view().transpose().get(0, 1), Some(&Token(3)));
+        let mut line = mat.view_mut(1..2, 0..2).unwrap();
+        if let Some(token) = line.get_mut(0, 1) {
+            token.0 = 0;
+        }
+        assert_eq!(MatrixAccess::get(&mat, 1, 1), Some(&Token(0)));
+    }
+}
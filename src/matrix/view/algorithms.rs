@@ -0,0 +1,216 @@
+//!
+//! The algorithms of [`Matrix`] on a [`MatrixView`].
+//!
+//! # Implementations details
+//!
+//! The products, the trace and the determinants of up to `4×4` read the
+//! elements in place, through [`MatrixAccess`]. The eliminations work on a
+//! copy of their input, even for a [`Matrix`]: they take it from the view.
+//!
+
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    error::{
+        DeterminantError, InverseError, LuDecompositionError, MulMatError, MulVecError, TraceError,
+    },
+    linear_operator::LinearOperator,
+    matrix::{determinant::closed_form_determinant, Dimensions, LuDecomposition, MatrixAccess},
+    traits::{Abs, IsField, IsZero, One},
+    Matrix, Vector,
+};
+
+use super::MatrixView;
+
+impl<K> MatrixView<'_, K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    ///
+    /// Returns the product of the view by `vec`.
+    ///
+    /// # Errors
+    /// If the width of the view is not the size of `vec`, returns a [`SizeMismatch`](MulVecError::SizeMismatch)
+    ///
+    /// # Complexity
+    /// For a `m` * `n` view:
+    /// Time: O(mn)
+    /// Space: O(m)
+    ///
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Result<Vector<K>, MulVecError> {
+        if self.dimensions.width != vec.len() {
+            return Err(MulVecError::SizeMismatch(self.dimensions.width, vec.len()));
+        }
+        Ok(self.apply(vec))
+    }
+
+    ///
+    /// Returns the product of the view by `rhs`, which can be a [`Matrix`] or
+    /// another view.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// let block = mat.view(0..2, 1..3).unwrap();
+    /// assert_eq!(block.mul_mat(&block.transpose()).unwrap(), [[13, 28], [28, 61]]);
+    /// ```
+    ///
+    /// # Errors
+    /// If the width of the view is not the height of `rhs`, returns a [`SizeMismatch`](MulMatError::SizeMismatch)
+    ///
+    /// # Complexity
+    /// For a `m` * `n` view and a `n` * `p` matrix:
+    /// Time: O(mnp)
+    /// Space: O(mp)
+    ///
+    pub fn mul_mat(&self, rhs: &impl MatrixAccess<K>) -> Result<Matrix<K>, MulMatError> {
+        let rhs_dimensions = rhs.dimensions();
+        if self.dimensions.width != rhs_dimensions.height {
+            return Err(MulMatError::SizeMismatch(
+                self.dimensions.width,
+                rhs_dimensions.height,
+            ));
+        }
+        let mut content = Vec::with_capacity(self.dimensions.height * rhs_dimensions.width);
+        for line in 0..self.dimensions.height {
+            for column in 0..rhs_dimensions.width {
+                let mut sum = K::default();
+                for index in 0..self.dimensions.width {
+                    if let (Some(lhs), Some(rhs)) = (self.get(line, index), rhs.get(index, column))
+                    {
+                        sum += &(lhs * rhs);
+                    }
+                }
+                content.push(sum);
+            }
+        }
+        Ok(Matrix {
+            content,
+            dimensions: Dimensions {
+                width: rhs_dimensions.width,
+                height: self.dimensions.height,
+            },
+        })
+    }
+
+    ///
+    /// Returns the trace of a square view: the sum of its diagonal.
+    ///
+    /// # Errors
+    /// If the view is not a square, returns a [`NotSquareMatrix`](TraceError::NotSquareMatrix)
+    ///
+    /// # Complexity
+    /// Time: O(n)
+    /// Space: O(1)
+    ///
+    pub fn trace(&self) -> Result<K, TraceError> {
+        if self.dimensions.width != self.dimensions.height {
+            return Err(TraceError::NotSquareMatrix);
+        }
+        let mut sum = K::default();
+        for index in 0..self.dimensions.width {
+            if let Some(elt) = self.get(index, index) {
+                sum += elt;
+            }
+        }
+        Ok(sum)
+    }
+}
+
+impl<K> MatrixView<'_, K>
+where
+    K: Clone + Default + Neg<Output = K> + One + IsField,
+    for<'b> &'b K: Sub<&'b K, Output = K> + Mul<&'b K, Output = K> + Add<&'b K, Output = K>,
+    for<'b> K: Display
+        + Clone
+        + Default
+        + MulAssign<&'b K>
+        + SubAssign<&'b K>
+        + AddAssign<&'b K>
+        + DivAssign<&'b K>,
+    for<'b> &'b K: PartialEq + Mul<&'b K, Output = K> + Div<&'b K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the determinant of a square view.
+    ///
+    /// See [`determinant`](Matrix#method.determinant): up to `4×4`, it reads
+    /// the elements in place.
+    ///
+    /// # Errors
+    /// If the view is not a square, returns a [`NotSquareMatrix`](DeterminantError::NotSquareMatrix)
+    ///
+    pub fn determinant(&self) -> Result<K, DeterminantError> {
+        closed_form_determinant(self).map_or_else(|| self.to_matrix().determinant(), Ok)
+    }
+}
+
+impl<K> MatrixView<'_, K>
+where
+    for<'a> K: Clone + Default + One + Abs + PartialOrd + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the LU decomposition of a square view.
+    ///
+    /// See [`lu`](Matrix#method.lu).
+    ///
+    /// # Errors
+    /// If the view is not a square, returns a [`NotSquareMatrix`](LuDecompositionError::NotSquareMatrix)
+    ///
+    pub fn lu(&self) -> Result<LuDecomposition<K>, LuDecompositionError> {
+        self.to_matrix().lu()
+    }
+}
+
+impl<K> MatrixView<'_, K>
+where
+    for<'a> K: Clone + One + Default + MulAssign<&'a K> + SubAssign<&'a K> + DivAssign<&'a K>,
+    for<'a> &'a K: PartialEq + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the rank of the view.
+    ///
+    /// See [`rank`](Matrix#method.rank).
+    ///
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.to_matrix().rank()
+    }
+
+    ///
+    /// Returns the inverse of a square view.
+    ///
+    /// See [`inverse`](Matrix#method.inverse).
+    ///
+    /// # Errors
+    /// If the view is not a square, returns a [`NotSquareMatrix`](InverseError::NotSquareMatrix)
+    /// If the view is singular, returns a [`SingularMatrix`](InverseError::SingularMatrix)
+    ///
+    pub fn inverse(&self) -> Result<Matrix<K>, InverseError> {
+        self.to_matrix().inverse()
+    }
+
+    ///
+    /// Returns the row echelon form of the view.
+    ///
+    /// See [`row_echelon`](Matrix#method.row_echelon).
+    ///
+    pub fn row_echelon(&self) -> Matrix<K> {
+        self.to_matrix().row_echelon()
+    }
+
+    ///
+    /// Returns the reduced row echelon form of the view.
+    ///
+    /// See [`reduced_row_echelon`](Matrix#method.reduced_row_echelon).
+    ///
+    pub fn reduced_row_echelon(&self) -> Matrix<K> {
+        self.to_matrix().reduced_row_echelon()
+    }
+}
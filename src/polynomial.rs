@@ -0,0 +1,15 @@
+///
+/// A polynomial in one variable, with coefficients in `K`.
+///
+/// The coefficients are stored from the constant term up, and the leading one
+/// is never zero: the zero polynomial has no coefficient at all. This makes
+/// two equal polynomials structurally equal.
+///
+#[derive(Clone, PartialEq, Eq, Debug, Default, Hash)]
+pub struct Polynomial<K> {
+    coefficients: Vec<K>,
+}
+
+mod operations;
+mod roots;
+mod utils;
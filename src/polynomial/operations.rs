@@ -0,0 +1,188 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::traits::IsZero;
+
+use super::Polynomial;
+
+impl<K> Polynomial<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K> + SubAssign<&'a K> + MulAssign<&'a K>,
+    for<'a> &'a K: IsZero,
+{
+    #[inline(always)]
+    fn default_add(&self, rhs: &Self) -> Self {
+        let mut coefficients = self.coefficients.clone();
+        if coefficients.len() < rhs.coefficients.len() {
+            coefficients.resize(rhs.coefficients.len(), K::default());
+        }
+        for (lhs, rhs) in coefficients.iter_mut().zip(&rhs.coefficients) {
+            *lhs += rhs;
+        }
+        Self::new(coefficients)
+    }
+
+    #[inline(always)]
+    fn default_sub(&self, rhs: &Self) -> Self {
+        let mut coefficients = self.coefficients.clone();
+        if coefficients.len() < rhs.coefficients.len() {
+            coefficients.resize(rhs.coefficients.len(), K::default());
+        }
+        for (lhs, rhs) in coefficients.iter_mut().zip(&rhs.coefficients) {
+            *lhs -= rhs;
+        }
+        Self::new(coefficients)
+    }
+
+    ///
+    /// Schoolbook product, in O(n * m).
+    ///
+    #[inline(always)]
+    fn default_mul(&self, rhs: &Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::default();
+        }
+        let mut coefficients =
+            vec![K::default(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (lhs_power, lhs) in self.coefficients.iter().enumerate() {
+            for (rhs_power, rhs) in rhs.coefficients.iter().enumerate() {
+                let mut product = lhs.clone();
+                product *= rhs;
+                coefficients[lhs_power + rhs_power] += &product;
+            }
+        }
+        // Two non-zero leading coefficients may multiply to zero in a ring
+        Self::new(coefficients)
+    }
+}
+
+macro_rules! impl_operation {
+    ($trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident, $internal: ident) => {
+        impl<K> $assign_trait<&Self> for Polynomial<K>
+        where
+            for<'a> K: Clone + Default + AddAssign<&'a K> + SubAssign<&'a K> + MulAssign<&'a K>,
+            for<'a> &'a K: IsZero,
+        {
+            fn $assign_method(&mut self, rhs: &Self) {
+                *self = self.$internal(rhs);
+            }
+        }
+
+        impl<K> $assign_trait<Self> for Polynomial<K>
+        where
+            for<'a> K: Clone + Default + AddAssign<&'a K> + SubAssign<&'a K> + MulAssign<&'a K>,
+            for<'a> &'a K: IsZero,
+        {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.$internal(&rhs);
+            }
+        }
+
+        impl<K> $trait<Self> for &Polynomial<K>
+        where
+            for<'a> K: Clone + Default + AddAssign<&'a K> + SubAssign<&'a K> + MulAssign<&'a K>,
+            for<'a> &'a K: IsZero,
+        {
+            type Output = Polynomial<K>;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$internal(rhs)
+            }
+        }
+
+        impl<K> $trait<Polynomial<K>> for &Polynomial<K>
+        where
+            for<'a> K: Clone + Default + AddAssign<&'a K> + SubAssign<&'a K> + MulAssign<&'a K>,
+            for<'a> &'a K: IsZero,
+        {
+            type Output = Polynomial<K>;
+            fn $method(self, rhs: Polynomial<K>) -> Self::Output {
+                self.$internal(&rhs)
+            }
+        }
+
+        impl<K> $trait<&Self> for Polynomial<K>
+        where
+            for<'a> K: Clone + Default + AddAssign<&'a K> + SubAssign<&'a K> + MulAssign<&'a K>,
+            for<'a> &'a K: IsZero,
+        {
+            type Output = Self;
+            fn $method(self, rhs: &Self) -> Self::Output {
+                self.$internal(rhs)
+            }
+        }
+
+        impl<K> $trait<Self> for Polynomial<K>
+        where
+            for<'a> K: Clone + Default + AddAssign<&'a K> + SubAssign<&'a K> + MulAssign<&'a K>,
+            for<'a> &'a K: IsZero,
+        {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$internal(&rhs)
+            }
+        }
+    };
+}
+
+impl_operation!(Add, add, AddAssign, add_assign, default_add);
+impl_operation!(Sub, sub, SubAssign, sub_assign, default_sub);
+impl_operation!(Mul, mul, MulAssign, mul_assign, default_mul);
+
+impl<K> Neg for Polynomial<K>
+where
+    for<'a> &'a K: Neg<Output = K>,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<K> Neg for &Polynomial<K>
+where
+    for<'a> &'a K: Neg<Output = K>,
+{
+    type Output = Polynomial<K>;
+    fn neg(self) -> Self::Output {
+        Polynomial {
+            coefficients: self.coefficients.iter().map(|elt| -elt).collect(),
+        }
+    }
+}
+
+#[allow(clippy::op_ref)]
+#[cfg(test)]
+mod test {
+    use crate::{polynomial::Polynomial, Gf};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn arithmetic() {
+        let (lhs, rhs) = (Polynomial::from([1, 2, 3]), Polynomial::from([-1, 1]));
+        assert_eq!(&lhs + &rhs, Polynomial::from([0, 3, 3]));
+        assert_eq!(&rhs + &lhs, Polynomial::from([0, 3, 3]));
+        assert_eq!(lhs.clone() - &rhs, Polynomial::from([2, 1, 3]));
+        assert_eq!(&lhs * rhs.clone(), Polynomial::from([-1, -1, -1, 3]));
+        assert_eq!(-&rhs, Polynomial::from([1, -1]));
+        assert_eq!(&lhs - &lhs, Polynomial::default());
+        assert_eq!(&lhs * &Polynomial::default(), Polynomial::default());
+
+        let mut poly = Polynomial::from([1., 1.]);
+        poly *= poly.clone();
+        assert_eq!(poly, Polynomial::from([1., 2., 1.]));
+        poly -= Polynomial::from([0., 0., 1.]);
+        assert_eq!(poly.degree(), Some(1));
+        poly += &Polynomial::from([-1.]);
+        assert_eq!(poly, Polynomial::from([0., 2.]));
+    }
+
+    #[test]
+    fn in_finite_field() {
+        // (x + 1)^2 = x^2 + 1 in GF(2)
+        let poly = Polynomial::from([Gf::<2>::new(1), Gf::new(1)]);
+        assert_eq!(
+            &poly * &poly,
+            Polynomial::from([Gf::new(1), Gf::new(0), Gf::new(1)])
+        );
+        assert_eq!(&poly + &poly, Polynomial::default());
+    }
+}
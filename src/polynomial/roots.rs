@@ -0,0 +1,143 @@
+//!
+//! Roots of a polynomial with real coefficients, as the eigenvalues of its
+//! companion matrix.
+//!
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{
+    error::{EigenvaluesError, RootsError},
+    traits::{Abs, Epsilon, IsZero, One, Sqrt},
+    Complex, Matrix, Vector,
+};
+
+use super::Polynomial;
+
+impl<K> Polynomial<K>
+where
+    for<'a> K: Clone
+        + Default
+        + One
+        + Sqrt
+        + Abs
+        + Epsilon
+        + PartialOrd
+        + Neg<Output = K>
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>
+        + IsZero,
+{
+    ///
+    /// Returns the complex roots of the polynomial, repeated according to
+    /// their multiplicity, in the order of [`eigenvalues`](Matrix#method.eigenvalues).
+    ///
+    /// A polynomial of degree `n` has exactly `n` roots, so a non-zero
+    /// constant has none.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{polynomial::Polynomial, Complex};
+    ///
+    /// // x^2 + 1
+    /// let poly = Polynomial::from([1., 0., 1.]);
+    /// assert_eq!(
+    ///     poly.roots().unwrap(),
+    ///     [Complex::new(0., 1.), Complex::new(0., -1.)]
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// If the polynomial is zero, every number is a root, and returns a [`ZeroPolynomial`](RootsError::ZeroPolynomial)
+    /// If the QR iterations don't converge, returns a [`NoConvergence`](RootsError::NoConvergence)
+    ///
+    /// # Complexity
+    /// For a polynomial of degree `n`:
+    /// Time: O(n^3)
+    /// Space: O(n^2)
+    ///
+    pub fn roots(&self) -> Result<Vector<Complex<K>>, RootsError> {
+        let Some(degree) = self.degree() else {
+            return Err(RootsError::ZeroPolynomial);
+        };
+        if degree == 0 {
+            return Ok(Vector::from(Vec::new()));
+        }
+        let leading = &self.coefficients[degree];
+
+        // x^n + c[n - 1] * x^(n - 1) + ... + c[0] is the characteristic
+        // polynomial of the matrix with ones on its subdiagonal, and the
+        // `-c[i]` on its last column
+        let mut companion: Matrix<K> =
+            Matrix::fill_default(degree, degree).unwrap_or_else(|| unreachable!());
+        for line in 1..degree {
+            companion[line * degree + line - 1] = K::one();
+        }
+        for (line, coefficient) in self.coefficients[..degree].iter().enumerate() {
+            companion[line * degree + degree - 1] = -(coefficient / leading);
+        }
+
+        companion.eigenvalues().map_err(|error| match error {
+            EigenvaluesError::NoConvergence => RootsError::NoConvergence,
+            EigenvaluesError::NotSquareMatrix => unreachable!(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{
+        assert_eq_float, complex::cpl, error::RootsError, polynomial::Polynomial, Complex,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn assert_roots(poly: &Polynomial<f64>, expected: &[Complex<f64>]) {
+        let roots = poly.roots().unwrap();
+        assert_eq!(roots.len(), expected.len());
+        for (root, expected) in roots.iter().zip(expected) {
+            assert_eq_float!(*root.re(), *expected.re());
+            assert_eq_float!(*root.im(), *expected.im());
+        }
+    }
+
+    #[test]
+    fn real_roots() {
+        // (x - 1)(x - 2)(x - 3)
+        let poly = Polynomial::from([-6., 11., -6., 1.]);
+        assert_roots(&poly, &[cpl!(3., 0.), cpl!(2., 0.), cpl!(1., 0.)]);
+        // 2x - 1
+        assert_roots(&Polynomial::from([-1., 2.]), &[cpl!(0.5, 0.)]);
+        // x^2 (x + 4)
+        assert_roots(
+            &Polynomial::from([0., 0., 4., 1.]),
+            &[cpl!(0., 0.), cpl!(0., 0.), cpl!(-4., 0.)],
+        );
+    }
+
+    #[test]
+    fn complex_roots() {
+        // (x^2 + 2x + 5)(x - 1), with roots -1 ± 2i and 1
+        let poly = Polynomial::from([-5., 3., 1., 1.]);
+        assert_roots(&poly, &[cpl!(1., 0.), cpl!(-1., 2.), cpl!(-1., -2.)]);
+        for root in &poly.roots().unwrap() {
+            let value = Polynomial::from([cpl!(-5., 0.), cpl!(3., 0.), cpl!(1., 0.), cpl!(1., 0.)])
+                .evaluate(root);
+            assert_eq_float!(*value.re(), 0.);
+            assert_eq_float!(*value.im(), 0.);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Polynomial::from([3.]).roots().unwrap().len(), 0);
+        assert_eq!(
+            Polynomial::<f64>::default().roots(),
+            Err(RootsError::ZeroPolynomial)
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    fmt::Display,
+    ops::{AddAssign, Mul},
+};
+
+use crate::traits::{IsZero, One};
+
+use super::Polynomial;
+
+impl<K> Polynomial<K>
+where
+    for<'a> &'a K: IsZero,
+{
+    ///
+    /// Creates the polynomial `c[0] + c[1] * x + c[2] * x^2 + ...`.
+    ///
+    /// The trailing zero coefficients are removed.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::polynomial::Polynomial;
+    ///
+    /// let poly = Polynomial::new(vec![1, 0, 3, 0]);
+    /// assert_eq!(poly.coefficients(), &[1, 0, 3]);
+    /// assert_eq!(poly.degree(), Some(2));
+    /// ```
+    ///
+    #[must_use]
+    pub fn new(mut coefficients: Vec<K>) -> Self {
+        while coefficients.last().is_some_and(|elt| elt.is_zero()) {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+}
+
+impl<K> Polynomial<K> {
+    ///
+    /// Returns the coefficients, from the constant term to the leading one.
+    ///
+    #[must_use]
+    pub fn coefficients(&self) -> &[K] {
+        &self.coefficients
+    }
+
+    ///
+    /// Returns the degree of the polynomial, or `None` for the zero
+    /// polynomial.
+    ///
+    #[must_use]
+    pub const fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    ///
+    /// Returns the coefficient of the highest power of `x`, or `None` for the
+    /// zero polynomial.
+    ///
+    #[must_use]
+    pub fn leading_coefficient(&self) -> Option<&K> {
+        self.coefficients.last()
+    }
+
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+impl<K> Polynomial<K>
+where
+    for<'a> K: Clone + Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    ///
+    /// Returns the value of the polynomial at `x`, using Horner's method.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::polynomial::Polynomial;
+    ///
+    /// // x^2 - 3x + 2
+    /// let poly = Polynomial::from([2, -3, 1]);
+    /// assert_eq!(poly.evaluate(&1), 0);
+    /// assert_eq!(poly.evaluate(&5), 12);
+    /// ```
+    ///
+    /// # Complexity
+    /// Time: O(n), with `n` the degree of the polynomial
+    /// Space: O(1)
+    ///
+    pub fn evaluate(&self, x: &K) -> K {
+        let mut result = K::default();
+        for coefficient in self.coefficients.iter().rev() {
+            result = &result * x;
+            result += coefficient;
+        }
+        result
+    }
+}
+
+impl<K> Polynomial<K>
+where
+    for<'a> K: Clone + One + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + IsZero,
+{
+    ///
+    /// Returns the derivative of the polynomial.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::polynomial::Polynomial;
+    ///
+    /// // x^3 + 2x + 5
+    /// let poly = Polynomial::from([5, 2, 0, 1]);
+    /// assert_eq!(poly.derivative(), Polynomial::from([2, 0, 3]));
+    /// ```
+    ///
+    /// # Complexity
+    /// Time: O(n), with `n` the degree of the polynomial
+    /// Space: O(n)
+    ///
+    #[must_use]
+    pub fn derivative(&self) -> Self {
+        let mut factor = K::one();
+        let mut coefficients = Vec::with_capacity(self.coefficients.len().saturating_sub(1));
+        for coefficient in self.coefficients.iter().skip(1) {
+            coefficients.push(coefficient * &factor);
+            factor += &K::one();
+        }
+        // In a field of characteristic `p`, `p * c` is zero
+        Self::new(coefficients)
+    }
+}
+
+impl<K> One for Polynomial<K>
+where
+    K: One,
+    for<'a> &'a K: IsZero,
+{
+    fn one() -> Self {
+        Self::new(vec![K::one()])
+    }
+}
+
+impl<K> IsZero for &Polynomial<K> {
+    fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+impl<K> From<Vec<K>> for Polynomial<K>
+where
+    for<'a> &'a K: IsZero,
+{
+    fn from(coefficients: Vec<K>) -> Self {
+        Self::new(coefficients)
+    }
+}
+
+impl<K, const SIZE: usize> From<[K; SIZE]> for Polynomial<K>
+where
+    for<'a> &'a K: IsZero,
+{
+    fn from(coefficients: [K; SIZE]) -> Self {
+        Self::new(Vec::from(coefficients))
+    }
+}
+
+impl<K> Display for Polynomial<K>
+where
+    K: Display,
+    for<'a> &'a K: IsZero,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (power, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.is_zero() {
+                continue;
+            }
+            // The sign is read from the display, which keeps working for
+            // coefficients that can't be compared to zero
+            let coefficient = coefficient.to_string();
+            let (negative, magnitude) = coefficient
+                .strip_prefix('-')
+                .map_or((false, coefficient.as_str()), |magnitude| (true, magnitude));
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => (),
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+            match power {
+                0 => write!(f, "{magnitude}")?,
+                _ if magnitude == "1" => write!(f, "x")?,
+                _ => write!(f, "{magnitude}x")?,
+            }
+            if power > 1 {
+                write!(f, "^{power}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{complex::cpl, polynomial::Polynomial, rational::rat, Gf};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn construction() {
+        let poly = Polynomial::from([0, 0, 0]);
+        assert!(poly.is_zero());
+        assert_eq!(poly.degree(), None);
+        assert_eq!(poly.leading_coefficient(), None);
+        let poly = Polynomial::from([1., -2., 0.]);
+        assert_eq!(poly.degree(), Some(1));
+        assert_eq!(poly.leading_coefficient(), Some(&-2.));
+    }
+
+    #[test]
+    fn evaluate() {
+        let poly = Polynomial::from([2, -3, 1]);
+        assert_eq!(poly.evaluate(&0), 2);
+        assert_eq!(poly.evaluate(&2), 0);
+        assert_eq!(poly.evaluate(&-1), 6);
+        assert_eq!(Polynomial::<i64>::default().evaluate(&5), 0);
+
+        // x^2 + 1 vanishes at i
+        let poly = Polynomial::from([cpl!(1, 0), cpl!(0, 0), cpl!(1, 0)]);
+        assert_eq!(poly.evaluate(&cpl!(0, 1)), cpl!(0, 0));
+
+        let poly = Polynomial::from([rat!(1_i64, 2), rat!(1, 3)]);
+        assert_eq!(poly.evaluate(&rat!(3, 2)), rat!(1));
+    }
+
+    #[test]
+    fn derivative() {
+        let poly = Polynomial::from([1., 1., 1., 1.]);
+        assert_eq!(poly.derivative(), Polynomial::from([1., 2., 3.]));
+        assert_eq!(
+            Polynomial::from([4]).derivative(),
+            Polynomial::<i32>::default()
+        );
+        // The derivative of x^3 is 3x^2 = 0 in GF(3)
+        let poly = Polynomial::from([Gf::<3>::new(1), Gf::new(0), Gf::new(0), Gf::new(1)]);
+        assert!(poly.derivative().is_zero());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Polynomial::from([-4, 1, 0, 3]).to_string(), "3x^3 + x - 4");
+        assert_eq!(Polynomial::from([1, -1, -2]).to_string(), "-2x^2 - x + 1");
+        assert_eq!(Polynomial::from([0, 2]).to_string(), "2x");
+        assert_eq!(Polynomial::from([-1]).to_string(), "-1");
+        assert_eq!(
+            Polynomial::from([rat!(-1_i64, 2), rat!(1)]).to_string(),
+            "x - 1/2"
+        );
+        assert_eq!(Polynomial::<i32>::default().to_string(), "0");
+    }
+}
@@ -0,0 +1,27 @@
+///
+/// An exact fraction `numerator / denominator` of two integers.
+///
+/// It is always kept normalized: the fraction is irreducible, and its
+/// denominator is positive. This makes two equal fractions structurally equal.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Rational<T> {
+    numerator: T,
+    denominator: T,
+}
+
+mod operations;
+mod utils;
+
+#[cfg(test)]
+macro_rules! rat {
+    ($numerator: expr, $denominator: expr) => {
+        crate::Rational::new($numerator, $denominator)
+    };
+    ($numerator: expr) => {
+        crate::Rational::from($numerator)
+    };
+}
+
+#[cfg(test)]
+pub(crate) use rat;
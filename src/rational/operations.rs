@@ -0,0 +1,178 @@
+use std::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::traits::Gcd;
+
+use super::Rational;
+
+macro_rules! impl_operation {
+    ($current: ident, $trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident, $internal: ident) => {
+        impl $assign_trait<&Self> for Rational<$current> {
+            fn $assign_method(&mut self, rhs: &Self) {
+                *self = self.$internal(rhs);
+            }
+        }
+
+        impl $assign_trait<Self> for Rational<$current> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.$internal(&rhs);
+            }
+        }
+
+        impl $trait<Self> for &Rational<$current> {
+            type Output = Rational<$current>;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$internal(rhs)
+            }
+        }
+
+        impl $trait<Rational<$current>> for &Rational<$current> {
+            type Output = Rational<$current>;
+            fn $method(self, rhs: Rational<$current>) -> Self::Output {
+                self.$internal(&rhs)
+            }
+        }
+
+        impl $trait<&Self> for Rational<$current> {
+            type Output = Self;
+            fn $method(self, rhs: &Self) -> Self::Output {
+                self.$internal(rhs)
+            }
+        }
+
+        impl $trait<Self> for Rational<$current> {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$internal(&rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_operations {
+    ($current: ident, $($types: ident),+) => {
+        impl_operations!($current);
+        impl_operations!($($types),+);
+    };
+    ($current: ident) => {
+        impl Rational<$current> {
+            // a/b + c/d = (a * d/g + c * b/g) / (b/g * d), with g = gcd(b, d)
+            #[inline(always)]
+            fn default_add(&self, rhs: &Self) -> Self {
+                let divisor = self.denominator.gcd(&rhs.denominator);
+                let numerator = self.numerator * (rhs.denominator / divisor)
+                    + rhs.numerator * (self.denominator / divisor);
+                Self::new(numerator, self.denominator / divisor * rhs.denominator)
+            }
+
+            #[inline(always)]
+            fn default_sub(&self, rhs: &Self) -> Self {
+                self.default_add(&-rhs)
+            }
+
+            // Cross simplifications keep the products as small as possible
+            #[inline(always)]
+            fn default_mul(&self, rhs: &Self) -> Self {
+                let first = self.numerator.gcd(&rhs.denominator);
+                let second = rhs.numerator.gcd(&self.denominator);
+                Self::new(
+                    (self.numerator / first) * (rhs.numerator / second),
+                    (self.denominator / second) * (rhs.denominator / first),
+                )
+            }
+
+            ///
+            /// # Panics
+            /// If `rhs` is zero.
+            ///
+            #[inline(always)]
+            fn default_div(&self, rhs: &Self) -> Self {
+                assert!(rhs.numerator != 0, "attempt to divide a rational by zero");
+                self.default_mul(&Self {
+                    numerator: rhs.denominator,
+                    denominator: rhs.numerator,
+                })
+            }
+        }
+
+        impl_operation!($current, Add, add, AddAssign, add_assign, default_add);
+        impl_operation!($current, Sub, sub, SubAssign, sub_assign, default_sub);
+        impl_operation!($current, Mul, mul, MulAssign, mul_assign, default_mul);
+        impl_operation!($current, Div, div, DivAssign, div_assign, default_div);
+
+        impl Neg for Rational<$current> {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                -&self
+            }
+        }
+
+        impl Neg for &Rational<$current> {
+            type Output = Rational<$current>;
+            fn neg(self) -> Self::Output {
+                Rational {
+                    numerator: -self.numerator,
+                    denominator: self.denominator,
+                }
+            }
+        }
+
+        impl Sum for Rational<$current> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::from(0), |acc, new| acc + new)
+            }
+        }
+
+        impl Product for Rational<$current> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::from(1), |acc, new| acc * new)
+            }
+        }
+    };
+}
+
+impl_operations!(i8, i16, i32, i64, i128);
+
+#[allow(clippy::op_ref)]
+#[cfg(test)]
+mod test {
+    use crate::rational::rat;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn arithmetic() {
+        let (half, third) = (rat!(1_i64, 2), rat!(1_i64, 3));
+        assert_eq!(half + third, rat!(5, 6));
+        assert_eq!(&half - &third, rat!(1, 6));
+        assert_eq!(half * &third, rat!(1, 6));
+        assert_eq!(&half / third, rat!(3, 2));
+        assert_eq!(-half, rat!(-1, 2));
+        assert_eq!(rat!(3_i64, 4) * rat!(-8, 9), rat!(-2, 3));
+        assert_eq!(rat!(1_i64, 6) + rat!(1, 3), rat!(1, 2));
+        assert_eq!(rat!(0_i64) * rat!(-8, 9), rat!(0));
+
+        let mut nb = rat!(1_i128, 2);
+        nb += rat!(1, 4);
+        nb -= &rat!(1, 8);
+        nb *= rat!(8, 5);
+        assert_eq!(nb, rat!(1));
+        nb /= &rat!(-3);
+        assert_eq!(nb, rat!(-1, 3));
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let sum: crate::Rational<i64> = (1..=4).map(|i| rat!(1, i)).sum();
+        assert_eq!(sum, rat!(25, 12));
+        let product: crate::Rational<i64> = (1..=4).map(|i| rat!(i, i + 1)).product();
+        assert_eq!(product, rat!(1, 5));
+    }
+
+    #[test]
+    #[should_panic = "attempt to divide a rational by zero"]
+    fn division_by_zero() {
+        let _ = rat!(1_i64, 2) / rat!(0);
+    }
+}
@@ -0,0 +1,300 @@
+use std::{cmp::Ordering, fmt::Display, ops::Div};
+
+use crate::traits::{
+    Abs, ApproxZero, CheckedNeg, Conjugate, Divisor, Gcd, IsField, IsOne, IsPositive, IsZero, One,
+    Zero,
+};
+
+use super::Rational;
+
+impl<T> Rational<T>
+where
+    T: Copy + Gcd + Zero + One + PartialOrd + Div<Output = T> + CheckedNeg,
+    for<'a> &'a T: IsZero + IsOne,
+{
+    ///
+    /// Returns the normalized fraction `numerator / denominator`.
+    ///
+    /// # Panics
+    /// If `denominator` is zero.
+    /// If the normalized fraction is not representable, because its
+    /// numerator or denominator would be `-MIN`, like for `new(1, MIN)` or
+    /// `new(MIN, -1)`.
+    ///
+    pub fn new(numerator: T, denominator: T) -> Self {
+        assert!(
+            !(&denominator).is_zero(),
+            "the denominator of a rational is zero"
+        );
+        // The only cases where the gcd would be `-MIN`
+        if (&numerator).is_zero() {
+            return Self::from_parts(T::zero(), T::one());
+        }
+        if numerator == denominator {
+            return Self::from_parts(T::one(), T::one());
+        }
+        let divisor = numerator.gcd(&denominator);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        if denominator >= T::zero() {
+            return Self::from_parts(numerator, denominator);
+        }
+        match (numerator.checked_neg(), denominator.checked_neg()) {
+            (Some(numerator), Some(denominator)) => Self::from_parts(numerator, denominator),
+            _ => panic!("the normalized fraction of a rational is not representable"),
+        }
+    }
+
+    const fn from_parts(numerator: T, denominator: T) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    pub const fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    pub const fn denominator(&self) -> T {
+        self.denominator
+    }
+
+    ///
+    /// Returns whether the fraction is an integer.
+    ///
+    pub fn is_integer(&self) -> bool {
+        (&self.denominator).is_one()
+    }
+}
+
+macro_rules! impl_rational {
+    ($current: ident, $($types: ident),+) => {
+        impl_rational!($current);
+        impl_rational!($($types),+);
+    };
+    ($current: ident) => {
+        impl Default for Rational<$current> {
+            fn default() -> Self {
+                Self::from(0)
+            }
+        }
+
+        impl From<$current> for Rational<$current> {
+            fn from(nb: $current) -> Self {
+                Self {
+                    numerator: nb,
+                    denominator: 1,
+                }
+            }
+        }
+
+        impl Zero for Rational<$current> {
+            fn zero() -> Self {
+                Self::from(0)
+            }
+        }
+
+        impl One for Rational<$current> {
+            fn one() -> Self {
+                Self::from(1)
+            }
+        }
+
+        impl IsZero for Rational<$current> {
+            #[inline(always)]
+            fn is_zero(&self) -> bool {
+                self.numerator == 0
+            }
+        }
+
+        impl IsZero for &Rational<$current> {
+            #[inline(always)]
+            fn is_zero(&self) -> bool {
+                self.numerator == 0
+            }
+        }
+
+        impl IsOne for Rational<$current> {
+            #[inline(always)]
+            fn is_one(&self) -> bool {
+                self.numerator == 1 && self.denominator == 1
+            }
+        }
+
+        impl IsOne for &Rational<$current> {
+            #[inline(always)]
+            fn is_one(&self) -> bool {
+                self.numerator == 1 && self.denominator == 1
+            }
+        }
+
+        impl Abs for Rational<$current> {
+            fn abs(&self) -> Self {
+                Self {
+                    numerator: self.numerator.abs(),
+                    denominator: self.denominator,
+                }
+            }
+        }
+
+        impl Divisor for Rational<$current> {
+            #[inline(always)]
+            fn can_be_divisor(&self) -> bool {
+                self.numerator != 0
+            }
+        }
+
+        impl IsField for Rational<$current> {
+            const IS_FIELD: bool = true;
+        }
+
+        impl Conjugate for Rational<$current> {
+            fn conjugate(&self) -> Self {
+                *self
+            }
+        }
+
+        impl IsPositive for Rational<$current> {
+            fn is_positive(&self) -> bool {
+                self.numerator > 0
+            }
+        }
+
+        impl ApproxZero for Rational<$current> {
+            type Tolerance = Self;
+
+            fn is_approx_zero(&self, tolerance: &Self) -> bool {
+                &self.abs() <= tolerance
+            }
+        }
+
+        impl Ord for Rational<$current> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                // Cross-multiplying could overflow: the fractions are
+                // compared through their continued fraction expansions
+                // instead, whose terms all fit in the integer type
+                let (mut lhs_numerator, mut lhs_denominator) = (self.numerator, self.denominator);
+                let (mut rhs_numerator, mut rhs_denominator) = (other.numerator, other.denominator);
+                let mut reversed = false;
+                let ordering = loop {
+                    // Both denominators are positive
+                    let lhs_integer = lhs_numerator.div_euclid(lhs_denominator);
+                    let rhs_integer = rhs_numerator.div_euclid(rhs_denominator);
+                    if lhs_integer != rhs_integer {
+                        break lhs_integer.cmp(&rhs_integer);
+                    }
+                    let lhs_remainder = lhs_numerator.rem_euclid(lhs_denominator);
+                    let rhs_remainder = rhs_numerator.rem_euclid(rhs_denominator);
+                    match (lhs_remainder == 0, rhs_remainder == 0) {
+                        (true, true) => break Ordering::Equal,
+                        (true, false) => break Ordering::Less,
+                        (false, true) => break Ordering::Greater,
+                        (false, false) => (),
+                    }
+                    // r1 / d1 < r2 / d2 exactly when d1 / r1 > d2 / r2
+                    (lhs_numerator, lhs_denominator) = (lhs_denominator, lhs_remainder);
+                    (rhs_numerator, rhs_denominator) = (rhs_denominator, rhs_remainder);
+                    reversed = !reversed;
+                };
+                if reversed {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+
+        impl PartialOrd for Rational<$current> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Display for Rational<$current> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if self.is_integer() {
+                    write!(f, "{}", self.numerator)
+                } else {
+                    write!(f, "{}/{}", self.numerator, self.denominator)
+                }
+            }
+        }
+    };
+}
+
+impl_rational!(i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        rational::rat,
+        traits::{IsOne, IsZero},
+        Rational,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn normalization() {
+        assert_eq!(rat!(2_i64, 4), rat!(1, 2));
+        assert_eq!(rat!(3_i64, -6), rat!(-1, 2));
+        assert_eq!(rat!(-3_i64, -6), rat!(1, 2));
+        assert_eq!(rat!(0_i64, -6), rat!(0));
+        let nb = rat!(-10_i128, 4);
+        assert_eq!((nb.numerator(), nb.denominator()), (-5, 2));
+        assert!(rat!(6_i64, 3).is_integer());
+    }
+
+    #[test]
+    #[should_panic = "the denominator of a rational is zero"]
+    fn zero_denominator() {
+        let _ = Rational::new(1_i64, 0);
+    }
+
+    #[test]
+    fn extreme_values() {
+        assert_eq!(rat!(i64::MIN, 1), rat!(i64::MIN));
+        assert_eq!(rat!(i64::MIN, 2), rat!(i64::MIN / 2));
+        assert_eq!(rat!(i64::MIN, i64::MIN), rat!(1));
+        assert_eq!(rat!(0, i64::MIN), rat!(0));
+        assert_eq!(rat!(2_i64, i64::MIN), rat!(-1, 1 << 62));
+        assert_eq!(rat!(i64::MAX, i64::MIN + 1), rat!(-1));
+        assert_eq!(rat!(i64::MIN, -2).numerator(), 1 << 62);
+    }
+
+    #[test]
+    #[should_panic = "the normalized fraction of a rational is not representable"]
+    fn unrepresentable_denominator() {
+        let _ = Rational::new(1_i64, i64::MIN);
+    }
+
+    #[test]
+    #[should_panic = "the normalized fraction of a rational is not representable"]
+    fn unrepresentable_numerator() {
+        let _ = Rational::new(i64::MIN, -1);
+    }
+
+    #[test]
+    fn compare_large() {
+        // The cross products overflow an `i64`
+        let max = i64::MAX;
+        assert!(rat!(max, max - 1) < rat!(max - 1, max - 2));
+        assert!(rat!(max - 1, max) > rat!(max - 2, max - 1));
+        assert!(rat!(-max, max - 1) > rat!(-(max - 1), max - 2));
+        assert!(rat!(max, 2) > rat!(max - 2, 2));
+        assert!(rat!(i64::MIN + 1, max - 1) < rat!(-1));
+    }
+
+    #[test]
+    fn traits() {
+        assert!(rat!(0_i64, 5).is_zero());
+        assert!(rat!(5_i64, 5).is_one());
+        assert_eq!(Rational::<i64>::default(), rat!(0));
+        assert!(rat!(1_i64, 3) < rat!(1, 2));
+        assert!(rat!(-1_i64, 2) < rat!(-1, 3));
+        assert_eq!(rat!(3_i64, 4).cmp(&rat!(6, 8)), std::cmp::Ordering::Equal);
+        assert!(rat!(-7_i64, 3) < rat!(-2));
+        assert!(rat!(5_i64, 3) < rat!(7, 4));
+        assert_eq!(rat!(1_i64, 3).to_string(), "1/3");
+        assert_eq!(rat!(-4_i64, 2).to_string(), "-2");
+    }
+}
@@ -0,0 +1,153 @@
+//!
+//! Iterative solvers for `A * x = b`, for systems too large for the dense
+//! [`inverse`](crate::Matrix#method.inverse) or [`lu`](crate::Matrix#method.lu).
+//!
+//! They only need the products of `A` by vectors, through the
+//! [`LinearOperator`] trait, and start from `x = 0`:
+//! - [`conjugate_gradient`] for symmetric positive definite systems,
+//! - [`bicgstab`] and [`gmres`] for any invertible system,
+//! - [`jacobi`] and [`gauss_seidel`], which also read the elements of `A`
+//!   through [`LineEntries`], for diagonally dominant systems.
+//!
+//! All of them iterate until the residual `‖b - A * x‖` is at most
+//! `tolerance * ‖b‖`, or until `max_iterations` is reached, and return a
+//! [`SolverReport`]. They are meant for real numbers.
+//!
+
+use std::ops::{AddAssign, Mul, SubAssign};
+
+use crate::{error::SolverError, linear_operator::LinearOperator, Vector};
+
+mod bicgstab;
+mod conjugate_gradient;
+mod gmres;
+mod stationary;
+
+pub use bicgstab::bicgstab;
+pub use conjugate_gradient::conjugate_gradient;
+pub use gmres::gmres;
+pub use stationary::{gauss_seidel, jacobi};
+
+///
+/// An operator whose non-zero elements can be read line by line, as needed
+/// by the stationary methods.
+///
+pub trait LineEntries<K> {
+    ///
+    /// Returns the `(column, value)` of the elements of the line `line` that
+    /// may be non-zero, by increasing column.
+    ///
+    fn line_entries<'a>(&'a self, line: usize) -> impl Iterator<Item = (usize, &'a K)>
+    where
+        K: 'a;
+}
+
+///
+/// The result of an iterative solver.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct SolverReport<K: Clone> {
+    solution: Vector<K>,
+    iterations: usize,
+    residuals: Vec<K>,
+    converged: bool,
+}
+
+impl<K: Clone> SolverReport<K> {
+    ///
+    /// Returns the last approximation of the solution.
+    ///
+    pub const fn solution(&self) -> &Vector<K> {
+        &self.solution
+    }
+
+    ///
+    /// Returns the number of iterations done.
+    ///
+    #[must_use]
+    pub const fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    ///
+    /// Returns the norm of the residual `b - A * x` before the first
+    /// iteration, and after each of them.
+    ///
+    #[must_use]
+    pub fn residuals(&self) -> &[K] {
+        &self.residuals
+    }
+
+    ///
+    /// Returns whether the residual went under the tolerance.
+    ///
+    #[must_use]
+    pub const fn converged(&self) -> bool {
+        self.converged
+    }
+
+    ///
+    /// Returns the last approximation of the solution, consuming the report.
+    ///
+    pub fn into_solution(self) -> Vector<K> {
+        self.solution
+    }
+}
+
+///
+/// Checks that the operator is square, of the size of `rhs`.
+///
+fn check_dimensions<K: Clone>(
+    operator: &impl LinearOperator<K>,
+    rhs: &Vector<K>,
+) -> Result<(), SolverError> {
+    let dimensions = operator.dimensions();
+    if dimensions.width != dimensions.height {
+        return Err(SolverError::NotSquareOperator);
+    }
+    if dimensions.width != rhs.len() {
+        return Err(SolverError::SizeMismatch(dimensions.width, rhs.len()));
+    }
+    Ok(())
+}
+
+///
+/// Computes `lhs += factor * rhs`.
+///
+fn add_scaled<K>(lhs: &mut [K], factor: &K, rhs: &[K])
+where
+    for<'a> K: AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    for (lhs, rhs) in lhs.iter_mut().zip(rhs) {
+        *lhs += &(factor * rhs);
+    }
+}
+
+///
+/// Computes `lhs -= factor * rhs`.
+///
+fn sub_scaled<K>(lhs: &mut [K], factor: &K, rhs: &[K])
+where
+    for<'a> K: SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    for (lhs, rhs) in lhs.iter_mut().zip(rhs) {
+        *lhs -= &(factor * rhs);
+    }
+}
+
+///
+/// Returns `rhs - operator * x`.
+///
+fn residual<K>(operator: &impl LinearOperator<K>, rhs: &Vector<K>, x: &Vector<K>) -> Vector<K>
+where
+    for<'a> K: Clone + SubAssign<&'a K>,
+{
+    let mut result = rhs.clone();
+    for (elt, product) in result.iter_mut().zip(operator.apply(x).iter()) {
+        *elt -= product;
+    }
+    result
+}
@@ -0,0 +1,157 @@
+use std::ops::{AddAssign, Div, Mul, Sub, SubAssign};
+
+use crate::{
+    error::SolverError,
+    linear_operator::LinearOperator,
+    traits::{IsZero, One, Sqrt},
+    utils::{dot, norm},
+    Vector,
+};
+
+use super::{add_scaled, check_dimensions, sub_scaled, SolverReport};
+
+///
+/// Solves `A * x = b` with the stabilized biconjugate gradient method
+/// (`BiCGSTAB`), which works on non-symmetric systems.
+///
+/// The iterations stop early, without converging, if the method breaks
+/// down.
+///
+/// # Example
+/// ```
+/// use matrix::{solvers::bicgstab, Matrix, Vector};
+///
+/// let mat = Matrix::from([[4., 1.], [2., 3.]]);
+/// let report = bicgstab(&mat, &Vector::from([1., 2.]), &1e-10, 10).unwrap();
+/// assert!(report.converged());
+/// ```
+///
+/// # Errors
+/// If the operator is not square, returns a [`NotSquareOperator`](SolverError::NotSquareOperator)
+/// If the size of `rhs` doesn't match the operator, returns a [`SizeMismatch`](SolverError::SizeMismatch)
+///
+/// # Complexity
+/// Each iteration takes two products by `A`, and
+/// Time: O(n)
+/// Space: O(n)
+///
+pub fn bicgstab<K>(
+    operator: &impl LinearOperator<K>,
+    rhs: &Vector<K>,
+    tolerance: &K,
+    max_iterations: usize,
+) -> Result<SolverReport<K>, SolverError>
+where
+    for<'a> K: Clone + Default + One + Sqrt + PartialOrd + AddAssign<&'a K> + SubAssign<&'a K>,
+    for<'a> &'a K:
+        Sub<&'a K, Output = K> + Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    check_dimensions(operator, rhs)?;
+    let size = rhs.len();
+    let threshold = tolerance * &norm(rhs);
+    let mut solution = Vector::fill(&K::default(), size);
+    let mut residual = rhs.clone();
+    let shadow = rhs.clone();
+    let mut residuals = vec![norm(&residual)];
+    let mut converged = residuals[0] <= threshold;
+    let mut iterations = 0;
+
+    let (mut rho, mut alpha, mut omega) = (K::one(), K::one(), K::one());
+    let mut direction = Vector::fill(&K::default(), size);
+    let mut product = Vector::fill(&K::default(), size);
+    while !converged && iterations < max_iterations {
+        let next_rho = dot(&shadow, &residual);
+        if (&next_rho).is_zero() || (&omega).is_zero() {
+            break;
+        }
+        let beta = &(&next_rho / &rho) * &(&alpha / &omega);
+        rho = next_rho;
+        // p = r + beta * (p - omega * v)
+        sub_scaled(&mut direction, &omega, &product);
+        for (direction, residual) in direction.iter_mut().zip(residual.iter()) {
+            let mut next = residual.clone();
+            next += &(&beta * direction);
+            *direction = next;
+        }
+        product = operator.apply(&direction);
+        let projection = dot(&shadow, &product);
+        if (&projection).is_zero() {
+            break;
+        }
+        alpha = &rho / &projection;
+        add_scaled(&mut solution, &alpha, &direction);
+        sub_scaled(&mut residual, &alpha, &product);
+        iterations += 1;
+
+        let half_norm = norm(&residual);
+        if half_norm <= threshold {
+            residuals.push(half_norm);
+            converged = true;
+            break;
+        }
+        let stabilizer = operator.apply(&residual);
+        let stabilizer_norm = dot(&stabilizer, &stabilizer);
+        if (&stabilizer_norm).is_zero() {
+            residuals.push(half_norm);
+            break;
+        }
+        omega = &dot(&stabilizer, &residual) / &stabilizer_norm;
+        add_scaled(&mut solution, &omega, &residual);
+        sub_scaled(&mut residual, &omega, &stabilizer);
+
+        let residual_norm = norm(&residual);
+        converged = residual_norm <= threshold;
+        residuals.push(residual_norm);
+    }
+
+    Ok(SolverReport {
+        solution,
+        iterations,
+        residuals,
+        converged,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::bicgstab;
+    use crate::{matrix::SparseMatrix, Matrix, Vector};
+
+    #[test]
+    fn example() {
+        let mat = Matrix::from([[4_f64, 1., 0.], [2., 5., 1.], [0., -1., 3.]]);
+        let rhs = Vector::from([1., 2., 3.]);
+        let report = bicgstab(&mat, &rhs, &1e-12, 20).unwrap();
+        assert!(report.converged());
+        let check = mat.mul_vec(report.solution()).unwrap();
+        for (value, expected) in check.iter().zip(rhs.iter()) {
+            assert!((value - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn convection_diffusion() {
+        // Non-symmetric: -u'' + u' discretized with upwind differences
+        let size = 100;
+        let mut triplets = Vec::new();
+        for index in 0..size {
+            triplets.push((index, index, 4_f64));
+            if index > 0 {
+                triplets.push((index, index - 1, -2.));
+            }
+            if index + 1 < size {
+                triplets.push((index, index + 1, -1.));
+            }
+        }
+        let mat = SparseMatrix::from_triplets(size, size, &triplets).unwrap();
+        let rhs = Vector::fill(&1., size);
+        let report = bicgstab(&mat, &rhs, &1e-10, 500).unwrap();
+        assert!(report.converged());
+        assert_eq!(report.residuals().len(), report.iterations() + 1);
+        let check = mat.mul_vec(report.solution()).unwrap();
+        for value in &check {
+            assert!((value - 1.).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::ops::{AddAssign, Div, Mul, SubAssign};
+
+use crate::{
+    error::SolverError,
+    linear_operator::LinearOperator,
+    traits::{IsZero, Sqrt},
+    utils::{dot, norm},
+    Vector,
+};
+
+use super::{add_scaled, check_dimensions, sub_scaled, SolverReport};
+
+///
+/// Solves `A * x = b` with the conjugate gradient method, where `A` must be
+/// symmetric positive definite.
+///
+/// In exact arithmetic, it converges in at most `n` iterations, and much
+/// faster when the eigenvalues of `A` are clustered.
+///
+/// # Example
+/// ```
+/// use matrix::{solvers::conjugate_gradient, Matrix, Vector};
+///
+/// let mat = Matrix::from([[4., 1.], [1., 3.]]);
+/// let report = conjugate_gradient(&mat, &Vector::from([1., 2.]), &1e-10, 10).unwrap();
+/// assert!(report.converged());
+/// assert_eq!(report.iterations(), 2);
+/// ```
+///
+/// # Errors
+/// If the operator is not square, returns a [`NotSquareOperator`](SolverError::NotSquareOperator)
+/// If the size of `rhs` doesn't match the operator, returns a [`SizeMismatch`](SolverError::SizeMismatch)
+///
+/// # Complexity
+/// Each iteration takes one product by `A`, and
+/// Time: O(n)
+/// Space: O(n)
+///
+pub fn conjugate_gradient<K>(
+    operator: &impl LinearOperator<K>,
+    rhs: &Vector<K>,
+    tolerance: &K,
+    max_iterations: usize,
+) -> Result<SolverReport<K>, SolverError>
+where
+    for<'a> K: Clone + Default + Sqrt + PartialOrd + AddAssign<&'a K> + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    check_dimensions(operator, rhs)?;
+    let threshold = tolerance * &norm(rhs);
+    let mut solution = Vector::fill(&K::default(), rhs.len());
+    let mut residual = rhs.clone();
+    let mut direction = rhs.clone();
+    let mut squared_norm = dot(&residual, &residual);
+    let mut residuals = vec![squared_norm.sqrt()];
+    let mut converged = residuals[0] <= threshold;
+    let mut iterations = 0;
+
+    while !converged && iterations < max_iterations {
+        let product = operator.apply(&direction);
+        let curvature = dot(&direction, &product);
+        if (&curvature).is_zero() {
+            break;
+        }
+        let step = &squared_norm / &curvature;
+        add_scaled(&mut solution, &step, &direction);
+        sub_scaled(&mut residual, &step, &product);
+        iterations += 1;
+
+        let next_squared_norm = dot(&residual, &residual);
+        let residual_norm = next_squared_norm.sqrt();
+        converged = residual_norm <= threshold;
+        residuals.push(residual_norm);
+
+        // p = r + beta * p
+        let beta = &next_squared_norm / &squared_norm;
+        for (direction, residual) in direction.iter_mut().zip(residual.iter()) {
+            let mut next = residual.clone();
+            next += &(&beta * direction);
+            *direction = next;
+        }
+        squared_norm = next_squared_norm;
+    }
+
+    Ok(SolverReport {
+        solution,
+        iterations,
+        residuals,
+        converged,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::conjugate_gradient;
+    use crate::{error::SolverError, matrix::SparseMatrix, Matrix, Vector};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn example() {
+        let mat = Matrix::from([[4_f64, 1.], [1., 3.]]);
+        let report = conjugate_gradient(&mat, &Vector::from([1., 2.]), &1e-12, 10).unwrap();
+        assert!(report.converged());
+        assert_eq!(report.iterations(), 2);
+        assert_eq!(report.residuals().len(), 3);
+        let solution = report.solution();
+        assert!((solution[0] - 1. / 11.).abs() < 1e-12);
+        assert!((solution[1] - 7. / 11.).abs() < 1e-12);
+
+        // Zero right hand side
+        let report = conjugate_gradient(&mat, &Vector::from([0., 0.]), &1e-12, 10).unwrap();
+        assert!(report.converged());
+        assert_eq!(report.iterations(), 0);
+        assert_eq!(report.into_solution(), [0., 0.]);
+    }
+
+    #[test]
+    fn sparse_laplacian() {
+        let size = 200;
+        let mut triplets = Vec::new();
+        for index in 0..size {
+            triplets.push((index, index, 2_f64));
+            if index + 1 < size {
+                triplets.push((index, index + 1, -1.));
+                triplets.push((index + 1, index, -1.));
+            }
+        }
+        let laplacian = SparseMatrix::from_triplets(size, size, &triplets).unwrap();
+        let rhs = Vector::fill(&1., size);
+        let report = conjugate_gradient(&laplacian, &rhs, &1e-10, 1000).unwrap();
+        assert!(report.converged());
+        assert!(report.iterations() <= size);
+        let check = laplacian.mul_vec(report.solution()).unwrap();
+        for value in &check {
+            assert!((value - 1.).abs() < 1e-6);
+        }
+
+        // Not enough iterations
+        let report = conjugate_gradient(&laplacian, &rhs, &1e-10, 5).unwrap();
+        assert!(!report.converged());
+        assert_eq!(report.iterations(), 5);
+    }
+
+    #[test]
+    fn errors() {
+        let mat = Matrix::from([[1., 2., 3.]]);
+        assert_eq!(
+            conjugate_gradient(&mat, &Vector::from([1.]), &1e-10, 10),
+            Err(SolverError::NotSquareOperator)
+        );
+        let mat = Matrix::from([[1., 0.], [0., 1.]]);
+        assert_eq!(
+            conjugate_gradient(&mat, &Vector::from([1.]), &1e-10, 10),
+            Err(SolverError::SizeMismatch(2, 1))
+        );
+    }
+}
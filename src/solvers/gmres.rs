@@ -0,0 +1,212 @@
+use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+use crate::{
+    error::SolverError,
+    linear_operator::LinearOperator,
+    traits::{Abs, IsZero, One, Sqrt},
+    utils::{dot, norm},
+    Vector,
+};
+
+use super::{add_scaled, check_dimensions, residual, sub_scaled, SolverReport};
+
+///
+/// Solves `A * x = b` with the generalized minimal residual method (GMRES),
+/// restarted every `restart` iterations.
+///
+/// Each cycle builds an orthonormal basis of the Krylov space
+/// `b, A * b, A^2 * b, ...` with the Arnoldi process, and picks the element
+/// of that space minimizing the residual. The bigger `restart`, the faster
+/// the convergence, and the more memory used.
+///
+/// The residuals reported during a cycle are the ones minimized by the
+/// method, which only match the true residuals up to rounding errors.
+///
+/// # Example
+/// ```
+/// use matrix::{solvers::gmres, Matrix, Vector};
+///
+/// let mat = Matrix::from([[1., 2.], [3., 4.]]);
+/// let report = gmres(&mat, &Vector::from([1., 2.]), &1e-10, 10, 5).unwrap();
+/// assert!(report.converged());
+/// assert_eq!(report.iterations(), 2);
+/// ```
+///
+/// # Errors
+/// If the operator is not square, returns a [`NotSquareOperator`](SolverError::NotSquareOperator)
+/// If the size of `rhs` doesn't match the operator, returns a [`SizeMismatch`](SolverError::SizeMismatch)
+///
+/// # Complexity
+/// Each iteration `j` of a cycle takes one product by `A`, and
+/// Time: O(jn)
+/// Space: O(restart * n)
+///
+pub fn gmres<K>(
+    operator: &impl LinearOperator<K>,
+    rhs: &Vector<K>,
+    tolerance: &K,
+    max_iterations: usize,
+    restart: usize,
+) -> Result<SolverReport<K>, SolverError>
+where
+    for<'a> K:
+        Clone + Default + One + Abs + Sqrt + PartialOrd + AddAssign<&'a K> + SubAssign<&'a K>,
+    for<'a> &'a K: Add<&'a K, Output = K>
+        + Sub<&'a K, Output = K>
+        + Mul<&'a K, Output = K>
+        + Div<&'a K, Output = K>
+        + IsZero,
+{
+    check_dimensions(operator, rhs)?;
+    let restart = restart.max(1);
+    let threshold = tolerance * &norm(rhs);
+    let mut solution = Vector::fill(&K::default(), rhs.len());
+    let mut residual_norm = norm(rhs);
+    let mut residuals = vec![residual_norm.clone()];
+    let mut converged = residual_norm <= threshold;
+    let mut iterations = 0;
+
+    let mut current = rhs.clone();
+    while !converged && iterations < max_iterations && !(&residual_norm).is_zero() {
+        // Arnoldi process, the Hessenberg matrix being reduced to an upper
+        // triangular one with Givens rotations along the way
+        let mut basis = vec![scaled(&current, &residual_norm)];
+        let mut hessenberg: Vec<Vec<K>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(K, K)> = Vec::with_capacity(restart);
+        let mut projected = vec![residual_norm.clone()];
+        while hessenberg.len() < restart && iterations < max_iterations {
+            let mut next = operator.apply(&basis[basis.len() - 1]);
+            let mut column: Vec<K> = Vec::with_capacity(basis.len() + 1);
+            for vector in &basis {
+                let coefficient = dot(&next, vector);
+                sub_scaled(&mut next, &coefficient, vector);
+                column.push(coefficient);
+            }
+            let next_norm = norm(&next);
+            column.push(next_norm.clone());
+
+            for (index, (cosine, sine)) in rotations.iter().enumerate() {
+                let (top, bottom) = (&column[index], &column[index + 1]);
+                let rotated_top = &(cosine * top) + &(sine * bottom);
+                let rotated_bottom = &(cosine * bottom) - &(sine * top);
+                column[index] = rotated_top;
+                column[index + 1] = rotated_bottom;
+            }
+            let last = column.len() - 1;
+            let (top, bottom) = (&column[last - 1], &column[last]);
+            let radius = (&(top * top) + &(bottom * bottom)).sqrt();
+            let (cosine, sine) = if (&radius).is_zero() {
+                (K::one(), K::default())
+            } else {
+                (top / &radius, bottom / &radius)
+            };
+            column[last - 1] = radius;
+            column.pop();
+            let top_projected = projected[last - 1].clone();
+            projected[last - 1] = &cosine * &top_projected;
+            projected.push(&K::default() - &(&sine * &top_projected));
+            rotations.push((cosine, sine));
+            hessenberg.push(column);
+            iterations += 1;
+
+            let estimate = projected[last].abs();
+            converged = estimate <= threshold;
+            residuals.push(estimate);
+            // A zero `next` means that the solution is in the Krylov space
+            if converged || (&next_norm).is_zero() {
+                break;
+            }
+            basis.push(scaled(&next, &next_norm));
+        }
+
+        // Back substitution on the triangular system
+        let dimension = hessenberg.len();
+        let mut coefficients = projected[..dimension].to_vec();
+        for line in (0..dimension).rev() {
+            for column in line + 1..dimension {
+                let tmp = &hessenberg[column][line] * &coefficients[column];
+                coefficients[line] -= &tmp;
+            }
+            if (&hessenberg[line][line]).is_zero() {
+                coefficients[line] = K::default();
+            } else {
+                coefficients[line] = &coefficients[line] / &hessenberg[line][line];
+            }
+        }
+        for (coefficient, vector) in coefficients.iter().zip(&basis) {
+            add_scaled(&mut solution, coefficient, vector);
+        }
+
+        current = residual(operator, rhs, &solution);
+        residual_norm = norm(&current);
+        converged = residual_norm <= threshold;
+    }
+
+    Ok(SolverReport {
+        solution,
+        iterations,
+        residuals,
+        converged,
+    })
+}
+
+fn scaled<K>(vec: &Vector<K>, norm: &K) -> Vector<K>
+where
+    K: Clone,
+    for<'a> &'a K: Div<&'a K, Output = K>,
+{
+    Vector::from(vec.iter().map(|elt| elt / norm).collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::gmres;
+    use crate::{matrix::SparseMatrix, Matrix, Vector};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn example() {
+        let mat = Matrix::from([[1_f64, 2., 0.], [3., 4., 1.], [0., 1., -2.]]);
+        let rhs = Vector::from([1., 2., 3.]);
+        // Without restart, it converges in at most `n` iterations
+        let report = gmres(&mat, &rhs, &1e-12, 10, 10).unwrap();
+        assert!(report.converged());
+        assert_eq!(report.iterations(), 3);
+        let check = mat.mul_vec(report.solution()).unwrap();
+        for (value, expected) in check.iter().zip(rhs.iter()) {
+            assert!((value - expected).abs() < 1e-10);
+        }
+        // The residuals never increase
+        for pair in report.residuals().windows(2) {
+            assert!(pair[1] <= pair[0] + 1e-12);
+        }
+    }
+
+    #[test]
+    fn restarted() {
+        let size = 100;
+        let mut triplets = Vec::new();
+        for index in 0..size {
+            triplets.push((index, index, 4_f64));
+            if index > 0 {
+                triplets.push((index, index - 1, -2.));
+            }
+            if index + 1 < size {
+                triplets.push((index, index + 1, -1.));
+            }
+        }
+        let mat = SparseMatrix::from_triplets(size, size, &triplets).unwrap();
+        let rhs = Vector::fill(&1., size);
+        let report = gmres(&mat, &rhs, &1e-10, 500, 20).unwrap();
+        assert!(report.converged());
+        let check = mat.mul_vec(report.solution()).unwrap();
+        for value in &check {
+            assert!((value - 1.).abs() < 1e-8);
+        }
+
+        let report = gmres(&mat, &rhs, &1e-10, 3, 20).unwrap();
+        assert!(!report.converged());
+        assert_eq!(report.iterations(), 3);
+    }
+}
@@ -0,0 +1,240 @@
+use std::ops::{AddAssign, Div, Mul, SubAssign};
+
+use crate::{
+    error::SolverError,
+    linear_operator::LinearOperator,
+    traits::{IsZero, Sqrt},
+    utils::norm,
+    Vector,
+};
+
+use super::{check_dimensions, residual, LineEntries, SolverReport};
+
+///
+/// Solves `A * x = b` with the Jacobi method: each coordinate is updated
+/// from its line of the system, using the previous approximation for all
+/// the other ones.
+///
+/// It converges when `A` is strictly diagonally dominant.
+///
+/// # Example
+/// ```
+/// use matrix::{solvers::jacobi, Matrix, Vector};
+///
+/// let mat = Matrix::from([[4., 1.], [2., 5.]]);
+/// let report = jacobi(&mat, &Vector::from([5., 7.]), &1e-10, 100).unwrap();
+/// assert!(report.converged());
+/// ```
+///
+/// # Errors
+/// If the operator is not square, returns a [`NotSquareOperator`](SolverError::NotSquareOperator)
+/// If the size of `rhs` doesn't match the operator, returns a [`SizeMismatch`](SolverError::SizeMismatch)
+/// If an element of the diagonal is zero, returns a [`ZeroOnDiagonal`](SolverError::ZeroOnDiagonal)
+///
+/// # Complexity
+/// Each iteration takes two passes over the `nnz` elements stored by `A`:
+/// Time: O(nnz)
+/// Space: O(n)
+///
+pub fn jacobi<K, A>(
+    operator: &A,
+    rhs: &Vector<K>,
+    tolerance: &K,
+    max_iterations: usize,
+) -> Result<SolverReport<K>, SolverError>
+where
+    A: LinearOperator<K> + LineEntries<K>,
+    for<'a> K: Clone + Default + Sqrt + PartialOrd + AddAssign<&'a K> + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    iterate(
+        operator,
+        rhs,
+        tolerance,
+        max_iterations,
+        |solution, diagonal| {
+            let previous = solution.clone();
+            for (line, value) in solution.iter_mut().enumerate() {
+                *value = relax_line(operator, rhs, &previous, line, &diagonal[line]);
+            }
+        },
+    )
+}
+
+///
+/// Solves `A * x = b` with the Gauss-Seidel method: like
+/// [`jacobi`], but each coordinate is updated using the ones already
+/// updated during the same iteration.
+///
+/// It converges when `A` is strictly diagonally dominant, or symmetric
+/// positive definite, usually about twice as fast as the Jacobi method.
+///
+/// # Example
+/// ```
+/// use matrix::{solvers::gauss_seidel, Matrix, Vector};
+///
+/// let mat = Matrix::from([[4., 1.], [2., 5.]]);
+/// let report = gauss_seidel(&mat, &Vector::from([5., 7.]), &1e-10, 100).unwrap();
+/// assert!(report.converged());
+/// ```
+///
+/// # Errors
+/// If the operator is not square, returns a [`NotSquareOperator`](SolverError::NotSquareOperator)
+/// If the size of `rhs` doesn't match the operator, returns a [`SizeMismatch`](SolverError::SizeMismatch)
+/// If an element of the diagonal is zero, returns a [`ZeroOnDiagonal`](SolverError::ZeroOnDiagonal)
+///
+/// # Complexity
+/// Each iteration takes two passes over the `nnz` elements stored by `A`:
+/// Time: O(nnz)
+/// Space: O(n)
+///
+pub fn gauss_seidel<K, A>(
+    operator: &A,
+    rhs: &Vector<K>,
+    tolerance: &K,
+    max_iterations: usize,
+) -> Result<SolverReport<K>, SolverError>
+where
+    A: LinearOperator<K> + LineEntries<K>,
+    for<'a> K: Clone + Default + Sqrt + PartialOrd + AddAssign<&'a K> + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K> + IsZero,
+{
+    iterate(
+        operator,
+        rhs,
+        tolerance,
+        max_iterations,
+        |solution, diagonal| {
+            for (line, diagonal) in diagonal.iter().enumerate() {
+                let value = relax_line(operator, rhs, solution, line, diagonal);
+                if let Some(elt) = solution.get_mut(line) {
+                    *elt = value;
+                }
+            }
+        },
+    )
+}
+
+///
+/// Returns `(b[line] - sum(A[line][j] * x[j], j != line)) / A[line][line]`.
+///
+fn relax_line<K, A>(
+    operator: &A,
+    rhs: &Vector<K>,
+    solution: &Vector<K>,
+    line: usize,
+    diagonal: &K,
+) -> K
+where
+    A: LineEntries<K>,
+    for<'a> K: Clone + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + Div<&'a K, Output = K>,
+{
+    let mut value = rhs[line].clone();
+    for (column, elt) in operator.line_entries(line) {
+        if column != line {
+            value -= &(elt * &solution[column]);
+        }
+    }
+    &value / diagonal
+}
+
+fn iterate<K, A>(
+    operator: &A,
+    rhs: &Vector<K>,
+    tolerance: &K,
+    max_iterations: usize,
+    mut step: impl FnMut(&mut Vector<K>, &[K]),
+) -> Result<SolverReport<K>, SolverError>
+where
+    A: LinearOperator<K> + LineEntries<K>,
+    for<'a> K: Clone + Default + Sqrt + PartialOrd + AddAssign<&'a K> + SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K> + IsZero,
+{
+    check_dimensions(operator, rhs)?;
+    let diagonal = (0..rhs.len())
+        .map(|line| {
+            operator
+                .line_entries(line)
+                .find(|(column, _)| *column == line)
+                .map(|(_, elt)| elt.clone())
+                .filter(|elt| !elt.is_zero())
+                .ok_or(SolverError::ZeroOnDiagonal(line))
+        })
+        .collect::<Result<Vec<K>, _>>()?;
+
+    let threshold = tolerance * &norm(rhs);
+    let mut solution = Vector::fill(&K::default(), rhs.len());
+    let mut residuals = vec![norm(rhs)];
+    let mut converged = residuals[0] <= threshold;
+    let mut iterations = 0;
+    while !converged && iterations < max_iterations {
+        step(&mut solution, &diagonal);
+        iterations += 1;
+        let residual_norm = norm(&residual(operator, rhs, &solution));
+        converged = residual_norm <= threshold;
+        residuals.push(residual_norm);
+    }
+
+    Ok(SolverReport {
+        solution,
+        iterations,
+        residuals,
+        converged,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::{gauss_seidel, jacobi};
+    use crate::{error::SolverError, matrix::SparseMatrix, Matrix, Vector};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn example() {
+        let mat = Matrix::from([
+            [10_f64, -1., 2., 0.],
+            [-1., 11., -1., 3.],
+            [2., -1., 10., -1.],
+            [0., 3., -1., 8.],
+        ]);
+        let rhs = Vector::from([6., 25., -11., 15.]);
+        let expected = [1., 2., -1., 1.];
+
+        let jacobi_report = jacobi(&mat, &rhs, &1e-10, 100).unwrap();
+        assert!(jacobi_report.converged());
+        let seidel_report = gauss_seidel(&mat, &rhs, &1e-10, 100).unwrap();
+        assert!(seidel_report.converged());
+        assert!(seidel_report.iterations() < jacobi_report.iterations());
+        for report in [jacobi_report, seidel_report] {
+            for (value, expected) in report.solution().iter().zip(expected) {
+                assert!((value - expected).abs() < 1e-9);
+            }
+        }
+
+        let sparse = SparseMatrix::from(&mat);
+        let report = gauss_seidel(&sparse, &rhs, &1e-10, 100).unwrap();
+        assert!(report.converged());
+    }
+
+    #[test]
+    fn errors() {
+        let mat = Matrix::from([[1., 2.], [3., 0.]]);
+        assert_eq!(
+            jacobi(&mat, &Vector::from([1., 1.]), &1e-10, 10),
+            Err(SolverError::ZeroOnDiagonal(1))
+        );
+        let sparse = SparseMatrix::from(&Matrix::from([[0., 1.], [1., 1.]]));
+        assert_eq!(
+            gauss_seidel(&sparse, &Vector::from([1., 1.]), &1e-10, 10),
+            Err(SolverError::ZeroOnDiagonal(0))
+        );
+
+        // Not diagonally dominant: diverges
+        let mat = Matrix::from([[1., 3.], [3., 1.]]);
+        let report = jacobi(&mat, &Vector::from([1., 1.]), &1e-10, 20).unwrap();
+        assert!(!report.converged());
+        assert_eq!(report.iterations(), 20);
+    }
+}
@@ -35,3 +35,68 @@ impl<const LHS: usize, const RHS: usize> AssertCompare<LHS, RHS> {
     pub const EQUAL: () = assert!(LHS == RHS, "LHS should be equal to RHS");
     pub const DIFFERENT: () = assert!(LHS != RHS, "LHS shouldn't be equal to RHS");
 }
+
+pub struct AssertPrime<const P: u64>;
+
+impl<const P: u64> AssertPrime<P> {
+    pub const OK: () = assert!(is_prime(P), "P should be a prime number");
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+const fn mul_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    ((lhs as u128 * rhs as u128) % modulus as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+///
+/// Deterministic Miller-Rabin test: these bases are enough for every `u64`.
+///
+const fn is_prime(number: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if number < 2 {
+        return false;
+    }
+    let mut index = 0;
+    while index < BASES.len() {
+        if number.is_multiple_of(BASES[index]) {
+            return number == BASES[index];
+        }
+        index += 1;
+    }
+    let mut odd_part = number - 1;
+    let mut twos = 0;
+    while odd_part.is_multiple_of(2) {
+        odd_part /= 2;
+        twos += 1;
+    }
+    let mut index = 0;
+    'bases: while index < BASES.len() {
+        let mut value = pow_mod(BASES[index], odd_part, number);
+        index += 1;
+        if value == 1 || value == number - 1 {
+            continue;
+        }
+        let mut round = 1;
+        while round < twos {
+            value = mul_mod(value, value, number);
+            if value == number - 1 {
+                continue 'bases;
+            }
+            round += 1;
+        }
+        return false;
+    }
+    true
+}
@@ -28,6 +28,21 @@ pub trait Sqrt {
     fn sqrt(&self) -> Self;
 }
 
+pub trait Epsilon {
+    ///
+    /// Returns the difference between `1` and the next representable value.
+    ///
+    #[must_use]
+    fn epsilon() -> Self;
+}
+
+pub trait IsFinite {
+    ///
+    /// Returns whether `self` is neither infinite nor NaN.
+    ///
+    fn is_finite(&self) -> bool;
+}
+
 pub trait Max {
     #[must_use]
     fn max(self, other: Self) -> Self;
@@ -37,9 +52,60 @@ pub trait Divisor {
     fn can_be_divisor(&self) -> bool;
 }
 
+pub trait Conjugate {
+    #[must_use]
+    fn conjugate(&self) -> Self;
+}
+
+pub trait IsPositive {
+    fn is_positive(&self) -> bool;
+}
+
 pub trait IsZero {
     fn is_zero(&self) -> bool;
 }
+///
+/// Tolerance-aware counterpart of [`IsZero`], for types whose computations
+/// accumulate rounding errors.
+///
+pub trait ApproxZero {
+    /// The type of the tolerance, which is the type of the absolute value
+    type Tolerance;
+
+    ///
+    /// Returns whether the absolute value of `self` is at most `tolerance`.
+    ///
+    fn is_approx_zero(&self, tolerance: &Self::Tolerance) -> bool;
+}
+
+///
+/// Tells whether a type is a field: whether the division of two of its
+/// values, by a non-zero one, is always exact.
+///
+pub trait IsField {
+    const IS_FIELD: bool;
+}
+
+pub trait Gcd {
+    ///
+    /// Returns the greatest common divisor of `self` and `other`, which is
+    /// never negative.
+    ///
+    /// # Panics
+    /// For signed integers, if the divisor doesn't fit in the type, which
+    /// only happens for `gcd(MIN, MIN)` and `gcd(MIN, 0)`.
+    ///
+    #[must_use]
+    fn gcd(&self, other: &Self) -> Self;
+}
+
+pub trait CheckedNeg: Sized {
+    ///
+    /// Returns `-self`, or `None` if it is not representable.
+    ///
+    fn checked_neg(&self) -> Option<Self>;
+}
+
 pub trait IsOne {
     fn is_one(&self) -> bool;
 }
@@ -198,6 +264,36 @@ macro_rules! impl_is_one {
     };
 }
 
+macro_rules! impl_conjugate {
+    ($current: ident, $($types: ident),+) => {
+        impl_conjugate!($current);
+        impl_conjugate!($($types),+);
+    };
+    ($current: ident) => {
+        impl Conjugate for $current {
+            #[inline(always)]
+            fn conjugate(&self) -> Self {
+                *self
+            }
+        }
+    };
+}
+
+macro_rules! impl_is_positive {
+    ($value: expr, $current: ident, $($types: ident),+) => {
+        impl_is_positive!($value, $current);
+        impl_is_positive!($value, $($types),+);
+    };
+    ($value: expr, $current: ident) => {
+        impl IsPositive for $current {
+            #[inline(always)]
+            fn is_positive(&self) -> bool {
+                self > $value
+            }
+        }
+    };
+}
+
 macro_rules! impl_abs {
     ($current: ident, $($types: ident),+) => {
         impl_abs!($current);
@@ -226,6 +322,125 @@ macro_rules! impl_sqrt {
     };
 }
 
+macro_rules! impl_epsilon {
+    ($current: ident, $($types: ident),+) => {
+        impl_epsilon!($current);
+        impl_epsilon!($($types),+);
+    };
+    ($current: ident) => {
+        impl Epsilon for $current {
+            #[inline(always)]
+            fn epsilon() -> Self {
+                <$current>::EPSILON
+            }
+        }
+    };
+}
+
+macro_rules! impl_is_finite {
+    ($current: ident, $($types: ident),+) => {
+        impl_is_finite!($current);
+        impl_is_finite!($($types),+);
+    };
+    ($current: ident) => {
+        impl IsFinite for $current {
+            #[inline(always)]
+            fn is_finite(&self) -> bool {
+                <$current>::is_finite(*self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_approx_zero {
+    (signed, $current: ident, $($types: ident),+) => {
+        impl_approx_zero!(signed, $current);
+        impl_approx_zero!(signed, $($types),+);
+    };
+    (signed, $current: ident) => {
+        impl ApproxZero for $current {
+            type Tolerance = Self;
+
+            #[inline(always)]
+            fn is_approx_zero(&self, tolerance: &Self) -> bool {
+                &self.abs() <= tolerance
+            }
+        }
+    };
+    (unsigned, $current: ident, $($types: ident),+) => {
+        impl_approx_zero!(unsigned, $current);
+        impl_approx_zero!(unsigned, $($types),+);
+    };
+    (unsigned, $current: ident) => {
+        impl ApproxZero for $current {
+            type Tolerance = Self;
+
+            #[inline(always)]
+            fn is_approx_zero(&self, tolerance: &Self) -> bool {
+                self <= tolerance
+            }
+        }
+    };
+}
+
+macro_rules! impl_is_field {
+    ($value: expr, $current: ident, $($types: ident),+) => {
+        impl_is_field!($value, $current);
+        impl_is_field!($value, $($types),+);
+    };
+    ($value: expr, $current: ident) => {
+        impl IsField for $current {
+            const IS_FIELD: bool = $value;
+        }
+    };
+}
+
+macro_rules! impl_checked_neg {
+    ($current: ident, $($types: ident),+) => {
+        impl_checked_neg!($current);
+        impl_checked_neg!($($types),+);
+    };
+    ($current: ident) => {
+        impl CheckedNeg for $current {
+            #[inline(always)]
+            fn checked_neg(&self) -> Option<Self> {
+                <$current>::checked_neg(*self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_gcd {
+    (signed, $current: ident, $($types: ident),+) => {
+        impl_gcd!(signed, $current);
+        impl_gcd!(signed, $($types),+);
+    };
+    (signed, $current: ident) => {
+        impl Gcd for $current {
+            fn gcd(&self, other: &Self) -> Self {
+                // The magnitude of `MIN` only fits in the unsigned type
+                let divisor = self.unsigned_abs().gcd(&other.unsigned_abs());
+                Self::try_from(divisor).expect("the gcd doesn't fit in the type")
+            }
+        }
+    };
+    (unsigned, $current: ident, $($types: ident),+) => {
+        impl_gcd!(unsigned, $current);
+        impl_gcd!(unsigned, $($types),+);
+    };
+    (unsigned, $current: ident) => {
+        impl Gcd for $current {
+            fn gcd(&self, other: &Self) -> Self {
+                let (mut lhs, mut rhs) = (*self, *other);
+                while rhs != 0 {
+                    (lhs, rhs) = (rhs, lhs % rhs);
+                }
+                lhs
+            }
+        }
+    };
+}
+
 macro_rules! impl_max {
     ($current: ident, $($types: ident),+) => {
         impl_max!($current);
@@ -286,11 +501,24 @@ impl_divisor!(&0, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
 impl_divisor!(&0.0, f32, f64);
 impl_is_zero!(&0, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
 impl_is_zero!(&0.0, f32, f64);
+impl_approx_zero!(unsigned, u8, u16, u32, u64, u128);
+impl_approx_zero!(signed, i8, i16, i32, i64, i128, f32, f64);
+impl_is_field!(false, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+impl_is_field!(true, f32, f64);
+impl_gcd!(unsigned, u8, u16, u32, u64, u128);
+impl_gcd!(signed, i8, i16, i32, i64, i128);
+impl_checked_neg!(i8, i16, i32, i64, i128);
 impl_is_one!(&1, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
 impl_is_one!(&1.0, f32, f64);
 
+impl_conjugate!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+impl_is_positive!(&0, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+impl_is_positive!(&0.0, f32, f64);
+
 impl_abs!(i8, i16, i32, i64, i128, f32, f64);
 impl_sqrt!(f32, f64);
+impl_epsilon!(f32, f64);
+impl_is_finite!(f32, f64);
 impl_max!(f32, f64);
 impl_max_ord!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
 impl_fma!(float, f32, f64);
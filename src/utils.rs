@@ -1,3 +1,5 @@
+mod inner_product;
 mod linear_interpolation;
 
+pub(crate) use inner_product::{dot, norm};
 pub use linear_interpolation::lerp;
@@ -0,0 +1,34 @@
+//!
+//! Euclidean inner product and norm of slices, shared by the algorithms that
+//! work on the raw content of vectors.
+//!
+
+use std::ops::{AddAssign, Mul};
+
+use crate::traits::Sqrt;
+
+///
+/// Returns the sum of the products of the elements of `lhs` and `rhs`.
+///
+pub fn dot<K>(lhs: &[K], rhs: &[K]) -> K
+where
+    for<'a> K: Default + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    let mut sum = K::default();
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        sum += &(lhs * rhs);
+    }
+    sum
+}
+
+///
+/// Returns the euclidean norm of `vec`.
+///
+pub fn norm<K>(vec: &[K]) -> K
+where
+    for<'a> K: Default + Sqrt + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    dot(vec, vec).sqrt()
+}
@@ -3,6 +3,9 @@ mod basic_operations;
 mod linear_combination;
 pub use linear_combination::{linear_combination, linear_combination_unchecked};
 
+mod gram_schmidt;
+pub use gram_schmidt::{gram_schmidt, modified_gram_schmidt};
+
 mod dot_product;
 mod norms;
 
@@ -0,0 +1,271 @@
+//!
+//! Gram-Schmidt orthonormalization
+//!
+
+use std::ops::{AddAssign, DivAssign, Mul, SubAssign};
+
+use crate::{
+    error::GramSchmidtError,
+    traits::{ApproxZero, Conjugate, Sqrt},
+    Vector,
+};
+
+///
+/// Returns an orthonormal basis of the space spanned by `vectors`, using the
+/// classical Gram-Schmidt process.
+///
+/// Each vector has its projections on the previous basis vectors removed,
+/// all computed from the original vector. If the norm of what remains is
+/// approximately zero within `tolerance`, the vector depends on the previous
+/// ones and is dropped. The inner product conjugates its left operand, so
+/// this works for both real and complex vectors.
+///
+/// The classical process loses orthogonality on ill-conditioned inputs:
+/// see [`modified_gram_schmidt`] for a more stable one.
+///
+/// # Example:
+/// ```
+/// use matrix::vector::gram_schmidt;
+/// use matrix::Vector;
+///
+/// let vec1 = Vector::from([2., 0.]);
+/// let vec2 = Vector::from([4., 0.]);
+/// let vec3 = Vector::from([1., 3.]);
+/// let basis = gram_schmidt(&[vec1, vec2, vec3], &1e-12).unwrap();
+/// assert_eq!(basis, [Vector::from([1., 0.]), Vector::from([0., 1.])]);
+/// ```
+///
+/// # Errors
+/// If all vectors of `vectors` do not have the same size, returns a [`VectorSizeMismatch`](GramSchmidtError::VectorSizeMismatch)
+///
+/// # Complexity:
+/// Time: O(nk^2) with `n` the size of the vectors and `k` their number.
+/// Space: O(nk)
+///
+pub fn gram_schmidt<K>(
+    vectors: &[Vector<K>],
+    tolerance: &K::Tolerance,
+) -> Result<Vec<Vector<K>>, GramSchmidtError>
+where
+    for<'a> K: Clone
+        + Default
+        + Conjugate
+        + Sqrt
+        + ApproxZero
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    check_sizes(vectors)?;
+    let mut basis: Vec<Vector<K>> = Vec::with_capacity(vectors.len());
+    for vector in vectors {
+        let coefficients: Vec<K> = basis
+            .iter()
+            .map(|elt| inner_product(&elt.content, &vector.content))
+            .collect();
+        let mut remainder = vector.clone();
+        for (elt, coefficient) in basis.iter().zip(&coefficients) {
+            subtract_scaled(&mut remainder.content, &elt.content, coefficient);
+        }
+        if normalize(&mut remainder.content, tolerance) {
+            basis.push(remainder);
+        }
+    }
+    Ok(basis)
+}
+
+///
+/// Returns an orthonormal basis of the space spanned by `vectors`, using the
+/// modified Gram-Schmidt process.
+///
+/// It gives the same result as [`gram_schmidt`] in exact arithmetic, but
+/// removes the projections one at a time, each computed from what remains of
+/// the vector: the rounding errors don't pile up, and the basis stays
+/// orthogonal on ill-conditioned inputs.
+///
+/// # Example:
+/// ```
+/// use matrix::vector::modified_gram_schmidt;
+/// use matrix::Vector;
+///
+/// let vec1 = Vector::from([1., 1., 0.]);
+/// let vec2 = Vector::from([0., 0., 2.]);
+/// let basis = modified_gram_schmidt(&[vec1, vec2], &1e-12).unwrap();
+/// assert_eq!(basis[1], [0., 0., 1.]);
+/// ```
+///
+/// # Errors
+/// If all vectors of `vectors` do not have the same size, returns a [`VectorSizeMismatch`](GramSchmidtError::VectorSizeMismatch)
+///
+/// # Complexity:
+/// Time: O(nk^2) with `n` the size of the vectors and `k` their number.
+/// Space: O(nk)
+///
+pub fn modified_gram_schmidt<K>(
+    vectors: &[Vector<K>],
+    tolerance: &K::Tolerance,
+) -> Result<Vec<Vector<K>>, GramSchmidtError>
+where
+    for<'a> K: Clone
+        + Default
+        + Conjugate
+        + Sqrt
+        + ApproxZero
+        + AddAssign<&'a K>
+        + SubAssign<&'a K>
+        + DivAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    check_sizes(vectors)?;
+    let mut basis: Vec<Vector<K>> = Vec::with_capacity(vectors.len());
+    for vector in vectors {
+        let mut remainder = vector.clone();
+        for elt in &basis {
+            let coefficient = inner_product(&elt.content, &remainder.content);
+            subtract_scaled(&mut remainder.content, &elt.content, &coefficient);
+        }
+        if normalize(&mut remainder.content, tolerance) {
+            basis.push(remainder);
+        }
+    }
+    Ok(basis)
+}
+
+fn check_sizes<K: Clone>(vectors: &[Vector<K>]) -> Result<(), GramSchmidtError> {
+    let Some((first, next)) = vectors.split_first() else {
+        return Ok(());
+    };
+    for elt in next {
+        if first.len() != elt.len() {
+            return Err(GramSchmidtError::VectorSizeMismatch(first.len(), elt.len()));
+        }
+    }
+    Ok(())
+}
+
+///
+/// Returns `<lhs, rhs>`, the sum of the `conj(lhs[i]) * rhs[i]`.
+///
+#[inline(always)]
+fn inner_product<K>(lhs: &[K], rhs: &[K]) -> K
+where
+    for<'a> K: Default + Conjugate + AddAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    let mut sum = K::default();
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        sum += &(&lhs.conjugate() * rhs);
+    }
+    sum
+}
+
+///
+/// Computes `vector -= direction * coefficient`.
+///
+#[inline(always)]
+fn subtract_scaled<K>(vector: &mut [K], direction: &[K], coefficient: &K)
+where
+    for<'a> K: SubAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    for (elt, direction) in vector.iter_mut().zip(direction) {
+        *elt -= &(direction * coefficient);
+    }
+}
+
+///
+/// Divides `vector` by its norm, and returns `true`, unless that norm is
+/// approximately zero.
+///
+#[inline(always)]
+fn normalize<K>(vector: &mut [K], tolerance: &K::Tolerance) -> bool
+where
+    for<'a> K: Default + Conjugate + Sqrt + ApproxZero + AddAssign<&'a K> + DivAssign<&'a K>,
+    for<'a> &'a K: Mul<&'a K, Output = K>,
+{
+    let norm = inner_product(vector, vector).sqrt();
+    if norm.is_approx_zero(tolerance) {
+        return false;
+    }
+    for elt in vector {
+        *elt /= &norm;
+    }
+    true
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use super::{gram_schmidt, inner_product, modified_gram_schmidt};
+    use crate::{assert_eq_float, complex::cpl, error::GramSchmidtError, Vector};
+    use pretty_assertions::assert_eq;
+
+    fn assert_orthonormal(basis: &[Vector<f64>]) {
+        for (i, lhs) in basis.iter().enumerate() {
+            for (j, rhs) in basis.iter().enumerate() {
+                let expected = if i == j { 1. } else { 0. };
+                assert_eq_float!(inner_product(lhs, rhs), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn example() {
+        let vectors = [
+            Vector::from([1., 1., 0.]),
+            Vector::from([1., 0., 1.]),
+            Vector::from([2., 1., 1.]),
+            Vector::from([0., 1., 1.]),
+        ];
+        let basis = gram_schmidt(&vectors, &1e-12).unwrap();
+        assert_eq!(basis.len(), 3);
+        assert_orthonormal(&basis);
+        let basis = modified_gram_schmidt(&vectors, &1e-12).unwrap();
+        assert_eq!(basis.len(), 3);
+        assert_orthonormal(&basis);
+
+        assert_eq!(gram_schmidt::<f64>(&[], &1e-12), Ok(vec![]));
+        assert_eq!(
+            modified_gram_schmidt(&[Vector::from([0., 0.])], &1e-12),
+            Ok(vec![])
+        );
+        assert_eq!(
+            gram_schmidt(&[Vector::from([1., 0.]), Vector::from([1.])], &1e-12),
+            Err(GramSchmidtError::VectorSizeMismatch(2, 1))
+        );
+    }
+
+    #[test]
+    fn ill_conditioned() {
+        // Läuchli's example: the classical process loses orthogonality
+        let epsilon = 1e-8_f64;
+        let vectors = [
+            Vector::from([1., epsilon, 0., 0.]),
+            Vector::from([1., 0., epsilon, 0.]),
+            Vector::from([1., 0., 0., epsilon]),
+        ];
+        let classical = gram_schmidt(&vectors, &1e-14).unwrap();
+        assert!(inner_product(&classical[1], &classical[2]).abs() > 0.1);
+        let modified = modified_gram_schmidt(&vectors, &1e-14).unwrap();
+        assert!(inner_product(&modified[1], &modified[2]).abs() < 1e-7);
+    }
+
+    #[test]
+    fn with_complex() {
+        let vectors = [
+            Vector::from([cpl!(1., 1.), cpl!(0., 0.)]),
+            Vector::from([cpl!(1., 0.), cpl!(0., 1.)]),
+            Vector::from([cpl!(0., 2.), cpl!(2., 0.)]),
+        ];
+        let basis = modified_gram_schmidt(&vectors, &1e-12).unwrap();
+        assert_eq!(basis.len(), 2);
+        for (i, lhs) in basis.iter().enumerate() {
+            for (j, rhs) in basis.iter().enumerate() {
+                let product = inner_product(lhs, rhs);
+                assert_eq_float!(*product.re(), if i == j { 1. } else { 0. });
+                assert_eq_float!(*product.im(), 0.);
+            }
+        }
+    }
+}
@@ -30,6 +30,13 @@ impl<K: Clone> From<&[K]> for Vector<K> {
     }
 }
 
+impl<K: Clone> From<Vec<K>> for Vector<K> {
+    #[inline(always)]
+    fn from(content: Vec<K>) -> Self {
+        Self { content }
+    }
+}
+
 impl<K: Clone, const SIZE: usize> From<[K; SIZE]> for Vector<K> {
     #[inline(always)]
     fn from(base: [K; SIZE]) -> Self {